@@ -0,0 +1,4 @@
+fn main() {
+    // Expose Py_LIMITED_API / Py_3_x cfgs so abi3 builds can gate version-specific APIs
+    pyo3_build_config::use_pyo3_cfgs();
+}
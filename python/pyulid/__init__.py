@@ -14,9 +14,13 @@ Example:
 # Import compiled Rust module
 from . import pyulid as _pyulid_rs
 
-from datetime import datetime
+from datetime import datetime, timedelta, timezone
 from typing import Union, Optional, overload, TYPE_CHECKING
+import logging
 import sys
+import threading
+import uuid
+import weakref
 
 if sys.version_info >= (3, 10):
     from typing import TypeAlias
@@ -45,37 +49,521 @@ ULIDString: TypeAlias = str
 ULIDUnion: TypeAlias = Union["ULID", str]
 """Type alias for values that can be compared with ULIDs (ULID objects or strings)."""
 
+_EPOCH = datetime(1970, 1, 1, tzinfo=timezone.utc)
+
 # Re-export fast functions for python use
 ulid = _pyulid_rs.ulid
+ulid_bytes = _pyulid_rs.ulid_bytes
+ulid_into = _pyulid_rs.ulid_into
+ulid_batch = _pyulid_rs.ulid_batch
+ulid_non_monotonic = _pyulid_rs.ulid_non_monotonic
+ulid_with_timestamp_monotonic = _pyulid_rs.ulid_with_timestamp_monotonic
 ulid_with_timestamp = _pyulid_rs.ulid_with_timestamp
+ulid_from_datetime = _pyulid_rs.ulid_from_datetime
+ulid_sibling = _pyulid_rs.ulid_sibling
+ulid_clean = _pyulid_rs.ulid_clean
 ulid_is_valid = _pyulid_rs.ulid_is_valid
+ulid_validate = _pyulid_rs.ulid_validate
+ulid_is_valid_many = _pyulid_rs.ulid_is_valid_many
 ulid_timestamp = _pyulid_rs.ulid_timestamp
+ulid_timestamp_many = _pyulid_rs.ulid_timestamp_many
+ulid_timestamp_seconds = _pyulid_rs.ulid_timestamp_seconds
+ulid_at = _pyulid_rs.ulid_at
+ulid_from_iso8601 = _pyulid_rs.ulid_from_iso8601
+ulid_datetime = _pyulid_rs.ulid_datetime
+ulid_parse = _pyulid_rs.ulid_parse
 ulid_random = _pyulid_rs.ulid_random
+ulid_node_id = _pyulid_rs.ulid_node_id
 ulid_to_uuid = _pyulid_rs.ulid_to_uuid
+ulid_to_uuid_obj = _pyulid_rs.ulid_to_uuid_obj
 uuid_to_ulid = _pyulid_rs.uuid_to_ulid
+ulid_to_uuid7 = _pyulid_rs.ulid_to_uuid7
+uuid7_to_ulid = _pyulid_rs.uuid7_to_ulid
+uuid_timestamp = _pyulid_rs.uuid_timestamp
+convert_stream = _pyulid_rs.convert_stream
 encode_base32 = _pyulid_rs.encode_base32
 decode_base32 = _pyulid_rs.decode_base32
 ulid_from_str = _pyulid_rs.ulid_from_str
+ulid_from_str_lenient = _pyulid_rs.ulid_from_str_lenient
+ulid_to_int = _pyulid_rs.ulid_to_int
+ulid_from_int = _pyulid_rs.ulid_from_int
+ulid_to_bytes = _pyulid_rs.ulid_to_bytes
+ulid_from_bytes = _pyulid_rs.ulid_from_bytes
+ulid_timestamp_bytes = _pyulid_rs.ulid_timestamp_bytes
+ulid_random_bytes = _pyulid_rs.ulid_random_bytes
+ulid_to_hex = _pyulid_rs.ulid_to_hex
+ulid_from_hex = _pyulid_rs.ulid_from_hex
+ulid_next = _pyulid_rs.ulid_next
+ulid_prev = _pyulid_rs.ulid_prev
+# Aliases under the names cursor-pagination code tends to look for
+ulid_increment = ulid_next
+ulid_decrement = ulid_prev
+ulid_delta = _pyulid_rs.ulid_delta
+ulid_diff_ms = _pyulid_rs.ulid_diff_ms
+ulid_compare = _pyulid_rs.ulid_compare
+ulid_age_ms = _pyulid_rs.ulid_age_ms
+ulid_is_expired = _pyulid_rs.ulid_is_expired
+ulid_floor = _pyulid_rs.ulid_floor
+ulid_ceil = _pyulid_rs.ulid_ceil
+ulid_min_for_timestamp = _pyulid_rs.ulid_min_for_timestamp
+ulid_max_for_timestamp = _pyulid_rs.ulid_max_for_timestamp
+ulid_nil = _pyulid_rs.ulid_nil
+ulid_max = _pyulid_rs.ulid_max
+MIN_ULID = _pyulid_rs.MIN_ULID
+MAX_ULID = _pyulid_rs.MAX_ULID
+ulid_range = _pyulid_rs.ulid_range
+ulid_scan_partitions = _pyulid_rs.ulid_scan_partitions
+encode_timestamp_base32 = _pyulid_rs.encode_timestamp_base32
+decode_timestamp_base32 = _pyulid_rs.decode_timestamp_base32
+ulid_with_suffix = _pyulid_rs.ulid_with_suffix
+ulid_parse_suffix = _pyulid_rs.ulid_parse_suffix
+ulid_pattern = _pyulid_rs.ulid_pattern
+ulid_set_timestamp = _pyulid_rs.ulid_set_timestamp
+ulid_set_random = _pyulid_rs.ulid_set_random
+ulid_from_parts = _pyulid_rs.ulid_from_parts
+ulid_to_b32std = _pyulid_rs.ulid_to_b32std
+Ulid = _pyulid_rs.Ulid
+UlidGenerator = _pyulid_rs.UlidGenerator
+KeyedUlidGenerator = _pyulid_rs.KeyedUlidGenerator
+UlidArray = _pyulid_rs.UlidArray
+UlidSet = _pyulid_rs.UlidSet
+UlidLoadGenerator = _pyulid_rs.UlidLoadGenerator
+UlidInterval = _pyulid_rs.UlidInterval
+UlidCheckpoint = _pyulid_rs.UlidCheckpoint
+ulid_bisect_left = _pyulid_rs.ulid_bisect_left
+ulid_bisect_right = _pyulid_rs.ulid_bisect_right
+self_check = _pyulid_rs.self_check
+ulid_entropy_check = _pyulid_rs.ulid_entropy_check
+set_entropy_source = _pyulid_rs.set_entropy_source
+set_rng_mode = _pyulid_rs.set_rng_mode
+get_rng_mode = _pyulid_rs.get_rng_mode
+reseed = _pyulid_rs.reseed
+set_minimum_timestamp = _pyulid_rs.set_minimum_timestamp
+configure = _pyulid_rs.configure
+get_config = _pyulid_rs.get_config
+stats = _pyulid_rs.stats
+ulid_abbreviate = _pyulid_rs.ulid_abbreviate
+ulid_expand = _pyulid_rs.ulid_expand
+ulid_closest = _pyulid_rs.ulid_closest
+ulid_sessionize = _pyulid_rs.ulid_sessionize
+ulid_sample = _pyulid_rs.ulid_sample
+ulid_embed_tag = _pyulid_rs.ulid_embed_tag
+ulid_extract_tag = _pyulid_rs.ulid_extract_tag
+AmbiguousPrefixError = _pyulid_rs.AmbiguousPrefixError
+UlidError = _pyulid_rs.UlidError
+UlidInternalError = _pyulid_rs.UlidInternalError
+UlidDecodeError = _pyulid_rs.UlidDecodeError
+UlidOverflowError = _pyulid_rs.UlidOverflowError
+UlidClockError = _pyulid_rs.UlidClockError
+_freeze_time = _pyulid_rs._freeze_time
+_unfreeze_time = _pyulid_rs._unfreeze_time
+_resume_timestamp_sequence = _pyulid_rs._resume_timestamp_sequence
+ulid_batch_array = _pyulid_rs.ulid_batch_array
+ulid_from_b32std = _pyulid_rs.ulid_from_b32std
+ulid_to_base64 = _pyulid_rs.ulid_to_base64
+ulid_from_base64 = _pyulid_rs.ulid_from_base64
+ulid_with_check = _pyulid_rs.ulid_with_check
+ulid_verify_check = _pyulid_rs.ulid_verify_check
+ulid_strip_check = _pyulid_rs.ulid_strip_check
+ulid_to_json = _pyulid_rs.ulid_to_json
+ulid_from_json = _pyulid_rs.ulid_from_json
+ulid_to_ipv6 = _pyulid_rs.ulid_to_ipv6
+ulid_from_ipv6 = _pyulid_rs.ulid_from_ipv6
+
+# Only in builds with the `test-hooks` feature, for the panic conversion tests
+if hasattr(_pyulid_rs, "_inject_panic"):
+    _inject_panic = _pyulid_rs._inject_panic
 
 __version__ = "1.0.1"
 __all__ = [
     "ULID",
     "ULIDString",
     "ULIDUnion",
+    "Ulid",
+    "UlidGenerator",
+    "KeyedUlidGenerator",
+    "UlidArray",
+    "UlidSet",
+    "UlidLoadGenerator",
+    "UlidInterval",
+    "UlidCheckpoint",
+    "ulid_batch_array",
+    "ulid_bisect_left",
+    "ulid_bisect_right",
     "ulid",
+    "ulid_bytes",
+    "ulid_into",
+    "ulid_batch",
+    "ulid_non_monotonic",
+    "ulid_with_timestamp_monotonic",
     "ulid_with_timestamp",
+    "ulid_from_datetime",
+    "ulid_sibling",
+    "ulid_clean",
     "ulid_is_valid",
+    "ulid_validate",
+    "ulid_is_valid_many",
     "ulid_timestamp",
+    "ulid_timestamp_many",
+    "ulid_timestamp_seconds",
+    "ulid_at",
+    "ulid_from_iso8601",
+    "ulid_datetime",
+    "ulid_parse",
     "ulid_random",
+    "ulid_node_id",
     "ulid_to_uuid",
+    "ulid_to_uuid_obj",
     "uuid_to_ulid",
+    "ulid_to_uuid7",
+    "uuid7_to_ulid",
+    "uuid_timestamp",
+    "convert_stream",
     "encode_base32",
     "decode_base32",
     "ulid_from_str",
+    "ulid_from_str_lenient",
+    "ulid_to_int",
+    "ulid_from_int",
+    "ulid_to_bytes",
+    "ulid_from_bytes",
+    "ulid_timestamp_bytes",
+    "ulid_random_bytes",
+    "ulid_to_hex",
+    "ulid_from_hex",
+    "ulid_next",
+    "ulid_prev",
+    "ulid_increment",
+    "ulid_decrement",
+    "ulid_delta",
+    "ulid_diff_ms",
+    "ulid_compare",
+    "ulid_age_ms",
+    "ulid_is_expired",
+    "ulid_floor",
+    "ulid_ceil",
+    "ulid_min_for_timestamp",
+    "ulid_max_for_timestamp",
+    "ulid_nil",
+    "ulid_max",
+    "MIN_ULID",
+    "MAX_ULID",
+    "ulid_range",
+    "ulid_scan_partitions",
+    "encode_timestamp_base32",
+    "decode_timestamp_base32",
+    "ulid_with_suffix",
+    "ulid_parse_suffix",
+    "ulid_pattern",
+    "register_jsonschema_format",
+    "ulid_set_timestamp",
+    "ulid_set_random",
+    "ulid_from_parts",
+    "ulid_to_b32std",
+    "ulid_from_b32std",
+    "ulid_to_base64",
+    "ulid_from_base64",
+    "ulid_with_check",
+    "ulid_verify_check",
+    "ulid_strip_check",
+    "ulid_to_json",
+    "ulid_from_json",
+    "ulid_to_ipv6",
+    "ulid_from_ipv6",
+    "ulid_timestamps_np",
+    "ulid_batch_numpy",
     "parse",
+    "intern",
+    "self_check",
+    "ulid_entropy_check",
+    "set_entropy_source",
+    "set_rng_mode",
+    "get_rng_mode",
+    "reseed",
+    "set_minimum_timestamp",
+    "configure",
+    "get_config",
+    "stats",
+    "ulid_abbreviate",
+    "ulid_expand",
+    "ulid_closest",
+    "ulid_sessionize",
+    "ulid_sample",
+    "ulid_embed_tag",
+    "ulid_extract_tag",
+    "AmbiguousPrefixError",
+    "UlidError",
+    "UlidInternalError",
+    "UlidDecodeError",
+    "UlidOverflowError",
+    "UlidClockError",
+    "enable_logging",
+    "disable_logging",
+    "msgspec_enc_hook",
+    "msgspec_msgpack_enc_hook",
+    "msgspec_dec_hook",
+    "install_uuid4_patch",
+    "uninstall_uuid4_patch",
 ]
 
 
+def ulid_timestamps_np(seq, errors: str = "raise"):
+    """
+    Extract ULID timestamps into a NumPy ``datetime64[ms]`` array.
+
+    Decoding happens in Rust with the GIL released, writing straight into the
+    array's buffer, so no Python-level loop runs per element.
+
+    Args:
+        seq: List, pandas Series, or object/str/bytes-dtype NumPy array of ULIDs
+        errors: "raise" (default) to raise on the first invalid entry, or
+            "coerce" to turn invalid entries into NaT
+
+    Returns:
+        numpy.ndarray of dtype datetime64[ms]
+
+    Raises:
+        ImportError: If NumPy is not installed
+        ValueError: If an entry is invalid and errors="raise" (names the index)
+
+    Example:
+        >>> pyulid.ulid_timestamps_np(df["id"].to_numpy(), errors="coerce")
+    """
+    import numpy as np
+
+    raw = _pyulid_rs._ulid_timestamps_ms(seq, errors)
+    return np.frombuffer(raw, dtype=np.int64).view("datetime64[ms]")
+
+
+def ulid_batch_numpy(n: int, binary: bool = False):
+    """
+    Generate ``n`` monotonic ULIDs straight into a NumPy array.
+
+    The records are filled in Rust with the GIL released, so no per-ID Python
+    string is ever created.
+
+    Args:
+        n: Number of ULIDs to generate
+        binary: Return 16 big-endian bytes per ULID instead of the text form
+
+    Returns:
+        numpy.ndarray of dtype ``S26``, or ``uint8`` with shape ``(n, 16)``
+        when binary=True
+
+    Raises:
+        ImportError: If NumPy is not installed, or pyulid was built without
+            its ``numpy`` cargo feature
+
+    Example:
+        >>> ids = pyulid.ulid_batch_numpy(1_000_000)
+    """
+    try:
+        import numpy as np
+    except ImportError:
+        raise ImportError(
+            "ulid_batch_numpy requires NumPy; install it with "
+            "`pip install ulid-python[numpy]`"
+        ) from None
+    if not hasattr(_pyulid_rs, "_ulid_batch_raw"):
+        raise ImportError(
+            "ulid_batch_numpy requires pyulid built with the `numpy` cargo feature"
+        )
+
+    raw = _pyulid_rs._ulid_batch_raw(n, binary)
+    if binary:
+        return np.frombuffer(raw, dtype=np.uint8).reshape(n, 16)
+    return np.frombuffer(raw, dtype="S26")
+
+
+def _is_ulid_format(instance: object) -> bool:
+    # JSON Schema formats only constrain strings; other types pass through
+    if not isinstance(instance, str):
+        return True
+    return _pyulid_rs.ulid_is_valid(instance)
+
+
+def register_jsonschema_format(checker=None):
+    """
+    Register a ``"ulid"`` format with a jsonschema ``FormatChecker``.
+
+    Args:
+        checker: FormatChecker to extend, or None to create a new one
+            (which also carries jsonschema's built-in formats)
+
+    Returns:
+        The FormatChecker the format was registered on
+
+    Raises:
+        ImportError: If jsonschema is not installed
+
+    Example:
+        >>> checker = pyulid.register_jsonschema_format()
+        >>> jsonschema.validate(body, schema, format_checker=checker)
+    """
+    if checker is None:
+        try:
+            import jsonschema
+        except ImportError as e:
+            raise ImportError(
+                "register_jsonschema_format requires the 'jsonschema' package"
+            ) from e
+        checker = jsonschema.FormatChecker()
+
+    checker.checks("ulid")(_is_ulid_format)
+    return checker
+
+
+def enable_logging(level: Union[int, str] = "INFO") -> None:
+    """
+    Forward internal events to the ``logging.getLogger("pyulid")`` logger.
+
+    Events include generator state initialization, clock moving backwards
+    (with the delta, at DEBUG when the drift tolerance absorbs it), reseeds
+    (by ``reseed()`` or in a forked child) and random-component overflow.
+    Logging is off by default and costs a single atomic load per generation
+    while disabled.
+
+    Args:
+        level: Minimum level to forward, as a name ("DEBUG", "INFO", ...) or number.
+            The ``pyulid`` logger's level is set to match.
+
+    Raises:
+        ValueError: If the level name is unknown
+    """
+    if isinstance(level, str):
+        levelno = logging.getLevelName(level.upper())
+        if not isinstance(levelno, int):
+            raise ValueError(f"Unknown logging level: {level!r}")
+    else:
+        levelno = int(level)
+
+    if not 0 < levelno < 256:
+        raise ValueError(f"Logging level must be between 1 and 255, got {levelno}")
+
+    logging.getLogger("pyulid").setLevel(levelno)
+    _pyulid_rs._set_log_level(levelno)
+
+
+def disable_logging() -> None:
+    """Stop forwarding internal events to the ``pyulid`` logger."""
+    _pyulid_rs._set_log_level(0)
+
+
+def msgspec_enc_hook(obj: object) -> str:
+    """
+    msgspec ``enc_hook`` encoding ``ULID`` objects as their canonical string.
+
+    Use with ``msgspec.json.Encoder(enc_hook=pyulid.msgspec_enc_hook)``.
+
+    Raises:
+        NotImplementedError: For any other type, as msgspec expects
+    """
+    if isinstance(obj, ULID):
+        return obj._ulid
+    raise NotImplementedError(f"Objects of type {type(obj).__name__} are not supported")
+
+
+def msgspec_msgpack_enc_hook(obj: object) -> bytes:
+    """
+    msgspec ``enc_hook`` encoding ``ULID`` objects as 16 big-endian bytes (msgpack bin).
+
+    Use with ``msgspec.msgpack.Encoder(enc_hook=pyulid.msgspec_msgpack_enc_hook)``.
+
+    Raises:
+        NotImplementedError: For any other type, as msgspec expects
+    """
+    if isinstance(obj, ULID):
+        return _pyulid_rs.ulid_to_int(obj._ulid).to_bytes(16, "big")
+    raise NotImplementedError(f"Objects of type {type(obj).__name__} are not supported")
+
+
+def msgspec_dec_hook(target: type, obj: object) -> object:
+    """
+    msgspec ``dec_hook`` decoding ``ULID`` fields from a string or 16-byte bin.
+
+    Works for both JSON and msgpack decoders. Invalid values raise ``ValueError``
+    or ``TypeError``, which msgspec reports as ``msgspec.ValidationError``.
+
+    Raises:
+        NotImplementedError: For target types other than ULID, as msgspec expects
+    """
+    if not (isinstance(target, type) and issubclass(target, ULID)):
+        raise NotImplementedError(f"Type {target!r} is not supported")
+
+    if isinstance(obj, target):
+        return obj
+    if isinstance(obj, str):
+        return target.from_str(obj)
+    if isinstance(obj, (bytes, bytearray, memoryview)):
+        raw = bytes(obj)
+        if len(raw) != 16:
+            raise UlidDecodeError(
+                f"ULID bytes must be exactly 16 bytes, got {len(raw)}"
+            )
+        return target.from_str(_pyulid_rs.ulid_from_int(int.from_bytes(raw, "big")))
+    raise TypeError(f"Expected str or bytes for ULID, got {type(obj).__name__}")
+
+
+# The real uuid.uuid4 while the patch is installed, else None
+_original_uuid4 = None
+_uuid4_patch_lock = threading.Lock()
+_uuid4_strict = False
+
+
+def _time_ordered_uuid4() -> uuid.UUID:
+    value = _pyulid_rs.ulid_to_int(_pyulid_rs.ulid())
+    if _uuid4_strict:
+        return uuid.UUID(int=value)
+    # Overwrites 6 random bits with the version nibble and RFC 4122 variant
+    return uuid.UUID(int=value, version=4)
+
+
+def install_uuid4_patch(strict_ulid: bool = False) -> None:
+    """
+    Replace ``uuid.uuid4`` with a generator of time-ordered UUIDs (opt-in).
+
+    The replacement returns ``uuid.UUID`` objects built from this library's
+    monotonic generator: a 48-bit millisecond timestamp prefix followed by the
+    random component, with the version and variant bits set so the result
+    still reports ``version == 4``. Values sort by creation time, which keeps
+    B-tree index inserts local.
+
+    Caveats:
+        - Only lookups of ``uuid.uuid4`` at call time see the patch. References
+          captured before installing (``from uuid import uuid4``, or Django's
+          ``default=uuid.uuid4`` evaluated when a model module is imported)
+          keep the original, so install as early as possible, e.g. at the top
+          of ``settings.py`` or ``manage.py``.
+        - The IDs reveal their creation time and have 74 random bits instead
+          of 122, and IDs from the same millisecond are consecutive. Don't use
+          ``uuid.uuid4()`` values as secrets while the patch is active.
+        - Only the version and variant fields look like v4; code that relies on
+          the other bits being random is not protected.
+
+    Installing again only updates ``strict_ulid``. Safe to call from any thread.
+
+    Args:
+        strict_ulid: Emit the exact ULID bits instead, so ``UUID.int`` round-trips
+            through ``pyulid.ulid_from_int``. The version and variant fields are
+            then arbitrary and the values no longer present as valid v4.
+    """
+    global _original_uuid4, _uuid4_strict
+    with _uuid4_patch_lock:
+        _uuid4_strict = strict_ulid
+        if _original_uuid4 is None:
+            _original_uuid4 = uuid.uuid4
+            uuid.uuid4 = _time_ordered_uuid4
+
+
+def uninstall_uuid4_patch() -> None:
+    """Restore the original ``uuid.uuid4``; a no-op if the patch isn't installed."""
+    global _original_uuid4
+    with _uuid4_patch_lock:
+        if _original_uuid4 is not None:
+            uuid.uuid4 = _original_uuid4
+            _original_uuid4 = None
+
+
 def parse(ulid_str: str) -> "ULID":
     """
     Parse a ULID string into a ULID object.
@@ -95,6 +583,37 @@ def parse(ulid_str: str) -> "ULID":
     return ULID.from_str(ulid_str)
 
 
+# Process-wide cache of canonical ULID instances, keyed by their 128-bit value
+_intern_cache: "weakref.WeakValueDictionary[int, ULID]" = weakref.WeakValueDictionary()
+_intern_lock = threading.Lock()
+
+
+def intern(value: ULIDUnion) -> "ULID":
+    """
+    Parse a ULID and return the canonical shared instance for its value.
+
+    Equal values always return the identical object while any reference to it
+    is alive; entries are weakly held, so unused ones are garbage collected.
+
+    Args:
+        value: ULID string or ULID object
+
+    Returns:
+        Canonical ULID object
+
+    Raises:
+        ValueError: If the ULID string is invalid
+
+    Example:
+        >>> a = pyulid.intern("01ARZ3NDEKTSV4RRFFQ69G5FAV")
+        >>> a is pyulid.intern("01arz3ndektsv4rrffq69g5fav")
+        True
+    """
+    if isinstance(value, ULID):
+        return value.intern()
+    return ULID.from_str(value).intern()
+
+
 class ULID:
     """
     A ULID (Universally Unique Lexicographically Sortable Identifier) object.
@@ -114,6 +633,9 @@ class ULID:
         >>> print(ulid.datetime)  # Get creation time
     """
 
+    # Positional class patterns: `case ULID(ts, r)` binds timestamp_ms and random
+    __match_args__ = ("timestamp_ms", "random")
+
     def __init__(self, ulid_str: Optional[str] = None) -> None:
         """
         Create a ULID instance.
@@ -128,7 +650,7 @@ class ULID:
             self._ulid = _pyulid_rs.ulid()
         else:
             if not _pyulid_rs.ulid_is_valid(ulid_str):
-                raise ValueError(f"Invalid ULID string: {ulid_str}")
+                raise UlidDecodeError(f"Invalid ULID string: {ulid_str}")
             self._ulid = _pyulid_rs.ulid_from_str(ulid_str)
 
     @classmethod
@@ -177,13 +699,11 @@ class ULID:
             ULID object with specified timestamp
         """
         if isinstance(timestamp, datetime):
-            timestamp_ms = int(timestamp.timestamp() * 1000)
+            ulid_str = _pyulid_rs.ulid_with_timestamp(int(timestamp.timestamp() * 1000))
         elif isinstance(timestamp, float):
-            timestamp_ms = int(timestamp * 1000)
+            ulid_str = _pyulid_rs.ulid_at(timestamp)
         else:
-            timestamp_ms = timestamp
-
-        ulid_str = _pyulid_rs.ulid_with_timestamp(timestamp_ms)
+            ulid_str = _pyulid_rs.ulid_with_timestamp(timestamp)
         instance = cls.__new__(cls)
         instance._ulid = ulid_str
         return instance
@@ -214,6 +734,16 @@ class ULID:
         """
         return _pyulid_rs.ulid_timestamp(self._ulid)
 
+    @property
+    def timestamp_ms(self) -> int:
+        """
+        Get the timestamp in milliseconds since Unix epoch (same as `timestamp`).
+
+        Returns:
+            Timestamp as integer milliseconds
+        """
+        return _pyulid_rs.ulid_timestamp(self._ulid)
+
     @property
     def datetime(self) -> datetime:
         """
@@ -245,6 +775,21 @@ class ULID:
         """
         return _pyulid_rs.ulid_to_uuid(self._ulid)
 
+    def intern(self) -> "ULID":
+        """
+        Return the canonical shared instance equal to this ULID.
+
+        Returns:
+            The cached instance if one is alive, otherwise this object
+            (which becomes the canonical instance)
+        """
+        key = _pyulid_rs.ulid_to_int(self._ulid)
+        with _intern_lock:
+            canonical = _intern_cache.get(key)
+            if canonical is None:
+                _intern_cache[key] = canonical = self
+            return canonical
+
     def is_valid(self) -> bool:
         """
         Check if this ULID is valid.
@@ -267,10 +812,20 @@ class ULID:
         return self._ulid.encode("utf-8")
 
     # Comparisons (ULIDs are lexicographically sortable)
+    @staticmethod
+    def _datetime_ms(value: datetime) -> int:
+        # Ordering against datetimes uses whole epoch milliseconds (floored), so a
+        # datetime inside the ULID's millisecond compares as neither before nor after
+        if value.tzinfo is None or value.utcoffset() is None:
+            raise TypeError("cannot compare ULID with a naive datetime")
+        return (value - _EPOCH) // timedelta(milliseconds=1)
+
     def __eq__(self, other: object) -> bool:
         """
         Check equality with another ULID or string.
 
+        Comparing with a datetime is always False; use ordering operators instead.
+
         Args:
             other: ULID object or string to compare with
 
@@ -283,38 +838,91 @@ class ULID:
             return self._ulid == other
         return False
 
-    def __lt__(self, other: ULIDUnion) -> bool:
+    def __lt__(self, other: Union[ULIDUnion, datetime]) -> bool:
         """Check if this ULID is less than another (lexicographically)."""
         if isinstance(other, ULID):
             return self._ulid < other._ulid
         elif isinstance(other, str):
             return self._ulid < other
+        elif isinstance(other, datetime):
+            return self.timestamp < self._datetime_ms(other)
         return NotImplemented
 
-    def __le__(self, other: ULIDUnion) -> bool:
+    def __le__(self, other: Union[ULIDUnion, datetime]) -> bool:
         """Check if this ULID is less than or equal to another."""
         if isinstance(other, ULID):
             return self._ulid <= other._ulid
         elif isinstance(other, str):
             return self._ulid <= other
+        elif isinstance(other, datetime):
+            return self.timestamp <= self._datetime_ms(other)
         return NotImplemented
 
-    def __gt__(self, other: ULIDUnion) -> bool:
+    def __gt__(self, other: Union[ULIDUnion, datetime]) -> bool:
         """Check if this ULID is greater than another (lexicographically)."""
         if isinstance(other, ULID):
             return self._ulid > other._ulid
         elif isinstance(other, str):
             return self._ulid > other
+        elif isinstance(other, datetime):
+            return self.timestamp > self._datetime_ms(other)
         return NotImplemented
 
-    def __ge__(self, other: ULIDUnion) -> bool:
+    def __ge__(self, other: Union[ULIDUnion, datetime]) -> bool:
         """Check if this ULID is greater than or equal to another."""
         if isinstance(other, ULID):
             return self._ulid >= other._ulid
         elif isinstance(other, str):
             return self._ulid >= other
+        elif isinstance(other, datetime):
+            return self.timestamp >= self._datetime_ms(other)
         return NotImplemented
 
     def __hash__(self) -> int:
         """Make ULID hashable (can be used in sets, dict keys)."""
         return hash(self._ulid)
+
+    # Timestamp arithmetic (random component is preserved)
+    def _shifted(self, delta_ms: int) -> Self:
+        timestamp = self.timestamp + delta_ms
+        if not 0 <= timestamp < 2**48:
+            raise OverflowError(
+                "ULID timestamp out of range after timedelta arithmetic"
+            )
+        instance = type(self).__new__(type(self))
+        instance._ulid = _pyulid_rs.ulid_set_timestamp(self._ulid, timestamp)
+        return instance
+
+    @staticmethod
+    def _timedelta_ms(delta: timedelta) -> int:
+        # Sub-millisecond components truncate toward zero
+        micros = (delta.days * 86400 + delta.seconds) * 1_000_000 + delta.microseconds
+        return micros // 1000 if micros >= 0 else -(-micros // 1000)
+
+    def __add__(self, other: timedelta) -> Self:
+        """
+        Shift the timestamp forward by a timedelta, keeping the random part.
+
+        Sub-millisecond components of the timedelta are truncated toward zero.
+
+        Raises:
+            OverflowError: If the result leaves the 48-bit timestamp range
+        """
+        if isinstance(other, timedelta):
+            return self._shifted(self._timedelta_ms(other))
+        return NotImplemented
+
+    __radd__ = __add__
+
+    def __sub__(self, other: timedelta) -> Self:
+        """
+        Shift the timestamp backward by a timedelta, keeping the random part.
+
+        Sub-millisecond components of the timedelta are truncated toward zero.
+
+        Raises:
+            OverflowError: If the result leaves the 48-bit timestamp range
+        """
+        if isinstance(other, timedelta):
+            return self._shifted(-self._timedelta_ms(other))
+        return NotImplemented
@@ -0,0 +1,224 @@
+use pyo3::create_exception;
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::prelude::*;
+
+use crate::array::UlidArray;
+use crate::{encode_base32_internal, UlidArg};
+
+create_exception!(
+    pyulid,
+    AmbiguousPrefixError,
+    PyValueError,
+    "A prefix matches more than one ULID; `candidates` lists them."
+);
+
+const MAX_LISTED: usize = 10;
+
+fn collect_strings(seq: &Bound<'_, PyAny>) -> PyResult<Vec<String>> {
+    if let Ok(array) = seq.downcast::<UlidArray>() {
+        return Ok(array
+            .borrow()
+            .values()
+            .iter()
+            .map(|&v| encode_base32_internal(v))
+            .collect());
+    }
+    seq.try_iter()?
+        .map(|item| Ok(encode_base32_internal(item?.extract::<UlidArg>()?.0 .0)))
+        .collect()
+}
+
+fn common_prefix_len(a: &str, b: &str) -> usize {
+    a.bytes().zip(b.bytes()).take_while(|(x, y)| x == y).count()
+}
+
+/// Shortest prefixes (at least `min_len` characters) that are unique within `seq`.
+///
+/// The result is aligned with the input. Sorting once means each ULID only has to be
+/// compared with its two neighbours. Duplicates can't be told apart and keep all 26.
+#[pyfunction]
+#[pyo3(signature = (seq, min_len = 6))]
+pub fn ulid_abbreviate(
+    py: Python<'_>,
+    seq: &Bound<'_, PyAny>,
+    min_len: usize,
+) -> PyResult<Vec<String>> {
+    if !(1..=26).contains(&min_len) {
+        return Err(PyValueError::new_err(format!(
+            "min_len must be between 1 and 26, got {}",
+            min_len
+        )));
+    }
+    let strings = collect_strings(seq)?;
+
+    Ok(py.allow_threads(|| {
+        let mut order: Vec<usize> = (0..strings.len()).collect();
+        order.sort_unstable_by(|&a, &b| strings[a].cmp(&strings[b]));
+
+        let mut lengths = vec![min_len; strings.len()];
+        for pair in order.windows(2) {
+            let shared = common_prefix_len(&strings[pair[0]], &strings[pair[1]]);
+            let needed = (shared + 1).min(26);
+            for &index in pair {
+                lengths[index] = lengths[index].max(needed);
+            }
+        }
+
+        strings
+            .iter()
+            .zip(lengths)
+            .map(|(s, len)| s[..len].to_string())
+            .collect()
+    }))
+}
+
+/// Resolve an abbreviation from `ulid_abbreviate` back to the full ULID in `seq`.
+///
+/// Matching is case-insensitive. Raises `KeyError` when nothing matches and
+/// `AmbiguousPrefixError` when more than one distinct ULID does.
+#[pyfunction]
+pub fn ulid_expand(py: Python<'_>, prefix: &str, seq: &Bound<'_, PyAny>) -> PyResult<String> {
+    let prefix = prefix.to_ascii_uppercase();
+    let mut candidates: Vec<String> = collect_strings(seq)?
+        .into_iter()
+        .filter(|s| s.starts_with(&prefix))
+        .collect();
+    candidates.sort_unstable();
+    candidates.dedup();
+
+    match candidates.len() {
+        0 => Err(PyKeyError::new_err(format!(
+            "no ULID matches prefix '{}'",
+            prefix
+        ))),
+        1 => Ok(candidates.pop().unwrap()),
+        _ => {
+            // The message lists a few; the full set is on `candidates`
+            let mut listed = candidates[..candidates.len().min(MAX_LISTED)].join(", ");
+            if candidates.len() > MAX_LISTED {
+                listed.push_str(", ...");
+            }
+            let err = AmbiguousPrefixError::new_err(format!(
+                "prefix '{}' matches {} ULIDs: {}",
+                prefix,
+                candidates.len(),
+                listed
+            ));
+            err.value(py).setattr("candidates", candidates)?;
+            Err(err)
+        }
+    }
+}
+
+/// Uppercase and apply the Crockford substitutions (I, L → 1; O → 0). Non-ASCII
+/// characters become one byte that never matches, so distances count characters.
+fn normalize_typed(text: &str) -> Vec<u8> {
+    text.chars()
+        .map(|c| match c.to_ascii_uppercase() {
+            'I' | 'L' => b'1',
+            'O' => b'0',
+            c if c.is_ascii() => c as u8,
+            _ => 0xFF,
+        })
+        .collect()
+}
+
+/// Levenshtein distance if it is at most `max`, computed only in the diagonal band
+/// `|i - j| <= max` and abandoned as soon as a whole row exceeds `max`
+fn bounded_distance(
+    a: &[u8],
+    b: &[u8],
+    max: usize,
+    prev: &mut Vec<usize>,
+    cur: &mut Vec<usize>,
+) -> Option<usize> {
+    if a.len().abs_diff(b.len()) > max {
+        return None;
+    }
+    let over = max + 1;
+    let m = b.len();
+    prev.clear();
+    prev.extend((0..=m).map(|j| if j <= max { j } else { over }));
+    cur.clear();
+    cur.resize(m + 1, over);
+
+    for i in 1..=a.len() {
+        let lo = i.saturating_sub(max).max(1);
+        let hi = (i + max).min(m);
+        cur[0] = if i <= max { i } else { over };
+        cur[lo - 1] = if lo == 1 { cur[0] } else { over };
+
+        let mut row_min = cur[lo - 1];
+        for j in lo..=hi {
+            let cost = usize::from(a[i - 1] != b[j - 1]);
+            let value = (prev[j - 1] + cost)
+                .min(prev[j] + 1)
+                .min(cur[j - 1] + 1)
+                .min(over);
+            cur[j] = value;
+            row_min = row_min.min(value);
+        }
+        if hi < m {
+            cur[hi + 1] = over;
+        }
+        if row_min > max {
+            return None;
+        }
+        std::mem::swap(prev, cur);
+    }
+
+    (prev[m] <= max).then_some(prev[m])
+}
+
+#[derive(IntoPyObject)]
+pub enum Closest {
+    One(String),
+    Ties(Vec<String>),
+}
+
+/// Nearest ULID in `seq` to a possibly mistyped `candidate`, by edit distance.
+///
+/// Both sides are compared after normalization (uppercase, I/L → 1, O → 0).
+/// Returns `(match, distance)`, where `match` is a string, or a sorted list when
+/// several ULIDs tie, or `None` if nothing is within `max_distance` edits.
+#[pyfunction]
+#[pyo3(signature = (candidate, seq, max_distance = 2))]
+pub fn ulid_closest(
+    py: Python<'_>,
+    candidate: &str,
+    seq: &Bound<'_, PyAny>,
+    max_distance: usize,
+) -> PyResult<Option<(Closest, usize)>> {
+    let target = normalize_typed(candidate);
+    let strings = collect_strings(seq)?;
+
+    Ok(py.allow_threads(|| {
+        let (mut prev, mut cur) = (Vec::new(), Vec::new());
+        let mut best = max_distance;
+        let mut matches: Vec<&str> = Vec::new();
+
+        for s in &strings {
+            // Anything worse than the current best can be abandoned early
+            let Some(distance) = bounded_distance(&target, s.as_bytes(), best, &mut prev, &mut cur)
+            else {
+                continue;
+            };
+            if distance < best || matches.is_empty() {
+                best = distance;
+                matches.clear();
+            }
+            matches.push(s);
+        }
+
+        matches.sort_unstable();
+        matches.dedup();
+        match matches.as_slice() {
+            [] => None,
+            [one] => Some((Closest::One(one.to_string()), best)),
+            many => Some((
+                Closest::Ties(many.iter().map(|s| s.to_string()).collect()),
+                best,
+            )),
+        }
+    }))
+}
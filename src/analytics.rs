@@ -0,0 +1,248 @@
+use std::collections::BTreeMap;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+use crate::array::{collect_values, decode_item, UlidArray};
+use crate::{encode_base32_internal, signals, Ulid};
+
+#[derive(IntoPyObject)]
+pub enum Sessions {
+    Groups(Vec<Vec<String>>),
+    Starts(Vec<usize>),
+}
+
+/// Split ULIDs into sessions separated by at least `gap_ms` of inactivity.
+///
+/// The input is sorted first (a no-op for already ordered IDs). Returns a list of
+/// sessions, each a list of canonical ULIDs in order, or with `indices=True` the
+/// position in the sorted sequence where each session starts.
+#[pyfunction]
+#[pyo3(signature = (seq, gap_ms, indices = false))]
+pub fn ulid_sessionize(
+    py: Python<'_>,
+    seq: &Bound<'_, PyAny>,
+    gap_ms: i64,
+    indices: bool,
+) -> PyResult<Sessions> {
+    if gap_ms <= 0 {
+        return Err(PyValueError::new_err(format!(
+            "gap_ms must be positive, got {}",
+            gap_ms
+        )));
+    }
+    let mut values = collect_values(py, seq)?;
+
+    let starts = py.allow_threads(|| {
+        if !values.is_sorted() {
+            values.sort_unstable();
+        }
+
+        let mut starts = Vec::new();
+        let mut previous: Option<u64> = None;
+        for (i, &value) in values.iter().enumerate() {
+            let timestamp = Ulid(value).timestamp_ms();
+            if previous.is_none_or(|p| timestamp - p >= gap_ms as u64) {
+                starts.push(i);
+            }
+            previous = Some(timestamp);
+        }
+        starts
+    });
+
+    if indices {
+        return Ok(Sessions::Starts(starts));
+    }
+
+    let ends = starts.iter().skip(1).copied().chain([values.len()]);
+    let groups = starts
+        .iter()
+        .zip(ends)
+        .map(|(&start, end)| {
+            values[start..end]
+                .iter()
+                .map(|&v| encode_base32_internal(v))
+                .collect()
+        })
+        .collect();
+    Ok(Sessions::Groups(groups))
+}
+
+/// Algorithm R reservoir holding a uniform sample of everything offered to it
+struct Reservoir {
+    seen: usize,
+    items: Vec<u128>,
+}
+
+impl Reservoir {
+    fn new() -> Self {
+        Reservoir {
+            seen: 0,
+            items: Vec::new(),
+        }
+    }
+
+    fn offer(&mut self, value: u128, capacity: usize, rng: &mut StdRng) {
+        if self.items.len() < capacity {
+            self.items.push(value);
+        } else {
+            let slot = rng.random_range(0..=self.seen);
+            if slot < capacity {
+                self.items[slot] = value;
+            }
+        }
+        self.seen += 1;
+    }
+
+    /// A uniform subset of `n` items (partial Fisher-Yates)
+    fn take(mut self, n: usize, rng: &mut StdRng) -> Vec<u128> {
+        for i in 0..n {
+            let j = rng.random_range(i..self.items.len());
+            self.items.swap(i, j);
+        }
+        self.items.truncate(n);
+        self.items
+    }
+}
+
+/// Largest-remainder split of `k` across buckets in proportion to their sizes
+fn proportional_quotas(counts: &[usize], k: usize) -> Vec<usize> {
+    let total: usize = counts.iter().sum();
+    let k = k.min(total);
+    let exact: Vec<(usize, usize)> = counts
+        .iter()
+        .map(|&c| ((k * c) / total, (k * c) % total))
+        .collect();
+
+    let mut quotas: Vec<usize> = exact.iter().map(|&(q, _)| q).collect();
+    let mut order: Vec<usize> = (0..counts.len()).collect();
+    // Stable sort keeps bucket order on equal remainders, so results are deterministic
+    order.sort_by(|&a, &b| exact[b].1.cmp(&exact[a].1));
+    let missing = k - quotas.iter().sum::<usize>();
+    for &bucket in order.iter().take(missing) {
+        quotas[bucket] += 1;
+    }
+    quotas
+}
+
+/// Up to `per_bucket` from every bucket, levelled down evenly if that exceeds `k`
+fn even_quotas(counts: &[usize], per_bucket: usize, k: usize) -> Vec<usize> {
+    let caps: Vec<usize> = counts.iter().map(|&c| c.min(per_bucket)).collect();
+    if caps.iter().sum::<usize>() <= k {
+        return caps;
+    }
+
+    // Highest level where every bucket can take min(cap, level) without passing k
+    let mut level = 0;
+    while caps.iter().map(|&c| c.min(level + 1)).sum::<usize>() <= k {
+        level += 1;
+    }
+    let mut quotas: Vec<usize> = caps.iter().map(|&c| c.min(level)).collect();
+    let mut missing = k - quotas.iter().sum::<usize>();
+    for (quota, &cap) in quotas.iter_mut().zip(&caps) {
+        if missing == 0 {
+            break;
+        }
+        if cap > level {
+            *quota += 1;
+            missing -= 1;
+        }
+    }
+    quotas
+}
+
+/// Random sample of `k` ULIDs from `seq`, returned sorted.
+///
+/// `method="uniform"` is a single reservoir-sampling pass. `method="stratified"`
+/// buckets by `timestamp // bucket_ms` and allocates `k` across buckets in proportion
+/// to their sizes, or with `per_bucket=n` takes up to `n` from every bucket
+/// (levelled down evenly when that would exceed `k`). A `seed` makes the result
+/// reproducible; `k` larger than the population returns everything.
+///
+/// Either way `seq` is read once and only the reservoirs are kept, so memory is
+/// O(k), or O(k) per bucket when stratified, however long the input is.
+#[pyfunction]
+#[pyo3(signature = (seq, k, method = "uniform", seed = None, *, bucket_ms = None, per_bucket = None))]
+pub fn ulid_sample(
+    py: Python<'_>,
+    seq: &Bound<'_, PyAny>,
+    k: usize,
+    method: &str,
+    seed: Option<u64>,
+    bucket_ms: Option<i64>,
+    per_bucket: Option<usize>,
+) -> PyResult<Vec<String>> {
+    let bucket_ms = match (method, bucket_ms) {
+        ("uniform", None) if per_bucket.is_none() => None,
+        ("uniform", _) => {
+            return Err(PyValueError::new_err(
+                "bucket_ms and per_bucket only apply to method='stratified'",
+            ))
+        }
+        ("stratified", Some(width)) if width > 0 => Some(width as u64),
+        ("stratified", Some(width)) => {
+            return Err(PyValueError::new_err(format!(
+                "bucket_ms must be positive, got {}",
+                width
+            )))
+        }
+        ("stratified", None) => {
+            return Err(PyValueError::new_err(
+                "method='stratified' requires bucket_ms",
+            ))
+        }
+        _ => {
+            return Err(PyValueError::new_err(format!(
+                "method must be 'uniform' or 'stratified', got '{}'",
+                method
+            )))
+        }
+    };
+    let mut rng = match seed {
+        Some(seed) => StdRng::seed_from_u64(seed),
+        None => StdRng::from_rng(&mut rand::rng()),
+    };
+
+    // One pass that keeps only the reservoirs; each bucket keeps at most as many as
+    // it could ever be asked for
+    let capacity = per_bucket.unwrap_or(k).min(k);
+    let mut uniform = Reservoir::new();
+    let mut buckets: BTreeMap<u64, Reservoir> = BTreeMap::new();
+    let mut offer = |value: u128, rng: &mut StdRng| match bucket_ms {
+        None => uniform.offer(value, k, rng),
+        Some(width) => buckets
+            .entry(Ulid(value).timestamp_ms() / width)
+            .or_insert_with(Reservoir::new)
+            .offer(value, capacity, rng),
+    };
+    if let Ok(array) = seq.downcast::<UlidArray>() {
+        let array = array.borrow();
+        let values = array.values();
+        py.allow_threads(|| values.iter().for_each(|&value| offer(value, &mut rng)));
+    } else {
+        for (i, item) in seq.try_iter()?.enumerate() {
+            signals::check_every_with_gil(py, i)?;
+            offer(decode_item(&item?, i)?, &mut rng);
+        }
+    }
+
+    let mut sample = if bucket_ms.is_none() {
+        uniform.items
+    } else {
+        let counts: Vec<usize> = buckets.values().map(|b| b.seen).collect();
+        let quotas = match per_bucket {
+            Some(n) => even_quotas(&counts, n, k),
+            None => proportional_quotas(&counts, k),
+        };
+        buckets
+            .into_values()
+            .zip(quotas)
+            .flat_map(|(reservoir, quota)| reservoir.take(quota, &mut rng))
+            .collect()
+    };
+
+    sample.sort_unstable();
+    Ok(sample.into_iter().map(encode_base32_internal).collect())
+}
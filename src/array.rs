@@ -0,0 +1,340 @@
+#[cfg(all(any(Py_3_11, not(Py_LIMITED_API)), not(feature = "forbid-unsafe")))]
+use std::ffi::{c_int, c_void};
+#[cfg(all(any(Py_3_11, not(Py_LIMITED_API)), not(feature = "forbid-unsafe")))]
+use std::ptr;
+
+use pyo3::exceptions::{PyBufferError, PyIndexError, PyTypeError};
+#[cfg(all(any(Py_3_11, not(Py_LIMITED_API)), not(feature = "forbid-unsafe")))]
+use pyo3::ffi;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyList, PyMemoryView, PySlice, PyString};
+
+use crate::{
+    decode_ascii, decode_ascii_checked, encode_base32_internal, errors, generate_batch, logging,
+    signals, Invalid, UlidArg,
+};
+
+/// Contiguous array of ULIDs stored as 16-byte `u128` values.
+///
+/// The buffer protocol exposes the raw records as `len * 16` read-only bytes in
+/// native byte order; `to_bytes()` gives the portable big-endian form. The
+/// `forbid-unsafe` build has no buffer protocol; `to_memoryview()` gives the same
+/// view in every build, copied there.
+#[pyclass(module = "pyulid", sequence)]
+pub struct UlidArray {
+    values: Vec<u128>,
+    // Live buffer exports; resizing is refused while any exist
+    exports: usize,
+}
+
+impl UlidArray {
+    fn check_resizable(&self) -> PyResult<()> {
+        if self.exports > 0 {
+            return Err(PyBufferError::new_err(
+                "cannot resize UlidArray while a buffer export exists",
+            ));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn values(&self) -> &[u128] {
+        &self.values
+    }
+
+    fn normalize_index(&self, index: isize) -> PyResult<usize> {
+        let len = self.values.len() as isize;
+        let resolved = if index < 0 { index + len } else { index };
+        if resolved < 0 || resolved >= len {
+            return Err(PyIndexError::new_err("UlidArray index out of range"));
+        }
+        Ok(resolved as usize)
+    }
+}
+
+/// Decode any iterable of ULIDs, releasing the GIL for the decode itself
+pub(crate) fn collect_values(py: Python<'_>, iterable: &Bound<'_, PyAny>) -> PyResult<Vec<u128>> {
+    if let Ok(other) = iterable.downcast::<UlidArray>() {
+        return Ok(other.borrow().values.clone());
+    }
+
+    // Strings are copied into one flat buffer under the GIL; anything else (ULID
+    // objects) is decoded immediately and re-encoded so the decode pass stays uniform
+    let mut raw: Vec<u8> = Vec::new();
+    let mut count = 0usize;
+    for item in iterable.try_iter()? {
+        let item = item?;
+        signals::check_every_with_gil(py, count)?;
+        with_entry_text(&item, count, |text| raw.extend_from_slice(text))?;
+        count += 1;
+    }
+
+    py.allow_threads(|| {
+        let mut values = Vec::with_capacity(count);
+        for (i, chunk) in raw.chunks_exact(26).enumerate() {
+            signals::check_every(i)?;
+            values.push(decode_entry(chunk, i)?);
+        }
+        Ok(values)
+    })
+}
+
+/// Decode entry `index` of an iterable on its own, for single-pass consumers that
+/// can't hold the whole input
+pub(crate) fn decode_item(item: &Bound<'_, PyAny>, index: usize) -> PyResult<u128> {
+    with_entry_text(item, index, |text| decode_entry(text, index))?
+}
+
+/// Hand the 26 ASCII bytes of one entry to `f`; ULID objects are re-encoded first
+fn with_entry_text<R>(
+    item: &Bound<'_, PyAny>,
+    index: usize,
+    f: impl FnOnce(&[u8]) -> R,
+) -> PyResult<R> {
+    let check_length = |len: usize| {
+        if len != 26 {
+            return Err(errors::decode_error(format!(
+                "Invalid ULID at index {}: must be exactly 26 characters",
+                index
+            )));
+        }
+        Ok(())
+    };
+
+    if let Ok(s) = item.downcast::<PyString>() {
+        let text = s.to_cow()?;
+        check_length(text.len())?;
+        return Ok(f(text.as_bytes()));
+    }
+    if let Ok(b) = item.downcast::<PyBytes>() {
+        check_length(b.as_bytes().len())?;
+        return Ok(f(b.as_bytes()));
+    }
+    let ulid: UlidArg = item.extract()?;
+    Ok(f(encode_base32_internal(ulid.0 .0).as_bytes()))
+}
+
+/// Decode one 26-byte entry, naming its index when it isn't a ULID
+fn decode_entry(chunk: &[u8], index: usize) -> PyResult<u128> {
+    decode_ascii_checked(chunk).ok_or_else(|| {
+        let reason = match decode_ascii(chunk) {
+            Some(_) => Invalid::Overflow(chunk[0] as char).message(),
+            None => "invalid Base32 character".to_string(),
+        };
+        errors::decode_error(format!("Invalid ULID at index {}: {}", index, reason))
+    })
+}
+
+#[pymethods]
+impl UlidArray {
+    #[new]
+    #[pyo3(signature = (iterable = None))]
+    fn new(py: Python<'_>, iterable: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let values = match iterable {
+            Some(iterable) => collect_values(py, iterable)?,
+            None => Vec::new(),
+        };
+        Ok(UlidArray { values, exports: 0 })
+    }
+
+    /// Build from a blob of big-endian 16-byte records (the `to_bytes()` format)
+    #[staticmethod]
+    fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        if !data.len().is_multiple_of(16) {
+            return Err(errors::decode_error(format!(
+                "byte length must be a multiple of 16, got {}",
+                data.len()
+            )));
+        }
+
+        let values = data
+            .chunks_exact(16)
+            .map(|chunk| u128::from_be_bytes(chunk.try_into().unwrap()))
+            .collect();
+        Ok(UlidArray { values, exports: 0 })
+    }
+
+    fn __len__(&self) -> usize {
+        self.values.len()
+    }
+
+    fn __getitem__(&self, py: Python<'_>, index: &Bound<'_, PyAny>) -> PyResult<PyObject> {
+        if let Ok(slice) = index.downcast::<PySlice>() {
+            let indices = slice.indices(self.values.len() as isize)?;
+            let values = (0..indices.slicelength)
+                .map(|i| self.values[(indices.start + i as isize * indices.step) as usize])
+                .collect();
+            return Ok(UlidArray { values, exports: 0 }
+                .into_pyobject(py)?
+                .into_any()
+                .unbind());
+        }
+
+        let index: isize = index
+            .extract()
+            .map_err(|_| PyTypeError::new_err("UlidArray indices must be integers or slices"))?;
+        let value = self.values[self.normalize_index(index)?];
+        Ok(encode_base32_internal(value)
+            .into_pyobject(py)?
+            .into_any()
+            .unbind())
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> UlidArrayIter {
+        UlidArrayIter {
+            array: slf.into(),
+            index: 0,
+        }
+    }
+
+    fn __eq__(&self, other: PyRef<'_, Self>) -> bool {
+        self.values == other.values
+    }
+
+    fn __repr__(&self) -> String {
+        format!("UlidArray(len={})", self.values.len())
+    }
+
+    fn __sizeof__(&self) -> usize {
+        std::mem::size_of::<Self>() + self.values.capacity() * std::mem::size_of::<u128>()
+    }
+
+    /// Append one ULID (string or ULID object)
+    fn append(&mut self, value: UlidArg) -> PyResult<()> {
+        self.check_resizable()?;
+        self.values.push(value.0 .0);
+        Ok(())
+    }
+
+    /// Append every ULID from an iterable
+    fn extend(&mut self, py: Python<'_>, iterable: &Bound<'_, PyAny>) -> PyResult<()> {
+        self.check_resizable()?;
+        let values = collect_values(py, iterable)?;
+        self.values.extend(values);
+        Ok(())
+    }
+
+    /// All elements as canonical ULID strings
+    fn to_list<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyList>> {
+        PyList::new(py, self.values.iter().map(|&v| encode_base32_internal(v)))
+    }
+
+    /// All elements as big-endian 16-byte records
+    fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        PyBytes::new_with(py, self.values.len() * 16, |buffer| {
+            for (chunk, value) in buffer.chunks_exact_mut(16).zip(&self.values) {
+                chunk.copy_from_slice(&value.to_be_bytes());
+            }
+            Ok(())
+        })
+    }
+
+    /// The records in native byte order as a read-only memoryview: zero-copy through
+    /// the buffer protocol, or over an owned copy in the `forbid-unsafe` build
+    fn to_memoryview<'py>(slf: &Bound<'py, Self>) -> PyResult<Bound<'py, PyMemoryView>> {
+        #[cfg(all(any(Py_3_11, not(Py_LIMITED_API)), not(feature = "forbid-unsafe")))]
+        return PyMemoryView::from(slf.as_any());
+
+        #[cfg(not(all(any(Py_3_11, not(Py_LIMITED_API)), not(feature = "forbid-unsafe"))))]
+        {
+            let this = slf.borrow();
+            let records = PyBytes::new_with(slf.py(), this.values.len() * 16, |buffer| {
+                for (chunk, value) in buffer.chunks_exact_mut(16).zip(&this.values) {
+                    chunk.copy_from_slice(&value.to_ne_bytes());
+                }
+                Ok(())
+            })?;
+            PyMemoryView::from(records.as_any())
+        }
+    }
+
+    // The buffer protocol only joined the limited API in 3.11, and needs raw pointers
+    #[cfg(all(any(Py_3_11, not(Py_LIMITED_API)), not(feature = "forbid-unsafe")))]
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        if view.is_null() {
+            return Err(PyBufferError::new_err("View is null"));
+        }
+        if (flags & ffi::PyBUF_WRITABLE) == ffi::PyBUF_WRITABLE {
+            return Err(PyBufferError::new_err("UlidArray buffers are read-only"));
+        }
+
+        let mut this = slf.borrow_mut();
+        this.exports += 1;
+        let data = this.values.as_ptr();
+        let len = (this.values.len() * 16) as isize;
+        drop(this);
+
+        // SAFETY: `view` is non-null and owned by the caller; the data pointer stays valid
+        // because the Vec can't be resized while `exports` is non-zero
+        unsafe {
+            (*view).obj = slf.into_any().into_ptr();
+            (*view).buf = data as *mut c_void;
+            (*view).len = len;
+            (*view).readonly = 1;
+            (*view).itemsize = 1;
+            (*view).format = if (flags & ffi::PyBUF_FORMAT) == ffi::PyBUF_FORMAT {
+                c"B".as_ptr() as *mut _
+            } else {
+                ptr::null_mut()
+            };
+            (*view).ndim = 1;
+            (*view).shape = if (flags & ffi::PyBUF_ND) == ffi::PyBUF_ND {
+                &mut (*view).len
+            } else {
+                ptr::null_mut()
+            };
+            (*view).strides = if (flags & ffi::PyBUF_STRIDES) == ffi::PyBUF_STRIDES {
+                &mut (*view).itemsize
+            } else {
+                ptr::null_mut()
+            };
+            (*view).suboffsets = ptr::null_mut();
+            (*view).internal = ptr::null_mut();
+        }
+        Ok(())
+    }
+
+    #[cfg(all(any(Py_3_11, not(Py_LIMITED_API)), not(feature = "forbid-unsafe")))]
+    unsafe fn __releasebuffer__(&mut self, _view: *mut ffi::Py_buffer) {
+        self.exports -= 1;
+    }
+}
+
+#[pyclass(module = "pyulid")]
+pub struct UlidArrayIter {
+    array: Py<UlidArray>,
+    index: usize,
+}
+
+#[pymethods]
+impl UlidArrayIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> Option<String> {
+        let array = self.array.borrow(py);
+        let value = array.values.get(self.index)?;
+        self.index += 1;
+        Some(encode_base32_internal(*value))
+    }
+}
+
+/// Generate `n` monotonic ULIDs straight into a `UlidArray`.
+///
+/// The state lock is released between chunks to check for Ctrl-C, so ULIDs from other
+/// threads may interleave with a large batch; the batch itself is still increasing.
+#[pyfunction]
+pub fn ulid_batch_array(py: Python<'_>, n: usize) -> PyResult<UlidArray> {
+    errors::guard(py, "ulid_batch_array", || {
+        let values = py.allow_threads(|| generate_batch(n));
+        logging::flush(py);
+
+        let values = values?;
+
+        Ok(UlidArray { values, exports: 0 })
+    })
+}
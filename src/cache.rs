@@ -0,0 +1,128 @@
+use std::collections::{BTreeMap, HashMap};
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+/// Bounded map from the exact 26 input bytes to their decoded value.
+///
+/// Only successful decodes are stored, so every error is still produced by the
+/// normal parsing path and cached and uncached results can't differ.
+struct DecodeCache {
+    capacity: usize,
+    entries: HashMap<[u8; 26], (u128, u64)>,
+    // Access order: oldest tick first, same scheme as KeyedUlidGenerator
+    lru: BTreeMap<u64, [u8; 26]>,
+    tick: u64,
+}
+
+impl DecodeCache {
+    fn get(&mut self, key: &[u8; 26]) -> Option<u128> {
+        self.tick += 1;
+        let entry = self.entries.get_mut(key)?;
+        self.lru.remove(&entry.1);
+        entry.1 = self.tick;
+        self.lru.insert(self.tick, *key);
+        Some(entry.0)
+    }
+
+    fn insert(&mut self, key: [u8; 26], value: u128) {
+        self.tick += 1;
+        if let Some(entry) = self.entries.get_mut(&key) {
+            self.lru.remove(&entry.1);
+            entry.1 = self.tick;
+        } else {
+            if self.entries.len() >= self.capacity {
+                if let Some((_, oldest)) = self.lru.pop_first() {
+                    self.entries.remove(&oldest);
+                }
+            }
+            self.entries.insert(key, (value, self.tick));
+        }
+        self.lru.insert(self.tick, key);
+    }
+}
+
+// Checked before touching the lock so a disabled cache costs one atomic load
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static CACHE: Mutex<Option<DecodeCache>> = Mutex::new(None);
+static HITS: AtomicU64 = AtomicU64::new(0);
+static MISSES: AtomicU64 = AtomicU64::new(0);
+
+// Poison-tolerant for the same reason as the global state lock
+fn lock() -> MutexGuard<'static, Option<DecodeCache>> {
+    CACHE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Resize the cache (0 disables it), dropping every entry and resetting the counters
+pub(crate) fn set_capacity(capacity: usize) {
+    let mut cache = lock();
+    *cache = (capacity > 0).then(|| DecodeCache {
+        capacity,
+        entries: HashMap::new(),
+        lru: BTreeMap::new(),
+        tick: 0,
+    });
+    HITS.store(0, Ordering::Relaxed);
+    MISSES.store(0, Ordering::Relaxed);
+    ENABLED.store(capacity > 0, Ordering::Release);
+}
+
+/// Drop every entry but keep the capacity and counters
+pub(crate) fn clear() {
+    if let Some(cache) = lock().as_mut() {
+        cache.entries.clear();
+        cache.lru.clear();
+    }
+}
+
+#[inline(always)]
+pub(crate) fn enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+pub(crate) fn capacity() -> usize {
+    lock().as_ref().map_or(0, |cache| cache.capacity)
+}
+
+/// Previously decoded value of `ulid_str`, counting the hit or miss
+#[inline(always)]
+pub(crate) fn lookup(ulid_str: &[u8]) -> Option<u128> {
+    if !enabled() {
+        return None;
+    }
+    let key: &[u8; 26] = ulid_str.try_into().ok()?;
+    let value = lock().as_mut().and_then(|cache| cache.get(key));
+    match value {
+        Some(_) => HITS.fetch_add(1, Ordering::Relaxed),
+        None => MISSES.fetch_add(1, Ordering::Relaxed),
+    };
+    value
+}
+
+/// Remember a successful decode of `ulid_str`
+#[inline(always)]
+pub(crate) fn store(ulid_str: &[u8], value: u128) {
+    if !enabled() {
+        return;
+    }
+    if let (Ok(key), Some(cache)) = (ulid_str.try_into(), lock().as_mut()) {
+        cache.insert(key, value);
+    }
+}
+
+/// `hits` and `misses` since the cache was last configured, plus its current `size`
+/// and `capacity` (0 while disabled)
+pub(crate) fn stats(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let (capacity, size) = lock()
+        .as_ref()
+        .map_or((0, 0), |cache| (cache.capacity, cache.entries.len()));
+
+    let stats = PyDict::new(py);
+    stats.set_item("hits", HITS.load(Ordering::Relaxed))?;
+    stats.set_item("misses", MISSES.load(Ordering::Relaxed))?;
+    stats.set_item("size", size)?;
+    stats.set_item("capacity", capacity)?;
+    Ok(stats)
+}
@@ -0,0 +1,168 @@
+use std::ffi::OsString;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+
+use crate::{encode_base32_internal, parse_ulid, UlidArg};
+
+// Distinguishes temp files of concurrent writers within one process
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Persistent high-water mark: the greatest ULID an incremental job has processed.
+///
+/// The file holds the canonical ULID and is only ever replaced by an atomic
+/// temp-file-plus-rename. Updates serialize on an exclusive lock of `<path>.lock`, so
+/// concurrent `advance` calls from any number of threads or processes can never move
+/// the mark backwards. Unreadable contents raise instead of being treated as empty.
+#[pyclass(module = "pyulid", frozen)]
+pub struct UlidCheckpoint {
+    path: PathBuf,
+    lock_path: PathBuf,
+}
+
+fn with_suffix(path: &Path, suffix: &str) -> PathBuf {
+    let mut name: OsString = path.as_os_str().to_owned();
+    name.push(suffix);
+    PathBuf::from(name)
+}
+
+/// Either a stored value or a reason the file isn't a valid checkpoint
+enum Stored {
+    Missing,
+    Value(u128),
+    Corrupt(String),
+}
+
+impl UlidCheckpoint {
+    fn read(&self) -> io::Result<Stored> {
+        let raw = match fs::read(&self.path) {
+            Ok(raw) => raw,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Stored::Missing),
+            Err(e) => return Err(e),
+        };
+
+        let Ok(text) = std::str::from_utf8(&raw) else {
+            return Ok(Stored::Corrupt("contents are not UTF-8".to_string()));
+        };
+        let text = text.strip_suffix('\n').unwrap_or(text);
+        if text.is_empty() {
+            return Ok(Stored::Corrupt("file is empty".to_string()));
+        }
+        Ok(match parse_ulid(text) {
+            Ok(ulid) => Stored::Value(ulid.0),
+            Err(_) => Stored::Corrupt(format!("{:?} is not a valid ULID", text)),
+        })
+    }
+
+    fn corrupt_error(&self, reason: String) -> PyErr {
+        PyValueError::new_err(format!(
+            "corrupted checkpoint file {}: {}",
+            self.path.display(),
+            reason
+        ))
+    }
+
+    /// Hold the exclusive update lock for the duration of `body`
+    fn locked<T>(&self, body: impl FnOnce() -> io::Result<T>) -> io::Result<T> {
+        let lock = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(&self.lock_path)?;
+        lock.lock()?;
+        let result = body();
+        lock.unlock()?;
+        result
+    }
+
+    fn write_atomic(&self, contents: &str) -> io::Result<()> {
+        let temp = with_suffix(
+            &self.path,
+            &format!(
+                ".{}.{}.tmp",
+                std::process::id(),
+                TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+            ),
+        );
+
+        let written = (|| {
+            let mut file = File::create(&temp)?;
+            file.write_all(contents.as_bytes())?;
+            file.sync_all()?;
+            fs::rename(&temp, &self.path)
+        })();
+        if written.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+        written?;
+
+        // Persist the rename itself; directories can't be opened for syncing everywhere
+        #[cfg(unix)]
+        if let Some(dir) = self.path.parent() {
+            let dir = if dir.as_os_str().is_empty() {
+                Path::new(".")
+            } else {
+                dir
+            };
+            if let Ok(dir) = File::open(dir) {
+                let _ = dir.sync_all();
+            }
+        }
+        Ok(())
+    }
+}
+
+#[pymethods]
+impl UlidCheckpoint {
+    #[new]
+    fn new(path: PathBuf) -> Self {
+        let lock_path = with_suffix(&path, ".lock");
+        UlidCheckpoint { path, lock_path }
+    }
+
+    /// The stored ULID, or None if nothing has been checkpointed yet
+    fn get(&self, py: Python<'_>) -> PyResult<Option<String>> {
+        match py.allow_threads(|| self.read())? {
+            Stored::Missing => Ok(None),
+            Stored::Value(value) => Ok(Some(encode_base32_internal(value))),
+            Stored::Corrupt(reason) => Err(self.corrupt_error(reason)),
+        }
+    }
+
+    /// Store `ulid` if it is greater than the current mark; returns whether it was stored
+    fn advance(&self, py: Python<'_>, ulid: UlidArg) -> PyResult<bool> {
+        let value = ulid.0 .0;
+        let outcome = py.allow_threads(|| {
+            self.locked(|| match self.read()? {
+                Stored::Value(current) if current >= value => Ok(Ok(false)),
+                Stored::Corrupt(reason) => Ok(Err(reason)),
+                _ => {
+                    self.write_atomic(&format!("{}\n", ulid.0))?;
+                    Ok(Ok(true))
+                }
+            })
+        })?;
+        outcome.map_err(|reason| self.corrupt_error(reason))
+    }
+
+    /// Forget the stored mark, so `get()` returns None again
+    fn reset(&self, py: Python<'_>) -> PyResult<()> {
+        py.allow_threads(|| {
+            self.locked(|| match fs::remove_file(&self.path) {
+                Err(e) if e.kind() != io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            })
+        })?;
+        Ok(())
+    }
+
+    fn __repr__(&self, py: Python<'_>) -> PyResult<String> {
+        let path = PyString::new(py, &self.path.to_string_lossy()).repr()?;
+        Ok(format!("UlidCheckpoint({})", path))
+    }
+}
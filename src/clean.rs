@@ -0,0 +1,274 @@
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, OnceLock};
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyBool;
+
+use crate::{encode_random, DECODE_TABLE};
+
+/// Built-in blocklist for `configure(clean_words=True)` and `ulid_clean()`.
+///
+/// Words are written in plain English and matched as substrings after the look-alike
+/// mapping in `normalize`, so e.g. "BOOB" also catches "B00B". Kept deliberately short:
+/// every extra word makes a clean ID slightly less likely per draw.
+const DEFAULT_WORDS: &[&str] = &[
+    "ANAL", "ANUS", "ARSE", "ASS", "BOOB", "COCK", "CRAP", "CUM", "CUNT", "DICK", "DYKE", "FAG",
+    "FCK", "FUCK", "FUK", "JIZZ", "KIKE", "KKK", "NAZI", "NIGGA", "NIGGER", "PENIS", "PISS",
+    "PORN", "PRICK", "PUSSY", "RAPE", "SEX", "SHIT", "SLUT", "SPIC", "TIT", "TWAT", "VAGINA",
+    "WANK", "WHORE",
+];
+
+/// Attempts per ULID before the last candidate is returned anyway
+const MAX_ATTEMPTS: usize = 16;
+
+// Automaton states are u16 indices, and each word character adds at most one state
+const MAX_TOTAL_LEN: usize = u16::MAX as usize - 1;
+
+/// Aho-Corasick automaton over the 32-symbol Base32 alphabet, as a dense DFA
+pub(crate) struct Matcher {
+    transitions: Vec<[u16; 32]>,
+    accepting: Vec<bool>,
+}
+
+impl Matcher {
+    fn build(words: &[Vec<u8>]) -> Self {
+        let mut transitions: Vec<[u16; 32]> = vec![[0; 32]];
+        let mut accepting = vec![false];
+
+        // Trie; 0 doubles as "no edge yet" since no edge ever points back to the root
+        for word in words {
+            let mut state = 0usize;
+            for &symbol in word {
+                let next = transitions[state][symbol as usize] as usize;
+                state = if next == 0 {
+                    transitions.push([0; 32]);
+                    accepting.push(false);
+                    let created = transitions.len() - 1;
+                    transitions[state][symbol as usize] = created as u16;
+                    created
+                } else {
+                    next
+                };
+            }
+            accepting[state] = true;
+        }
+
+        // Breadth-first failure links, folded straight into the transition table
+        let mut fail = vec![0usize; transitions.len()];
+        let mut queue: VecDeque<usize> = transitions[0]
+            .iter()
+            .filter(|&&next| next != 0)
+            .map(|&next| next as usize)
+            .collect();
+        while let Some(state) = queue.pop_front() {
+            accepting[state] |= accepting[fail[state]];
+            let (row, fallback) = (transitions[state], transitions[fail[state]]);
+            for (symbol, &next) in row.iter().enumerate() {
+                if next == 0 {
+                    transitions[state][symbol] = fallback[symbol];
+                } else {
+                    fail[next as usize] = fallback[symbol] as usize;
+                    queue.push_back(next as usize);
+                }
+            }
+        }
+
+        Matcher {
+            transitions,
+            accepting,
+        }
+    }
+
+    /// Index just past the end of the first blocked word in `encoded` (canonical Base32
+    /// text), or None if it contains none
+    #[inline]
+    fn match_end(&self, encoded: &[u8]) -> Option<usize> {
+        let mut state = 0usize;
+        for (i, &b) in encoded.iter().enumerate() {
+            state = self.transitions[state][(DECODE_TABLE[b as usize] & 0x1F) as usize] as usize;
+            if self.accepting[state] {
+                return Some(i + 1);
+            }
+        }
+        None
+    }
+
+    /// Whether `encoded` (canonical Base32 text) contains no blocked word
+    #[inline]
+    pub(crate) fn is_clean(&self, encoded: &[u8]) -> bool {
+        self.match_end(encoded).is_none()
+    }
+
+    /// First clean candidate among up to `MAX_ATTEMPTS`, else the last one drawn.
+    ///
+    /// `random` is the first candidate for a ULID with the encoded `timestamp`; `redraw`
+    /// supplies the next, or None when there is nothing left to try. It is given the
+    /// rejected candidate and the number of low bits below the blocked word, so a
+    /// monotonic caller can step just past the word instead of jumping anywhere above
+    /// it. A word inside the timestamp can't be fixed by redrawing, so `random` is
+    /// returned straight away.
+    pub(crate) fn pick(
+        &self,
+        timestamp: &[u8; 10],
+        mut random: u128,
+        mut redraw: impl FnMut(u128, u32) -> Option<u128>,
+    ) -> u128 {
+        if !self.is_clean(timestamp) {
+            return random;
+        }
+
+        let mut encoded = [0u8; 26];
+        encoded[..10].copy_from_slice(timestamp);
+        for _ in 1..MAX_ATTEMPTS {
+            encoded[10..].copy_from_slice(&encode_random(random));
+            let Some(end) = self.match_end(&encoded) else {
+                return random;
+            };
+            match redraw(random, 5 * (26 - end) as u32) {
+                Some(next) => random = next,
+                None => break,
+            }
+        }
+        random
+    }
+}
+
+/// Base32 symbols of `word`, mapping the letters Crockford leaves out to the
+/// characters they are read as: O as 0, I and L as 1, U as V
+fn normalize(word: &str) -> PyResult<Vec<u8>> {
+    if word.is_empty() {
+        return Err(PyValueError::new_err(
+            "clean_words entries must not be empty",
+        ));
+    }
+    word.bytes()
+        .map(|b| {
+            let c = match b.to_ascii_uppercase() {
+                b'O' => b'0',
+                b'I' | b'L' => b'1',
+                b'U' => b'V',
+                c => c,
+            };
+            match DECODE_TABLE[c as usize] {
+                0xFF => Err(PyValueError::new_err(format!(
+                    "clean_words entry '{}' must contain only letters and digits",
+                    word
+                ))),
+                symbol => Ok(symbol),
+            }
+        })
+        .collect()
+}
+
+/// The `clean_words` option: on with the built-in list, off, or a custom list
+pub enum CleanWords {
+    Flag(bool),
+    Words(Vec<String>),
+}
+
+impl<'py> FromPyObject<'py> for CleanWords {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(flag) = obj.downcast::<PyBool>() {
+            return Ok(CleanWords::Flag(flag.is_true()));
+        }
+        obj.extract()
+            .map(CleanWords::Words)
+            .map_err(|_| PyTypeError::new_err("clean_words must be a bool or a list of strings"))
+    }
+}
+
+impl<'py> IntoPyObject<'py> for &CleanWords {
+    type Target = PyAny;
+    type Output = Bound<'py, PyAny>;
+    type Error = PyErr;
+
+    fn into_pyobject(self, py: Python<'py>) -> PyResult<Self::Output> {
+        match self {
+            CleanWords::Flag(flag) => Ok(PyBool::new(py, *flag).to_owned().into_any()),
+            CleanWords::Words(words) => Ok(words.into_pyobject(py)?.into_any()),
+        }
+    }
+}
+
+struct Config {
+    option: CleanWords,
+    matcher: Option<Arc<Matcher>>,
+}
+
+// Checked before touching the lock so the default mode costs one atomic load
+static ENABLED: AtomicBool = AtomicBool::new(false);
+static CONFIG: Mutex<Config> = Mutex::new(Config {
+    option: CleanWords::Flag(false),
+    matcher: None,
+});
+
+// Poison-tolerant for the same reason as the global state lock
+fn lock() -> MutexGuard<'static, Config> {
+    CONFIG.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn default_matcher() -> Arc<Matcher> {
+    static DEFAULT: OnceLock<Arc<Matcher>> = OnceLock::new();
+    DEFAULT
+        .get_or_init(|| {
+            let words: Vec<Vec<u8>> = DEFAULT_WORDS
+                .iter()
+                .map(|word| normalize(word).expect("built-in words are alphanumeric"))
+                .collect();
+            Arc::new(Matcher::build(&words))
+        })
+        .clone()
+}
+
+/// Apply `configure(clean_words=...)`; the automaton is built here, once
+pub(crate) fn set(option: CleanWords) -> PyResult<()> {
+    let matcher = match &option {
+        CleanWords::Flag(false) => None,
+        CleanWords::Flag(true) => Some(default_matcher()),
+        CleanWords::Words(words) => {
+            if words.iter().map(String::len).sum::<usize>() > MAX_TOTAL_LEN {
+                return Err(PyValueError::new_err(format!(
+                    "clean_words may hold at most {} characters in total",
+                    MAX_TOTAL_LEN
+                )));
+            }
+            let words = words
+                .iter()
+                .map(|word| normalize(word))
+                .collect::<PyResult<Vec<_>>>()?;
+            Some(Arc::new(Matcher::build(&words)))
+        }
+    };
+
+    let mut config = lock();
+    ENABLED.store(matcher.is_some(), Ordering::Release);
+    *config = Config { option, matcher };
+    Ok(())
+}
+
+/// Current `clean_words` option, for `get_config()`
+pub(crate) fn option<'py>(py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+    (&lock().option).into_pyobject(py)
+}
+
+/// Whether generated ULIDs are being filtered
+#[inline(always)]
+pub(crate) fn enabled() -> bool {
+    ENABLED.load(Ordering::Acquire)
+}
+
+/// The configured matcher, or None when filtering is off
+#[inline(always)]
+pub(crate) fn active() -> Option<Arc<Matcher>> {
+    if !enabled() {
+        return None;
+    }
+    lock().matcher.clone()
+}
+
+/// The configured matcher, falling back to the built-in list, for `ulid_clean()`
+pub(crate) fn active_or_default() -> Arc<Matcher> {
+    active().unwrap_or_else(default_matcher)
+}
@@ -0,0 +1,139 @@
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Instant, SystemTime, UNIX_EPOCH};
+
+use pyo3::prelude::*;
+
+use crate::{shared, TimestampArg};
+
+// The Windows system clock usually ticks every 15.6 ms, so interpolation is on by
+// default there; elsewhere the system clock already has millisecond resolution
+static HYBRID: AtomicBool = AtomicBool::new(cfg!(windows));
+
+// Longer gaps between system-clock changes are sleeps or clock steps, not ticks
+const MAX_TICK_MS: u64 = 64;
+
+struct Anchor {
+    system_ms: u64,
+    instant: Instant,
+    tick_ms: u64,
+}
+
+static ANCHOR: Mutex<Option<Anchor>> = Mutex::new(None);
+
+// Generation never uses an earlier timestamp than this; 0 means no floor
+static FLOOR_MS: AtomicU64 = AtomicU64::new(0);
+
+// Largest backwards step absorbed by holding the last timestamp; 0 means none
+static DRIFT_TOLERANCE_MS: AtomicU64 = AtomicU64::new(0);
+
+// Set by `_freeze_time()` for tests; NOT_FROZEN while the real clocks are in use
+const NOT_FROZEN: u64 = u64::MAX;
+static FROZEN_MS: AtomicU64 = AtomicU64::new(NOT_FROZEN);
+
+pub(crate) fn drift_tolerance_ms() -> u64 {
+    DRIFT_TOLERANCE_MS.load(Ordering::Relaxed)
+}
+
+pub(crate) fn set_drift_tolerance(ms: u64) {
+    DRIFT_TOLERANCE_MS.store(ms, Ordering::Relaxed);
+}
+
+pub(crate) fn hybrid_enabled() -> bool {
+    HYBRID.load(Ordering::Relaxed)
+}
+
+pub(crate) fn set_hybrid(enabled: bool) {
+    HYBRID.store(enabled, Ordering::Relaxed);
+}
+
+fn system_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_millis() as u64
+}
+
+/// Wall-clock milliseconds since the Unix epoch
+pub(crate) fn now_ms() -> u64 {
+    let frozen = FROZEN_MS.load(Ordering::Relaxed);
+    if frozen != NOT_FROZEN {
+        return frozen;
+    }
+    if hybrid_enabled() {
+        hybrid_ms()
+    } else {
+        system_ms()
+    }
+}
+
+pub(crate) fn set_floor(floor_ms: u64) {
+    FLOOR_MS.store(floor_ms, Ordering::Relaxed);
+}
+
+/// Raise the floor to `floor_ms` unless it is already higher
+pub(crate) fn raise_floor(floor_ms: u64) {
+    FLOOR_MS.fetch_max(floor_ms, Ordering::Relaxed);
+}
+
+/// The floor from `set_minimum_timestamp`, while it is still ahead of the wall clock
+pub(crate) fn floor_ms() -> Option<u64> {
+    let floor = FLOOR_MS.load(Ordering::Relaxed);
+    (floor > now_ms()).then_some(floor)
+}
+
+/// Timestamp for new ULIDs: the wall clock, raised to the floor if one is set
+#[inline(always)]
+pub(crate) fn effective_ms() -> u64 {
+    now_ms().max(FLOOR_MS.load(Ordering::Relaxed))
+}
+
+/// The system clock, interpolated within its current tick by the monotonic counter
+/// (`Instant` is backed by QueryPerformanceCounter on Windows).
+///
+/// The interpolated part is clamped below the last observed tick length, so the result
+/// never runs ahead of the next system-clock tick. A system clock that steps backwards
+/// is passed through unchanged.
+fn hybrid_ms() -> u64 {
+    let system = system_ms();
+    let now = Instant::now();
+
+    let mut anchor = ANCHOR.lock().unwrap_or_else(|e| e.into_inner());
+    let anchor = anchor.get_or_insert(Anchor {
+        system_ms: system,
+        instant: now,
+        tick_ms: 1,
+    });
+
+    if system != anchor.system_ms {
+        if system > anchor.system_ms && system - anchor.system_ms <= MAX_TICK_MS {
+            anchor.tick_ms = system - anchor.system_ms;
+        }
+        anchor.system_ms = system;
+        anchor.instant = now;
+    }
+
+    let elapsed = now.duration_since(anchor.instant).as_millis() as u64;
+    anchor.system_ms + elapsed.min(anchor.tick_ms - 1)
+}
+
+/// Make the wall clock read `timestamp` until `_unfreeze_time()` (testing aid).
+///
+/// Call it again to step the frozen clock, backwards too.
+#[pyfunction]
+pub fn _freeze_time(timestamp: TimestampArg) {
+    FROZEN_MS.store(timestamp.0, Ordering::Relaxed);
+}
+
+/// Go back to the real clock (testing aid). ULIDs issued at a frozen time ahead of it
+/// stay a floor for new ones, as with `set_minimum_timestamp()`.
+#[pyfunction]
+pub fn _unfreeze_time() {
+    if FROZEN_MS.swap(NOT_FROZEN, Ordering::Relaxed) == NOT_FROZEN {
+        return;
+    }
+    let last_timestamp = shared::raise_past(None);
+    if last_timestamp > now_ms() {
+        raise_floor(last_timestamp);
+    }
+}
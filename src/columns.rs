@@ -0,0 +1,133 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyString};
+
+use crate::{decode_ascii, errors, format_uuid, signals, Ulid};
+
+/// One column entry after copying it out from under the GIL
+enum Cell {
+    Null,
+    Text([u8; 26]),
+    Raw(u128),
+    Invalid,
+}
+
+#[derive(Clone, Copy)]
+enum ColumnOp {
+    IsValid,
+    Timestamp,
+    ToBytes,
+    ToUuid,
+}
+
+#[derive(IntoPyObject)]
+pub enum ColumnValue<'py> {
+    Flag(bool),
+    Millis(u64),
+    Bytes(Bound<'py, PyBytes>),
+    Text(String),
+}
+
+/// Per-entry results, computed without the GIL
+enum Decoded {
+    Null,
+    Value(u128),
+    Invalid,
+}
+
+fn copy_cells(values: &Bound<'_, PyAny>) -> PyResult<Vec<Cell>> {
+    let mut cells = Vec::new();
+    for (i, item) in values.try_iter()?.enumerate() {
+        let item = item?;
+        signals::check_every_with_gil(values.py(), i)?;
+        let bytes_owned;
+        let raw: &[u8] = if item.is_none() {
+            cells.push(Cell::Null);
+            continue;
+        } else if let Ok(s) = item.downcast::<PyString>() {
+            bytes_owned = s.to_cow()?;
+            bytes_owned.as_bytes()
+        } else if let Ok(b) = item.downcast::<PyBytes>() {
+            b.as_bytes()
+        } else {
+            return Err(PyTypeError::new_err(format!(
+                "ULID at index {} must be str, bytes or None, got {}",
+                i,
+                item.get_type().name()?
+            )));
+        };
+
+        cells.push(match raw.len() {
+            26 => Cell::Text(raw.try_into().unwrap()),
+            16 => Cell::Raw(u128::from_be_bytes(raw.try_into().unwrap())),
+            _ => Cell::Invalid,
+        });
+    }
+    Ok(cells)
+}
+
+/// Vectorized ULID operation over one column, backing the `pyulid.polars` namespace.
+///
+/// `values` holds str (canonical text), bytes (26-byte text or 16 raw big-endian bytes)
+/// or None. Returns a list of the same length with None wherever the input was None.
+/// `op="is_valid"` reports invalid entries as False; the other ops raise ValueError
+/// naming the first invalid index.
+#[pyfunction]
+pub fn _ulid_column<'py>(
+    py: Python<'py>,
+    values: &Bound<'py, PyAny>,
+    op: &str,
+) -> PyResult<Vec<Option<ColumnValue<'py>>>> {
+    let op = match op {
+        "is_valid" => ColumnOp::IsValid,
+        "timestamp" => ColumnOp::Timestamp,
+        "to_bytes" => ColumnOp::ToBytes,
+        "to_uuid" => ColumnOp::ToUuid,
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "unknown column op '{}'",
+                other
+            )))
+        }
+    };
+    let cells = copy_cells(values)?;
+
+    let decoded: Vec<Decoded> = py.allow_threads(|| {
+        cells
+            .iter()
+            .map(|cell| match cell {
+                Cell::Null => Decoded::Null,
+                Cell::Text(text) => match decode_ascii(text) {
+                    // Only the first character may not exceed the 128-bit range
+                    Some(value) if text[0] <= b'7' => Decoded::Value(value),
+                    _ => Decoded::Invalid,
+                },
+                Cell::Raw(value) => Decoded::Value(*value),
+                Cell::Invalid => Decoded::Invalid,
+            })
+            .collect()
+    });
+
+    if !matches!(op, ColumnOp::IsValid) {
+        if let Some(index) = decoded.iter().position(|d| matches!(d, Decoded::Invalid)) {
+            return Err(errors::decode_error(format!(
+                "invalid ULID at index {}",
+                index
+            )));
+        }
+    }
+
+    Ok(decoded
+        .into_iter()
+        .map(|entry| match entry {
+            Decoded::Null => None,
+            Decoded::Invalid => Some(ColumnValue::Flag(false)),
+            Decoded::Value(value) => Some(match op {
+                ColumnOp::IsValid => ColumnValue::Flag(true),
+                ColumnOp::Timestamp => ColumnValue::Millis(Ulid(value).timestamp_ms()),
+                ColumnOp::ToBytes => ColumnValue::Bytes(PyBytes::new(py, &value.to_be_bytes())),
+                ColumnOp::ToUuid => ColumnValue::Text(format_uuid(value)),
+            }),
+        })
+        .collect())
+}
@@ -0,0 +1,111 @@
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::clean::{self, CleanWords};
+use crate::{cache, clock, entropy, shared};
+
+/// Change process-wide generator options. Options left as `None` keep their value.
+///
+/// `hybrid_clock` interpolates between coarse system-clock ticks using the
+/// high-resolution performance counter. It defaults to on for Windows only. Turning
+/// it off there mid-stream can look like the clock stepping back by under one tick.
+///
+/// `decode_cache=N` memoizes up to N recently parsed ULID strings (least recently
+/// used evicted first); 0, the default, turns it off. Results and errors are the same
+/// either way. Any `configure()` call empties the cache, and setting `decode_cache`
+/// also resets its `stats()` counters.
+///
+/// `clean_words=True` redraws the random component of generated ULIDs that spell a
+/// word of the built-in blocklist, matched case-insensitively with O/0, I/L/1 and U/V
+/// treated alike; pass a list of strings to use your own words instead.
+///
+/// `clock_drift_tolerance_ms=N` absorbs the clock stepping back by up to N ms (e.g. an
+/// NTP correction): generation keeps the last timestamp and increments the random
+/// component until the clock catches up. Larger steps still raise. Defaults to 0.
+///
+/// `overflow` decides what happens when the random component runs out within one
+/// millisecond: `"raise"` (the default) raises RuntimeError, `"carry"` moves on to the
+/// next millisecond with a fresh random component, holding later ULIDs there until the
+/// wall clock catches up. It applies to `ulid()`, `ulid_bytes()` and the batch functions.
+#[pyfunction]
+#[pyo3(signature = (
+    *,
+    hybrid_clock = None,
+    decode_cache = None,
+    clean_words = None,
+    clock_drift_tolerance_ms = None,
+    overflow = None
+))]
+pub fn configure(
+    hybrid_clock: Option<bool>,
+    decode_cache: Option<usize>,
+    clean_words: Option<CleanWords>,
+    clock_drift_tolerance_ms: Option<u64>,
+    overflow: Option<&str>,
+) -> PyResult<()> {
+    let carry = match overflow {
+        None => None,
+        Some("raise") => Some(false),
+        Some("carry") => Some(true),
+        Some(other) => {
+            return Err(PyValueError::new_err(format!(
+                "overflow must be 'raise' or 'carry', got '{}'",
+                other
+            )))
+        }
+    };
+
+    // Validated first, so a bad word list leaves every option unchanged
+    if let Some(words) = clean_words {
+        if !matches!(words, CleanWords::Flag(false)) {
+            entropy::check_no_source("configure(clean_words=...)")?;
+        }
+        clean::set(words)?;
+    }
+    if let Some(enabled) = hybrid_clock {
+        clock::set_hybrid(enabled);
+    }
+    if let Some(ms) = clock_drift_tolerance_ms {
+        clock::set_drift_tolerance(ms);
+    }
+    if let Some(carry) = carry {
+        shared::set_carry(carry);
+    }
+    match decode_cache {
+        Some(capacity) => cache::set_capacity(capacity),
+        None => cache::clear(),
+    }
+    Ok(())
+}
+
+/// Current values of every `configure()` option
+#[pyfunction]
+pub fn get_config(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let config = PyDict::new(py);
+    config.set_item("hybrid_clock", clock::hybrid_enabled())?;
+    config.set_item("decode_cache", cache::capacity())?;
+    config.set_item("clean_words", clean::option(py)?)?;
+    config.set_item("clock_drift_tolerance_ms", clock::drift_tolerance_ms())?;
+    config.set_item(
+        "overflow",
+        if shared::carry_enabled() {
+            "carry"
+        } else {
+            "raise"
+        },
+    )?;
+    Ok(config)
+}
+
+/// Runtime state of the generator and the optional caches.
+///
+/// `decode_cache` holds the cache counters; `minimum_timestamp` is the floor from
+/// `set_minimum_timestamp()` while it is ahead of the wall clock, else None.
+#[pyfunction]
+pub fn stats(py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+    let stats = PyDict::new(py);
+    stats.set_item("decode_cache", cache::stats(py)?)?;
+    stats.set_item("minimum_timestamp", clock::floor_ms())?;
+    Ok(stats)
+}
@@ -0,0 +1,304 @@
+use std::fs::File;
+use std::io::{self, BufWriter, Read, Write};
+use std::path::PathBuf;
+
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyList};
+
+use crate::{encode_random, encode_timestamp, Ulid};
+
+// Bytes read per chunk; memory use stays around two chunks regardless of file size
+const CHUNK_SIZE: usize = 1 << 20;
+// No UUID spelling is this long, so longer lines are failures and only a prefix is kept
+const MAX_LINE: usize = 256;
+// Characters of a failed line kept in the failure sample
+const SAMPLE_VALUE_LEN: usize = 64;
+
+enum Input<'py> {
+    File(File),
+    Stream(Bound<'py, PyAny>),
+}
+
+impl<'py> Input<'py> {
+    fn open(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if obj.hasattr("read")? {
+            return Ok(Input::Stream(obj.clone()));
+        }
+        let path: PathBuf = obj.extract()?;
+        Ok(Input::File(File::open(path)?))
+    }
+
+    /// Append the next chunk to `buffer`; returns the number of bytes read (0 at EOF)
+    fn read_into(&mut self, py: Python<'py>, buffer: &mut Vec<u8>) -> PyResult<usize> {
+        match self {
+            Input::File(file) => loop {
+                let read = py.allow_threads(|| {
+                    let start = buffer.len();
+                    buffer.resize(start + CHUNK_SIZE, 0);
+                    let read = file.read(&mut buffer[start..]);
+                    buffer.truncate(start + read.as_ref().map_or(0, |&n| n));
+                    read
+                });
+                match read {
+                    // A signal arrived mid-read: run its handler, then retry
+                    Err(e) if e.kind() == io::ErrorKind::Interrupted => py.check_signals()?,
+                    result => return Ok(result?),
+                }
+            },
+            Input::Stream(stream) => {
+                let chunk = stream.call_method1("read", (CHUNK_SIZE,))?;
+                let chunk = chunk.downcast::<PyBytes>().map_err(|_| {
+                    PyTypeError::new_err(
+                        "src must be opened in binary mode (read() returned non-bytes)",
+                    )
+                })?;
+                buffer.extend_from_slice(chunk.as_bytes());
+                Ok(chunk.as_bytes().len())
+            }
+        }
+    }
+}
+
+enum Output<'py> {
+    File(BufWriter<File>),
+    Stream(Bound<'py, PyAny>),
+}
+
+impl<'py> Output<'py> {
+    fn open(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if obj.hasattr("write")? {
+            return Ok(Output::Stream(obj.clone()));
+        }
+        let path: PathBuf = obj.extract()?;
+        Ok(Output::File(BufWriter::new(File::create(path)?)))
+    }
+
+    fn write(&mut self, py: Python<'py>, data: &[u8]) -> PyResult<()> {
+        if data.is_empty() {
+            return Ok(());
+        }
+        match self {
+            Output::File(file) => Ok(py.allow_threads(|| file.write_all(data))?),
+            Output::Stream(stream) => {
+                stream.call_method1("write", (PyBytes::new(py, data),))?;
+                Ok(())
+            }
+        }
+    }
+
+    /// Flush files we opened; caller-owned streams are left for the caller to flush
+    fn finish(self, py: Python<'py>) -> PyResult<()> {
+        if let Output::File(mut file) = self {
+            py.allow_threads(|| file.flush())?;
+        }
+        Ok(())
+    }
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum OutputFormat {
+    Ulid,
+    Pair,
+}
+
+struct Failure {
+    line: u64,
+    value: String,
+    error: &'static str,
+}
+
+/// Counters and failure sample accumulated across chunks
+struct Progress {
+    line: u64,
+    converted: u64,
+    failed: u64,
+    failures: Vec<Failure>,
+    max_failures: usize,
+}
+
+impl Progress {
+    fn fail(&mut self, text: &[u8], error: &'static str) {
+        self.failed += 1;
+        if self.failures.len() < self.max_failures {
+            let value: String = String::from_utf8_lossy(text)
+                .chars()
+                .take(SAMPLE_VALUE_LEN)
+                .collect();
+            self.failures.push(Failure {
+                line: self.line,
+                value,
+                error,
+            });
+        }
+    }
+}
+
+/// UUID value of dashed or bare hex text, with the same dash rule as `uuid_to_ulid`
+fn parse_uuid(text: &[u8]) -> Option<u128> {
+    let mut value: u128 = 0;
+    let mut digits = 0;
+    for &b in text {
+        if b == b'-' {
+            continue;
+        }
+        let digit = (b as char).to_digit(16)?;
+        digits += 1;
+        if digits > 32 {
+            return None;
+        }
+        value = (value << 4) | digit as u128;
+    }
+    (digits == 32).then_some(value)
+}
+
+/// Convert one line (without its `\n`), appending the output line to `out`
+fn convert_line(
+    line: &[u8],
+    overlong: bool,
+    format: OutputFormat,
+    out: &mut Vec<u8>,
+    progress: &mut Progress,
+) {
+    progress.line += 1;
+    let text = line.strip_suffix(b"\r").unwrap_or(line).trim_ascii();
+
+    if format == OutputFormat::Pair {
+        out.extend_from_slice(text);
+        out.push(b',');
+    }
+    match parse_uuid(text) {
+        Some(value) if !overlong => {
+            let ulid = Ulid(value);
+            out.extend_from_slice(&encode_timestamp(ulid.timestamp_ms()));
+            out.extend_from_slice(&encode_random(ulid.random()));
+            progress.converted += 1;
+        }
+        _ if overlong => progress.fail(text, "line too long"),
+        _ if text.is_empty() => progress.fail(text, "empty line"),
+        _ => progress.fail(text, "not a UUID"),
+    }
+    out.push(b'\n');
+}
+
+/// Convert every complete line in `pending`, draining them and leaving the partial tail
+fn convert_lines(
+    pending: &mut Vec<u8>,
+    format: OutputFormat,
+    out: &mut Vec<u8>,
+    progress: &mut Progress,
+) {
+    let mut start = 0;
+    while let Some(offset) = pending[start..].iter().position(|&b| b == b'\n') {
+        let end = start + offset;
+        convert_line(&pending[start..end], false, format, out, progress);
+        start = end + 1;
+    }
+    pending.drain(..start);
+}
+
+/// Convert a file of UUIDs, one per line, into ULIDs with constant memory.
+///
+/// `src` and `dst` are binary file objects or paths. Each line holds one UUID (dashed or
+/// bare hex; surrounding whitespace and CRLF endings are ignored) and produces one
+/// output line: the ULID for `output="ulid"`, or `uuid,ulid` for `output="pair"`. Lines
+/// that fail to convert produce an empty ULID, so output lines stay aligned with input.
+///
+/// Returns `{"converted": int, "failed": int, "failures": [...]}`, where `failures`
+/// holds up to `max_failures` entries of `{"line": 1-based number, "value", "error"}`.
+#[pyfunction]
+#[pyo3(signature = (src, dst, input_format = "lines", output = "ulid", *, max_failures = 100))]
+pub fn convert_stream<'py>(
+    py: Python<'py>,
+    src: &Bound<'py, PyAny>,
+    dst: &Bound<'py, PyAny>,
+    input_format: &str,
+    output: &str,
+    max_failures: usize,
+) -> PyResult<Bound<'py, PyDict>> {
+    if input_format != "lines" {
+        return Err(PyValueError::new_err(format!(
+            "input_format must be 'lines', got '{}'",
+            input_format
+        )));
+    }
+    let format = match output {
+        "ulid" => OutputFormat::Ulid,
+        "pair" => OutputFormat::Pair,
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "output must be 'ulid' or 'pair', got '{}'",
+                other
+            )))
+        }
+    };
+
+    let mut input = Input::open(src)?;
+    let mut output = Output::open(dst)?;
+    let mut progress = Progress {
+        line: 0,
+        converted: 0,
+        failed: 0,
+        failures: Vec::new(),
+        max_failures,
+    };
+    let mut pending: Vec<u8> = Vec::new();
+    let mut out: Vec<u8> = Vec::new();
+    // Set while skipping the rest of a line that exceeded MAX_LINE
+    let mut overlong = false;
+
+    loop {
+        let start = pending.len();
+        if input.read_into(py, &mut pending)? == 0 {
+            break;
+        }
+
+        if overlong {
+            match pending[start..].iter().position(|&b| b == b'\n') {
+                Some(offset) => {
+                    // Finish the overlong line from its kept prefix
+                    let tail = pending.split_off(start + offset + 1);
+                    convert_line(&pending[..start], true, format, &mut out, &mut progress);
+                    pending = tail;
+                    overlong = false;
+                }
+                None => {
+                    pending.truncate(start);
+                    continue;
+                }
+            }
+        }
+
+        py.allow_threads(|| convert_lines(&mut pending, format, &mut out, &mut progress));
+        if pending.len() > MAX_LINE {
+            pending.truncate(MAX_LINE);
+            overlong = true;
+        }
+
+        output.write(py, &out)?;
+        out.clear();
+        py.check_signals()?;
+    }
+
+    // A last line without a trailing newline
+    if !pending.is_empty() || overlong {
+        convert_line(&pending, overlong, format, &mut out, &mut progress);
+        output.write(py, &out)?;
+    }
+    output.finish(py)?;
+
+    let failures = PyList::empty(py);
+    for failure in &progress.failures {
+        let entry = PyDict::new(py);
+        entry.set_item("line", failure.line)?;
+        entry.set_item("value", &failure.value)?;
+        entry.set_item("error", failure.error)?;
+        failures.append(entry)?;
+    }
+
+    let report = PyDict::new(py);
+    report.set_item("converted", progress.converted)?;
+    report.set_item("failed", progress.failed)?;
+    report.set_item("failures", failures)?;
+    Ok(report)
+}
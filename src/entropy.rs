@@ -0,0 +1,375 @@
+use std::cell::Cell;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use pyo3::exceptions::{PyRuntimeError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use rand::rand_core::UnwrapErr;
+use rand::rngs::{OsRng, StdRng};
+use rand::{RngCore, SeedableRng, TryRngCore};
+
+use crate::array::collect_values;
+use crate::{clean, rollover_random, signals, RandomArg, Ulid};
+
+/// Fewest random components the statistics are meaningful for
+const MIN_SAMPLES: usize = 1000;
+
+/// Every check passes while its z-score stays below this (about 1 in 1.7 million per
+/// test for a good source)
+const Z_THRESHOLD: f64 = 5.0;
+
+const RAND_BYTES: usize = 10;
+
+/// Random components to test: either a count to generate or existing ULIDs
+#[derive(FromPyObject)]
+pub enum EntropySample<'py> {
+    Size(usize),
+    Ulids(Bound<'py, PyAny>),
+}
+
+/// Largest per-position deviation of the ones count from n/2, as (z, bit)
+fn bit_frequency(samples: &[u128]) -> (f64, u32) {
+    let mut ones = [0u64; Ulid::RAND_BITS as usize];
+    for &sample in samples {
+        for (bit, count) in ones.iter_mut().enumerate() {
+            *count += ((sample >> bit) & 1) as u64;
+        }
+    }
+
+    let n = samples.len() as f64;
+    ones.iter()
+        .enumerate()
+        .map(|(bit, &count)| {
+            (
+                (count as f64 - n / 2.0).abs() / (n / 4.0).sqrt(),
+                bit as u32,
+            )
+        })
+        .max_by(|a, b| a.0.total_cmp(&b.0))
+        .unwrap_or((0.0, 0))
+}
+
+/// Lag-1 autocorrelation of the top 64 random bits as uniforms in [0, 1)
+fn serial_correlation(samples: &[u128]) -> f64 {
+    let xs: Vec<f64> = samples
+        .iter()
+        .map(|&s| (s >> (Ulid::RAND_BITS - 64)) as u64 as f64 / 2f64.powi(64))
+        .collect();
+    let mean = xs.iter().sum::<f64>() / xs.len() as f64;
+
+    let variance: f64 = xs.iter().map(|x| (x - mean).powi(2)).sum();
+    let covariance: f64 = xs.windows(2).map(|w| (w[0] - mean) * (w[1] - mean)).sum();
+    if variance == 0.0 {
+        return 1.0;
+    }
+    covariance / variance
+}
+
+/// Pearson chi-square of the byte-value histogram over all 10 random bytes (255 df)
+fn byte_chi_square(samples: &[u128]) -> f64 {
+    let mut histogram = [0u64; 256];
+    for &sample in samples {
+        for i in 0..RAND_BYTES {
+            histogram[((sample >> (8 * i)) & 0xFF) as usize] += 1;
+        }
+    }
+
+    let expected = (samples.len() * RAND_BYTES) as f64 / 256.0;
+    histogram
+        .iter()
+        .map(|&count| (count as f64 - expected).powi(2) / expected)
+        .sum()
+}
+
+/// The freshly drawn random component of each millisecond, as the generator sees them
+fn first_per_millisecond(mut values: Vec<u128>) -> Vec<u128> {
+    values.sort_unstable();
+    values.dedup_by_key(|v| Ulid(*v).timestamp_ms());
+    values
+        .into_iter()
+        .map(|v| v & Ulid::bitmask(Ulid::RAND_BITS))
+        .collect()
+}
+
+/// Statistical sanity check of the random components, for CI against a deployment image.
+///
+/// Given a count, draws that many random components the way the generator starts each
+/// new millisecond (the process RNG mixed with fresh OS bytes), without touching the
+/// shared state. Given ULIDs, tests the first ULID of each millisecond; later ones are
+/// monotonic increments and carry no new entropy. Runs three tests, each passing while
+/// its z-score stays below 5:
+///
+/// - `bit_frequency`: ones count of each of the 80 bit positions (worst position reported)
+/// - `serial_correlation`: lag-1 correlation of consecutive values, z = r * sqrt(n)
+/// - `byte_chi_square`: chi-square of the byte histogram, z = (chi2 - 255) / sqrt(510)
+///
+/// Returns `{"samples": n, "passed": bool, check_name: {..., "z": float, "passed": bool}}`.
+/// Failures are reported in the dict; pass `raise_on_failure=True` to raise RuntimeError.
+#[pyfunction]
+#[pyo3(signature = (sample_size = EntropySample::Size(100_000), *, raise_on_failure = false))]
+pub fn ulid_entropy_check<'py>(
+    py: Python<'py>,
+    sample_size: EntropySample<'py>,
+    raise_on_failure: bool,
+) -> PyResult<Bound<'py, PyDict>> {
+    let samples = match sample_size {
+        EntropySample::Size(n) => py.allow_threads(|| {
+            let mut rng = StdRng::from_rng(&mut rand::rng());
+            (0..n)
+                .map(|i| {
+                    signals::check_every(i)?;
+                    Ok(rollover_random(&mut rng))
+                })
+                .collect::<PyResult<Vec<_>>>()
+        })?,
+        EntropySample::Ulids(seq) => {
+            let values = collect_values(py, &seq)?;
+            py.allow_threads(|| first_per_millisecond(values))
+        }
+    };
+    if samples.len() < MIN_SAMPLES {
+        return Err(PyValueError::new_err(format!(
+            "need at least {} samples (one per distinct millisecond), got {}",
+            MIN_SAMPLES,
+            samples.len()
+        )));
+    }
+
+    let (bit_z, worst_bit, correlation, chi_square) = py.allow_threads(|| {
+        let (bit_z, worst_bit) = bit_frequency(&samples);
+        (
+            bit_z,
+            worst_bit,
+            serial_correlation(&samples),
+            byte_chi_square(&samples),
+        )
+    });
+    let n = samples.len();
+    let correlation_z = correlation.abs() * (n as f64).sqrt();
+    let chi_square_z = (chi_square - 255.0).abs() / 510f64.sqrt();
+
+    let report = PyDict::new(py);
+    report.set_item("samples", n)?;
+
+    let bits = PyDict::new(py);
+    bits.set_item("worst_bit", worst_bit)?;
+    bits.set_item("z", bit_z)?;
+    bits.set_item("passed", bit_z < Z_THRESHOLD)?;
+
+    let serial = PyDict::new(py);
+    serial.set_item("coefficient", correlation)?;
+    serial.set_item("z", correlation_z)?;
+    serial.set_item("passed", correlation_z < Z_THRESHOLD)?;
+
+    let bytes = PyDict::new(py);
+    bytes.set_item("statistic", chi_square)?;
+    bytes.set_item("df", 255)?;
+    bytes.set_item("z", chi_square_z)?;
+    bytes.set_item("passed", chi_square_z < Z_THRESHOLD)?;
+
+    let checks = [
+        ("bit_frequency", bits, bit_z),
+        ("serial_correlation", serial, correlation_z),
+        ("byte_chi_square", bytes, chi_square_z),
+    ];
+    let failed: Vec<String> = checks
+        .iter()
+        .filter(|(_, _, z)| *z >= Z_THRESHOLD)
+        .map(|(name, _, z)| format!("{} (z = {:.2})", name, z))
+        .collect();
+    report.set_item("passed", failed.is_empty())?;
+    report.set_item("threshold", Z_THRESHOLD)?;
+    for (name, entry, _) in checks {
+        report.set_item(name, entry)?;
+    }
+
+    if raise_on_failure && !failed.is_empty() {
+        return Err(PyRuntimeError::new_err(format!(
+            "entropy check failed: {}",
+            failed.join(", ")
+        )));
+    }
+    Ok(report)
+}
+
+// Checked before touching the lock so the default mode costs one atomic load
+static SOURCE_SET: AtomicBool = AtomicBool::new(false);
+static SOURCE: Mutex<Option<Py<PyAny>>> = Mutex::new(None);
+
+thread_local! {
+    // Set while this thread is inside the source, which must not generate ULIDs itself
+    static DRAWING: Cell<bool> = const { Cell::new(false) };
+}
+
+// Poison-tolerant for the same reason as the global state lock
+fn lock() -> MutexGuard<'static, Option<Py<PyAny>>> {
+    SOURCE.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Whether `set_entropy_source()` installed a callable
+#[inline(always)]
+pub(crate) fn source_set() -> bool {
+    SOURCE_SET.load(Ordering::Acquire)
+}
+
+/// A fresh random component from the entropy source, or the built-in RNG if it was
+/// unset meanwhile. Takes the GIL, so call it with no generator lock held.
+pub(crate) fn draw() -> PyResult<u128> {
+    Python::with_gil(|py| {
+        let Some(source) = lock().as_ref().map(|source| source.clone_ref(py)) else {
+            return Ok(rollover_random(&mut StdRng::from_rng(&mut rand::rng())));
+        };
+        if DRAWING.replace(true) {
+            return Err(PyRuntimeError::new_err(
+                "the entropy source can't generate ULIDs itself",
+            ));
+        }
+        let result = source.bind(py).call0();
+        DRAWING.set(false);
+
+        result?
+            .extract::<RandomArg>()
+            .map(|random| random.0)
+            .map_err(|e| {
+                let message = format!("entropy source returned an invalid value: {}", e.value(py));
+                if e.is_instance_of::<PyTypeError>(py) {
+                    PyTypeError::new_err(message)
+                } else {
+                    PyValueError::new_err(message)
+                }
+            })
+    })
+}
+
+/// Draw the random component of new ULIDs from `source` instead of the built-in RNG.
+///
+/// `source()` takes no arguments and returns 10 bytes or an int below 2**80. The global
+/// monotonic generator calls it once per new millisecond (including the first ULID),
+/// `ulid_with_timestamp()` and the functions built on it once per ULID. Whatever it
+/// raises, and ValueError or TypeError for anything else it returns, propagates from
+/// the generating call. `None` restores the built-in RNG.
+///
+/// Clean-word filtering redraws blocked random components under the generator lock,
+/// where the source can't be called, so the two don't combine: this raises ValueError
+/// while `configure(clean_words=...)` is on, and so do `ulid_clean()` and turning it
+/// on while a source is set.
+#[pyfunction]
+pub fn set_entropy_source(source: Option<Bound<'_, PyAny>>) -> PyResult<()> {
+    if let Some(source) = &source {
+        if !source.is_callable() {
+            return Err(PyTypeError::new_err(format!(
+                "entropy source must be callable, got {}",
+                source.get_type().name()?
+            )));
+        }
+        if clean::enabled() {
+            return Err(PyValueError::new_err(
+                "set_entropy_source() can't be combined with configure(clean_words=...)",
+            ));
+        }
+    }
+
+    let source = source.map(Bound::unbind);
+    let mut current = lock();
+    SOURCE_SET.store(source.is_some(), Ordering::Release);
+    let previous = std::mem::replace(&mut *current, source);
+    // Released first: dropping the old callable may run arbitrary Python
+    drop(current);
+    drop(previous);
+    Ok(())
+}
+
+/// ValueError if an entropy source is set, for features it doesn't combine with
+pub(crate) fn check_no_source(feature: &str) -> PyResult<()> {
+    if source_set() {
+        return Err(PyValueError::new_err(format!(
+            "{} can't be combined with set_entropy_source()",
+            feature
+        )));
+    }
+    Ok(())
+}
+
+// "os" RNG mode: fresh random components come straight from the OS CSPRNG
+static OS_MODE: AtomicBool = AtomicBool::new(false);
+
+/// The RNG a fresh random component is drawn from: the given fast RNG, or the OS
+/// CSPRNG in "os" mode
+pub(crate) enum ModeRng<R> {
+    Fast(R),
+    Os(UnwrapErr<OsRng>),
+}
+
+impl<R: RngCore> RngCore for ModeRng<R> {
+    fn next_u32(&mut self) -> u32 {
+        match self {
+            ModeRng::Fast(rng) => rng.next_u32(),
+            ModeRng::Os(rng) => rng.next_u32(),
+        }
+    }
+
+    fn next_u64(&mut self) -> u64 {
+        match self {
+            ModeRng::Fast(rng) => rng.next_u64(),
+            ModeRng::Os(rng) => rng.next_u64(),
+        }
+    }
+
+    fn fill_bytes(&mut self, dst: &mut [u8]) {
+        match self {
+            ModeRng::Fast(rng) => rng.fill_bytes(dst),
+            ModeRng::Os(rng) => rng.fill_bytes(dst),
+        }
+    }
+}
+
+/// `rng`, or the OS CSPRNG in "os" mode. The mode is read once, so a draw in flight
+/// while the mode switches takes all of its bits from one RNG.
+#[inline(always)]
+pub(crate) fn mode_rng<R: RngCore>(rng: R) -> ModeRng<R> {
+    if os_mode() {
+        ModeRng::Os(OsRng.unwrap_err())
+    } else {
+        ModeRng::Fast(rng)
+    }
+}
+
+#[inline(always)]
+pub(crate) fn os_mode() -> bool {
+    OS_MODE.load(Ordering::Relaxed)
+}
+
+/// Choose where fresh random components come from.
+///
+/// `"fast"` (the default) draws them from a userspace CSPRNG seeded from the OS.
+/// `"os"` reads every one from the OS CSPRNG (`getrandom`), at the cost of a system
+/// call per draw. This covers `ulid()` and the batch functions (once per new
+/// millisecond), `ulid_with_timestamp()` and the functions built on it, and unseeded
+/// `UlidGenerator`s and `KeyedUlidGenerator`s. Seeded generators stay reproducible,
+/// and an entropy source set with `set_entropy_source()` takes precedence.
+#[pyfunction]
+pub fn set_rng_mode(mode: &str) -> PyResult<()> {
+    let os = match mode {
+        "fast" => false,
+        "os" => true,
+        other => {
+            return Err(PyValueError::new_err(format!(
+                "rng mode must be 'fast' or 'os', got '{}'",
+                other
+            )))
+        }
+    };
+    OS_MODE.store(os, Ordering::Relaxed);
+    Ok(())
+}
+
+/// The mode set by `set_rng_mode()`: `"fast"` or `"os"`
+#[pyfunction]
+pub fn get_rng_mode() -> &'static str {
+    if os_mode() {
+        "os"
+    } else {
+        "fast"
+    }
+}
@@ -0,0 +1,220 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+#[cfg(feature = "test-hooks")]
+use std::sync::atomic::{AtomicBool, Ordering};
+#[cfg(feature = "test-hooks")]
+use std::sync::Mutex;
+
+use pyo3::create_exception;
+use pyo3::exceptions::{PyException, PyRuntimeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyTuple, PyType};
+use pyo3::PyTypeInfo;
+
+create_exception!(
+    pyulid,
+    UlidError,
+    PyException,
+    "Base class for errors raised by pyulid."
+);
+
+// Subclasses of UlidError and a builtin; create_exception! only takes one base
+static INTERNAL_ERROR: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static DECODE_ERROR: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static OVERFLOW_ERROR: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+static CLOCK_ERROR: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+// One-shot failure injection for tests, armed with the name of a guarded function
+#[cfg(feature = "test-hooks")]
+static INJECT_ARMED: AtomicBool = AtomicBool::new(false);
+#[cfg(feature = "test-hooks")]
+static INJECT_TARGET: Mutex<Option<String>> = Mutex::new(None);
+
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    m.add("UlidError", py.get_type::<UlidError>())?;
+    subclass::<PyRuntimeError>(
+        m,
+        "UlidInternalError",
+        "An internal failure (such as a Rust panic) converted to an exception.",
+        &INTERNAL_ERROR,
+    )?;
+    subclass::<PyValueError>(
+        m,
+        "UlidDecodeError",
+        "A ULID that can't be decoded: bad length, bad character or overflow.",
+        &DECODE_ERROR,
+    )?;
+    subclass::<PyRuntimeError>(
+        m,
+        "UlidOverflowError",
+        "The random component ran out within a single millisecond.",
+        &OVERFLOW_ERROR,
+    )?;
+    subclass::<PyRuntimeError>(
+        m,
+        "UlidClockError",
+        "The clock moved backwards past the drift tolerance.",
+        &CLOCK_ERROR,
+    )
+}
+
+/// Add `name` to `m` as a subclass of both UlidError and the builtin `B`
+fn subclass<B: PyTypeInfo>(
+    m: &Bound<'_, PyModule>,
+    name: &str,
+    doc: &str,
+    cell: &GILOnceCell<Py<PyType>>,
+) -> PyResult<()> {
+    let py = m.py();
+    let namespace = PyDict::new(py);
+    namespace.set_item("__module__", "pyulid")?;
+    namespace.set_item("__doc__", doc)?;
+    let bases = PyTuple::new(
+        py,
+        [
+            py.get_type::<UlidError>().into_any(),
+            py.get_type::<B>().into_any(),
+        ],
+    )?;
+    let ty = py
+        .get_type::<PyType>()
+        .call1((name, bases, namespace))?
+        .downcast_into::<PyType>()?;
+    m.add(name, &ty)?;
+    let _ = cell.set(py, ty.unbind());
+    Ok(())
+}
+
+fn raise(
+    py: Python<'_>,
+    cell: &GILOnceCell<Py<PyType>>,
+    fallback: fn(String) -> PyErr,
+    message: String,
+) -> PyErr {
+    match cell.get(py) {
+        Some(ty) => PyErr::from_type(ty.bind(py).clone(), message),
+        None => fallback(message),
+    }
+}
+
+fn internal_error(py: Python<'_>, message: String) -> PyErr {
+    raise(py, &INTERNAL_ERROR, PyRuntimeError::new_err, message)
+}
+
+/// `UlidDecodeError` (a `ValueError`) with `message`
+pub(crate) fn decode_error(message: impl Into<String>) -> PyErr {
+    let message = message.into();
+    Python::with_gil(|py| raise(py, &DECODE_ERROR, PyValueError::new_err, message))
+}
+
+/// `UlidOverflowError` (a `RuntimeError`) with `message`
+pub(crate) fn overflow_error(message: impl Into<String>) -> PyErr {
+    let message = message.into();
+    Python::with_gil(|py| raise(py, &OVERFLOW_ERROR, PyRuntimeError::new_err, message))
+}
+
+/// `UlidClockError` (a `RuntimeError`) with `message`
+pub(crate) fn clock_error(message: impl Into<String>) -> PyErr {
+    let message = message.into();
+    Python::with_gil(|py| raise(py, &CLOCK_ERROR, PyRuntimeError::new_err, message))
+}
+
+/// Why generating a ULID failed, kept apart until it becomes an exception
+#[derive(Debug)]
+pub(crate) enum GenerateError {
+    /// The clock moved backwards past the drift tolerance
+    Clock(String),
+    /// The random component ran out within one millisecond
+    Overflow(String),
+}
+
+impl GenerateError {
+    /// The same error with `suffix` appended to its message
+    pub(crate) fn with_suffix(self, suffix: &str) -> Self {
+        match self {
+            GenerateError::Clock(message) => GenerateError::Clock(message + suffix),
+            GenerateError::Overflow(message) => GenerateError::Overflow(message + suffix),
+        }
+    }
+}
+
+impl std::fmt::Display for GenerateError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GenerateError::Clock(message) | GenerateError::Overflow(message) => {
+                f.write_str(message)
+            }
+        }
+    }
+}
+
+impl From<GenerateError> for PyErr {
+    fn from(error: GenerateError) -> PyErr {
+        match error {
+            GenerateError::Clock(message) => clock_error(message),
+            GenerateError::Overflow(message) => overflow_error(message),
+        }
+    }
+}
+
+fn panic_message(payload: &(dyn Any + Send)) -> &str {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message
+    } else {
+        "unknown panic"
+    }
+}
+
+#[cfg(feature = "test-hooks")]
+fn maybe_inject(name: &str) {
+    if !INJECT_ARMED.load(Ordering::Relaxed) {
+        return;
+    }
+    let mut target = INJECT_TARGET.lock().unwrap_or_else(|e| e.into_inner());
+    if target.as_deref() == Some(name) {
+        *target = None;
+        INJECT_ARMED.store(false, Ordering::Relaxed);
+        drop(target);
+        panic!("injected failure");
+    }
+}
+
+/// Run a pyfunction body, turning any Rust panic into `UlidInternalError` naming `name`
+///
+/// Every entry point that generates IDs is guarded: those share the clock, RNG and
+/// generator locks across threads and call out to user entropy sources, which is
+/// where panics the audit can't rule out would come from. Functions that only parse,
+/// convert or analyse their arguments are exempt; they touch no shared state and all
+/// their failures are returned as exceptions. Generators that delegate (`ulid_at`,
+/// `ulid_from_datetime`, ...) are covered by the function they call.
+#[inline(always)]
+pub(crate) fn guard<T>(
+    py: Python<'_>,
+    name: &str,
+    body: impl FnOnce() -> PyResult<T>,
+) -> PyResult<T> {
+    panic::catch_unwind(AssertUnwindSafe(|| {
+        #[cfg(feature = "test-hooks")]
+        maybe_inject(name);
+        body()
+    }))
+    .unwrap_or_else(|payload| {
+        Err(internal_error(
+            py,
+            format!("internal error in {}(): {}", name, panic_message(&*payload)),
+        ))
+    })
+}
+
+/// Make the next call to the guarded function `name` panic (testing aid, only in
+/// `test-hooks` builds)
+#[cfg(feature = "test-hooks")]
+#[pyfunction]
+pub fn _inject_panic(name: String) {
+    *INJECT_TARGET.lock().unwrap_or_else(|e| e.into_inner()) = Some(name);
+    INJECT_ARMED.store(true, Ordering::Relaxed);
+}
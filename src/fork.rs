@@ -0,0 +1,69 @@
+// A forked child inherits the generator state as it was at the fork, so without these
+// hooks the parent and each child would continue the same monotonic sequence and,
+// within the same millisecond, issue the very same ULIDs (gunicorn and other preforking
+// servers import pyulid in the master). The hooks go through `os.register_at_fork`,
+// which runs them on the forking thread with the GIL held; `os.fork()` and
+// `multiprocessing`'s fork start method both honour it.
+
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::{logging, replay, shared};
+
+// Forks this process descends from; each child counts one more than its parent
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+
+/// Changes in a forked child, so per-instance generators can tell they were inherited
+#[inline(always)]
+pub(crate) fn generation() -> u64 {
+    GENERATION.load(Ordering::Relaxed)
+}
+
+/// Take the state locks, so no other thread holds them at the moment of the fork
+#[pyfunction]
+fn _before_fork() {
+    shared::before_fork();
+    replay::before_fork();
+    logging::before_fork();
+}
+
+#[pyfunction]
+fn _after_fork_in_parent() {
+    logging::after_fork();
+    replay::after_fork_in_parent();
+    shared::after_fork_in_parent();
+}
+
+/// Redraw every random source the child inherited, then release the state locks
+#[pyfunction]
+fn _after_fork_in_child(py: Python<'_>) {
+    // rand's thread RNG isn't reseeded on fork by itself
+    let _ = rand::rng().reseed();
+    GENERATION.fetch_add(1, Ordering::Relaxed);
+    // Released first, so the reseed below can queue its event
+    logging::after_fork();
+    replay::after_fork_in_child();
+    shared::after_fork_in_child();
+    logging::flush(py);
+}
+
+/// Register the hooks; a no-op where `os.register_at_fork` doesn't exist (Windows)
+pub(crate) fn register(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    let py = m.py();
+    let os = py.import("os")?;
+    if !os.hasattr("register_at_fork")? {
+        return Ok(());
+    }
+
+    let hooks = PyDict::new(py);
+    hooks.set_item("before", wrap_pyfunction!(_before_fork, m)?)?;
+    hooks.set_item(
+        "after_in_parent",
+        wrap_pyfunction!(_after_fork_in_parent, m)?,
+    )?;
+    hooks.set_item("after_in_child", wrap_pyfunction!(_after_fork_in_child, m)?)?;
+    os.call_method("register_at_fork", (), Some(&hooks))?;
+    Ok(())
+}
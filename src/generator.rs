@@ -0,0 +1,378 @@
+use std::sync::{Mutex, MutexGuard};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+
+use crate::{clean, clock, encode_base32_internal, errors, logging, signals, Ulid, UlidState};
+
+struct Inner {
+    state: UlidState,
+    // The generator's own clock; only `advance()` and `set_timestamp()` move it. None
+    // for an unseeded generator, which follows the system clock instead
+    clock_ms: Option<u64>,
+}
+
+impl Inner {
+    /// Advance by one ULID; `words` only applies to unseeded generators
+    fn next(&mut self, words: Option<&clean::Matcher>) -> Result<Ulid, errors::GenerateError> {
+        match self.clock_ms {
+            Some(clock_ms) => self.state.advance_at(clock_ms, None)?,
+            None => self.state.advance_avoiding(words)?,
+        }
+        Ok(Ulid::from_parts(
+            self.state.last_timestamp,
+            self.state.last_random,
+        ))
+    }
+}
+
+/// ULID generator with its own monotonic sequence.
+///
+/// Unseeded, it follows the system clock like `ulid()` but keeps a separate sequence
+/// and lock, e.g. one generator per Kafka partition so a burst in one stream doesn't
+/// advance another's counter. A forked child redraws the RNG of an unseeded generator
+/// it inherited, so parent and child don't continue the same sequence.
+///
+/// With a `seed`, it is deterministic for reproducible tests: the random components
+/// come from a `StdRng` seeded with `seed`, and the timestamps from an internal clock
+/// that starts at `start_timestamp_ms` (default: the current time) and moves only
+/// through `advance()` and `set_timestamp()`. The same seed and the same calls always
+/// give the same ULIDs. Not for production IDs: anyone who knows the seed can predict
+/// them.
+///
+/// With `node_id` and `node_bits`, the top `node_bits` of every random component hold
+/// `node_id`, so generators on different nodes never issue the same ULID even if their
+/// clocks and RNGs collide. Only the bits below are drawn and incremented, so a
+/// millisecond overflows sooner.
+///
+/// A pickled generator keeps its seed, internal clock, node ID and last ULID, but not
+/// its RNG: each unpickled copy draws a fresh one and jumps ahead, so workers handed
+/// the same generator never repeat each other or go back before the original.
+#[pyclass(module = "pyulid", frozen)]
+pub struct UlidGenerator {
+    seed: Option<u64>,
+    node: Option<(u64, u8)>,
+    inner: Mutex<Inner>,
+}
+
+/// Most random bits a node ID may take, leaving at least 64 for the sequence
+const MAX_NODE_BITS: u8 = 16;
+
+fn check_node_bits(bits: u8) -> PyResult<()> {
+    if !(1..=MAX_NODE_BITS).contains(&bits) {
+        return Err(PyValueError::new_err(format!(
+            "node_bits must be in range 1..={}, got {}",
+            MAX_NODE_BITS, bits
+        )));
+    }
+    Ok(())
+}
+
+/// Validate `node_id` and `node_bits`, which come together or not at all
+fn node_config(node_id: Option<u64>, node_bits: Option<u8>) -> PyResult<Option<(u64, u8)>> {
+    match (node_id, node_bits) {
+        (Some(node_id), Some(bits)) => {
+            check_node_bits(bits)?;
+            if node_id >> bits != 0 {
+                return Err(PyValueError::new_err(format!(
+                    "node_id must be in range 0 <= node_id < 2**{}, got {}",
+                    bits, node_id
+                )));
+            }
+            check_clean_words("UlidGenerator with a node_id")?;
+            Ok(Some((node_id, bits)))
+        }
+        (None, None) => Ok(None),
+        _ => Err(PyValueError::new_err(
+            "node_id and node_bits must be given together",
+        )),
+    }
+}
+
+/// Filtering would make a seeded sequence depend on the blocklist as well as the seed,
+/// and a redraw could step over a node ID
+fn check_clean_words(kind: &str) -> PyResult<()> {
+    if clean::enabled() {
+        return Err(PyValueError::new_err(format!(
+            "a {} can't be used with configure(clean_words=...)",
+            kind
+        )));
+    }
+    Ok(())
+}
+
+impl UlidGenerator {
+    // Poison-tolerant for the same reason as the global state lock
+    fn lock(&self) -> MutexGuard<'_, Inner> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    fn check_clean_words(&self) -> PyResult<()> {
+        match (self.seed, self.node) {
+            (Some(_), _) => check_clean_words("seeded UlidGenerator"),
+            (None, Some(_)) => check_clean_words("UlidGenerator with a node_id"),
+            (None, None) => Ok(()),
+        }
+    }
+
+    fn internal_clock(&self) -> PyResult<MutexGuard<'_, Inner>> {
+        if self.seed.is_none() {
+            return Err(PyValueError::new_err(
+                "only a seeded UlidGenerator has an internal clock; unseeded ones follow \
+                 the system clock",
+            ));
+        }
+        Ok(self.lock())
+    }
+
+    fn next(&self, py: Python<'_>, name: &str) -> PyResult<Ulid> {
+        self.check_clean_words()?;
+
+        errors::guard(py, name, || {
+            let words = clean::active();
+            let result = self.lock().next(words.as_deref());
+            logging::flush(py);
+
+            result.map_err(PyErr::from)
+        })
+    }
+}
+
+#[pymethods]
+impl UlidGenerator {
+    #[new]
+    #[pyo3(signature = (
+        seed = None,
+        start_timestamp_ms = None,
+        *,
+        node_id = None,
+        node_bits = None,
+    ))]
+    fn new(
+        seed: Option<u64>,
+        start_timestamp_ms: Option<crate::TimestampArg>,
+        node_id: Option<u64>,
+        node_bits: Option<u8>,
+    ) -> PyResult<Self> {
+        let node = node_config(node_id, node_bits)?;
+
+        let inner = match seed {
+            Some(seed) => {
+                check_clean_words("seeded UlidGenerator")?;
+                let clock_ms = start_timestamp_ms.map_or_else(clock::now_ms, |ts| ts.0);
+                Inner {
+                    state: UlidState::seeded(seed, clock_ms),
+                    clock_ms: Some(clock_ms),
+                }
+            }
+            None if start_timestamp_ms.is_some() => {
+                return Err(PyValueError::new_err(
+                    "start_timestamp_ms needs a seed; unseeded generators follow the system \
+                     clock",
+                ))
+            }
+            None => Inner {
+                state: UlidState::new(),
+                clock_ms: None,
+            },
+        };
+
+        let inner = match node {
+            Some((node_id, bits)) => Inner {
+                state: inner.state.with_node(node_id as u128, bits),
+                ..inner
+            },
+            None => inner,
+        };
+
+        Ok(UlidGenerator {
+            seed,
+            node,
+            inner: Mutex::new(inner),
+        })
+    }
+
+    /// Next ULID in this generator's sequence
+    fn generate(&self, py: Python<'_>) -> PyResult<String> {
+        Ok(self.next(py, "UlidGenerator.generate")?.to_string())
+    }
+
+    /// `generate()` as 16 big-endian bytes
+    fn generate_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        let ulid = self.next(py, "UlidGenerator.generate_bytes")?;
+        Ok(PyBytes::new(py, &ulid.0.to_be_bytes()))
+    }
+
+    /// The next `n` ULIDs of this generator's sequence, generated without the GIL
+    fn generate_batch(&self, py: Python<'_>, n: usize) -> PyResult<Vec<String>> {
+        self.check_clean_words()?;
+
+        errors::guard(py, "UlidGenerator.generate_batch", || {
+            let words = clean::active();
+            let result = py.allow_threads(|| {
+                let mut ulids = Vec::with_capacity(n);
+                while ulids.len() < n {
+                    let chunk_end = n.min(ulids.len() + signals::CHECK_INTERVAL);
+                    // The lock is dropped before checking signals, which retakes the GIL
+                    let mut inner = self.lock();
+                    while ulids.len() < chunk_end {
+                        let ulid = inner.next(words.as_deref()).map_err(|e| {
+                            e.with_suffix(&format!(" (after {} of {} ULIDs)", ulids.len(), n))
+                        })?;
+                        ulids.push(encode_base32_internal(ulid.0));
+                    }
+                    drop(inner);
+
+                    if ulids.len() < n {
+                        signals::check()?;
+                    }
+                }
+                Ok(ulids)
+            });
+            logging::flush(py);
+            result
+        })
+    }
+
+    /// Move the internal clock forward by `ms` milliseconds (seeded generators only)
+    #[pyo3(signature = (ms = 1))]
+    fn advance(&self, ms: u64) -> PyResult<()> {
+        let mut inner = self.internal_clock()?;
+        let clock_ms = inner.clock_ms.unwrap_or_default();
+        match clock_ms.checked_add(ms) {
+            Some(next) if next <= Ulid::bitmask(Ulid::TIME_BITS) as u64 => {
+                inner.clock_ms = Some(next);
+                Ok(())
+            }
+            _ => Err(PyValueError::new_err(
+                "advancing the clock would pass the 48-bit timestamp limit",
+            )),
+        }
+    }
+
+    /// Set the internal clock, backwards too, e.g. to simulate an NTP step (seeded
+    /// generators only)
+    fn set_timestamp(&self, timestamp: crate::TimestampArg) -> PyResult<()> {
+        self.internal_clock()?.clock_ms = Some(timestamp.0);
+        Ok(())
+    }
+
+    /// Continue the sequence from `ulid` as if this generator had just issued it; the
+    /// node bits stay this generator's own (testing aid)
+    fn _resume(&self, ulid: crate::UlidArg) {
+        let ulid = ulid.0;
+        self.lock()
+            .state
+            .resume_at(ulid.timestamp_ms(), ulid.random());
+    }
+
+    // Pickles the configuration, the internal clock and the last ULID, but never the
+    // RNG: a copy that reused it would repeat the original's random components
+    #[allow(clippy::type_complexity)]
+    fn __reduce__<'py>(
+        slf: &Bound<'py, Self>,
+    ) -> PyResult<(
+        Bound<'py, PyAny>,
+        (Option<u64>, Option<u64>, Option<u64>, Option<u8>, u128),
+    )> {
+        let py = slf.py();
+        let this = slf.get();
+        let inner = this.lock();
+        let last = Ulid::from_parts(inner.state.last_timestamp, inner.state.last_random);
+        Ok((
+            slf.get_type().getattr(intern!(py, "_unpickle"))?,
+            (
+                this.seed,
+                inner.clock_ms,
+                this.node_id(),
+                this.node_bits(),
+                last.0,
+            ),
+        ))
+    }
+
+    /// Rebuild a pickled generator with a freshly seeded RNG, continuing after `last`
+    /// with the random component jumped forward, so unpickled copies in different
+    /// processes neither repeat each other nor go back before the original
+    #[staticmethod]
+    fn _unpickle(
+        seed: Option<u64>,
+        clock_ms: Option<crate::TimestampArg>,
+        node_id: Option<u64>,
+        node_bits: Option<u8>,
+        last: u128,
+    ) -> PyResult<Self> {
+        let node = node_config(node_id, node_bits)?;
+        if seed.is_some() {
+            check_clean_words("seeded UlidGenerator")?;
+        }
+        if seed.is_some() != clock_ms.is_some() {
+            return Err(PyValueError::new_err(
+                "a pickled UlidGenerator has an internal clock if and only if it is seeded",
+            ));
+        }
+
+        let last = Ulid(last);
+        let mut state = UlidState::resume(last.timestamp_ms(), last.random());
+        if let Some((node_id, bits)) = node {
+            state = state.with_node(node_id as u128, bits);
+        }
+        state.jump();
+
+        Ok(UlidGenerator {
+            seed,
+            node,
+            inner: Mutex::new(Inner {
+                state,
+                clock_ms: clock_ms.map(|ts| ts.0),
+            }),
+        })
+    }
+
+    #[getter]
+    fn seed(&self) -> Option<u64> {
+        self.seed
+    }
+
+    #[getter]
+    fn node_id(&self) -> Option<u64> {
+        self.node.map(|(node_id, _)| node_id)
+    }
+
+    #[getter]
+    fn node_bits(&self) -> Option<u8> {
+        self.node.map(|(_, bits)| bits)
+    }
+
+    /// The internal clock, or the current time for an unseeded generator, in
+    /// milliseconds since the Unix epoch
+    #[getter]
+    fn timestamp_ms(&self) -> u64 {
+        self.lock().clock_ms.unwrap_or_else(clock::now_ms)
+    }
+
+    fn __repr__(&self) -> String {
+        let mut args = Vec::new();
+        if let Some(seed) = self.seed {
+            args.push(format!(
+                "seed={}, timestamp_ms={}",
+                seed,
+                self.timestamp_ms()
+            ));
+        }
+        if let Some((node_id, bits)) = self.node {
+            args.push(format!("node_id={}, node_bits={}", node_id, bits));
+        }
+        format!("UlidGenerator({})", args.join(", "))
+    }
+}
+
+/// The node ID in the top `bits` of the random component, as set by
+/// `UlidGenerator(node_id=..., node_bits=...)`
+#[pyfunction]
+pub fn ulid_node_id(ulid: crate::UlidArg, bits: u8) -> PyResult<u64> {
+    check_node_bits(bits)?;
+    Ok((ulid.0.random() >> (Ulid::RAND_BITS - bits)) as u64)
+}
@@ -0,0 +1,165 @@
+use pyo3::exceptions::{PyIndexError, PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyInt, PyString};
+
+use crate::{encode_base32_internal, TimestampArg, Ulid, UlidArg};
+
+/// Half-open range `[start, end)` of ULIDs.
+///
+/// Bounds may be ULIDs or timezone-aware datetimes; a datetime stands for the first ULID
+/// of its millisecond, so `UlidInterval(t1, t2)` covers every ID generated from `t1` up
+/// to but excluding `t2`. `start == end` is the empty interval. Intervals are immutable,
+/// ordered by `(start, end)` and hashable. `interval[0]` and `interval[1]` are the bounds,
+/// but intervals are not iterable.
+#[pyclass(module = "pyulid", frozen, eq, ord, hash)]
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UlidInterval {
+    start: u128,
+    end: u128,
+}
+
+/// An interval bound: a ULID string or object, or a timezone-aware datetime
+struct BoundArg(u128);
+
+impl<'py> FromPyObject<'py> for BoundArg {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        // Integer milliseconds would be too easily confused with integer ULIDs
+        if obj.is_instance_of::<PyInt>() {
+            return Err(PyTypeError::new_err(
+                "interval bounds must be ULIDs or datetimes, got int",
+            ));
+        }
+        if obj.is_instance_of::<PyString>()
+            || obj.is_instance_of::<Ulid>()
+            || obj.hasattr("_ulid")?
+        {
+            return Ok(BoundArg(obj.extract::<UlidArg>()?.0 .0));
+        }
+        if obj.hasattr("utcoffset")? {
+            return Ok(BoundArg(
+                Ulid::from_parts(obj.extract::<TimestampArg>()?.0, 0).0,
+            ));
+        }
+        Err(PyTypeError::new_err(format!(
+            "interval bounds must be ULIDs or datetimes, got {}",
+            obj.get_type().name()?
+        )))
+    }
+}
+
+impl UlidInterval {
+    fn is_empty(&self) -> bool {
+        self.start == self.end
+    }
+
+    /// Overlapping or sharing an endpoint, so the union is a single interval
+    fn joins(&self, other: &Self) -> bool {
+        self.start <= other.end && other.start <= self.end
+    }
+}
+
+#[pymethods]
+impl UlidInterval {
+    #[new]
+    fn new(start: BoundArg, end: BoundArg) -> PyResult<Self> {
+        if start.0 > end.0 {
+            return Err(PyValueError::new_err("start must not be after end"));
+        }
+        Ok(UlidInterval {
+            start: start.0,
+            end: end.0,
+        })
+    }
+
+    #[getter]
+    fn start(&self) -> String {
+        encode_base32_internal(self.start)
+    }
+
+    #[getter]
+    fn end(&self) -> String {
+        encode_base32_internal(self.end)
+    }
+
+    /// Milliseconds between the timestamps of the two bounds
+    #[getter]
+    fn duration_ms(&self) -> u64 {
+        Ulid(self.end).timestamp_ms() - Ulid(self.start).timestamp_ms()
+    }
+
+    #[getter(is_empty)]
+    fn py_is_empty(&self) -> bool {
+        self.is_empty()
+    }
+
+    /// True if `start <= ulid < end`
+    fn contains(&self, ulid: UlidArg) -> bool {
+        self.start <= ulid.0 .0 && ulid.0 .0 < self.end
+    }
+
+    fn __contains__(&self, ulid: &Bound<'_, PyAny>) -> bool {
+        ulid.extract::<UlidArg>()
+            .is_ok_and(|ulid| self.contains(ulid))
+    }
+
+    /// True if some ULID lies in both intervals; touching endpoints do not overlap
+    fn overlaps(&self, other: PyRef<'_, Self>) -> bool {
+        !self.is_empty() && !other.is_empty() && self.start < other.end && other.start < self.end
+    }
+
+    /// The ULIDs in both intervals, or an empty interval at the later start
+    fn intersection(&self, other: PyRef<'_, Self>) -> Self {
+        let start = self.start.max(other.start);
+        UlidInterval {
+            start,
+            end: self.end.min(other.end).max(start),
+        }
+    }
+
+    /// The smallest interval covering both.
+    ///
+    /// Intervals that neither overlap nor touch raise ValueError unless `allow_gap=True`,
+    /// in which case the gap between them is included. Empty intervals are ignored.
+    #[pyo3(signature = (other, allow_gap = false))]
+    fn union(&self, other: PyRef<'_, Self>, allow_gap: bool) -> PyResult<Self> {
+        if other.is_empty() {
+            return Ok(*self);
+        }
+        if self.is_empty() {
+            return Ok(*other);
+        }
+        if !allow_gap && !self.joins(&other) {
+            return Err(PyValueError::new_err(
+                "intervals are disjoint; pass allow_gap=True to span the gap",
+            ));
+        }
+        Ok(UlidInterval {
+            start: self.start.min(other.start),
+            end: self.end.max(other.end),
+        })
+    }
+
+    fn __getitem__(&self, index: isize) -> PyResult<String> {
+        match index {
+            0 | -2 => Ok(self.start()),
+            1 | -1 => Ok(self.end()),
+            _ => Err(PyIndexError::new_err("interval index out of range")),
+        }
+    }
+
+    // Indexing would otherwise make intervals iterable, and unpack like the tuples
+    // they replace
+    fn __iter__(&self) -> PyResult<PyObject> {
+        Err(PyTypeError::new_err(
+            "'UlidInterval' object is not iterable",
+        ))
+    }
+
+    fn __bool__(&self) -> bool {
+        !self.is_empty()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("UlidInterval('{}', '{}')", self.start(), self.end())
+    }
+}
@@ -0,0 +1,181 @@
+use pyo3::prelude::*;
+use pyo3::types::PyString;
+
+use crate::{ulid_with_timestamp, TimestampArg};
+
+/// Byte cursor over the timestamp text
+struct Cursor<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Cursor<'_> {
+    fn peek(&self) -> Option<u8> {
+        self.bytes.get(self.pos).copied()
+    }
+
+    fn eat(&mut self, expected: &[u8]) -> Option<u8> {
+        let b = self.peek().filter(|b| expected.contains(b))?;
+        self.pos += 1;
+        Some(b)
+    }
+
+    /// Exactly `n` ASCII digits as a number
+    fn digits(&mut self, n: usize, what: &'static str) -> Result<u32, &'static str> {
+        let end = self.pos + n;
+        let field = self.bytes.get(self.pos..end).ok_or(what)?;
+        if !field.iter().all(u8::is_ascii_digit) {
+            return Err(what);
+        }
+        self.pos = end;
+        Ok(field.iter().fold(0, |n, &b| n * 10 + (b - b'0') as u32))
+    }
+
+    fn expect(&mut self, expected: u8, what: &'static str) -> Result<(), &'static str> {
+        self.eat(&[expected]).map(|_| ()).ok_or(what)
+    }
+}
+
+fn is_leap_year(year: i64) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+/// Days since 1970-01-01 of a proleptic Gregorian date (Howard Hinnant's algorithm)
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let month_index = (month as i64 + 9) % 12; // March is 0
+    let day_of_year = (153 * month_index + 2) / 5 + day as i64 - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// Milliseconds since the Unix epoch of `YYYY-MM-DDTHH:MM[:SS[.fff…]]` followed by `Z`
+/// or a `±HH[:MM]` offset. Digits past milliseconds are truncated.
+fn parse_ms(text: &str) -> Result<i64, &'static str> {
+    let mut cursor = Cursor {
+        bytes: text.as_bytes(),
+        pos: 0,
+    };
+
+    let year = cursor.digits(4, "expected a 4-digit year")? as i64;
+    cursor.expect(b'-', "expected '-' after the year")?;
+    let month = cursor.digits(2, "expected a 2-digit month")?;
+    cursor.expect(b'-', "expected '-' after the month")?;
+    let day = cursor.digits(2, "expected a 2-digit day")?;
+    if !(1..=12).contains(&month) {
+        return Err("month out of range");
+    }
+    let days_in_month = match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    };
+    if !(1..=days_in_month).contains(&day) {
+        return Err("day out of range");
+    }
+
+    if cursor.peek().is_none() {
+        return Err("a date alone is ambiguous; add a time and a UTC offset");
+    }
+    cursor
+        .eat(b"Tt ")
+        .ok_or("expected 'T' between the date and the time")?;
+    let hour = cursor.digits(2, "expected a 2-digit hour")?;
+    cursor.expect(b':', "expected ':' after the hour")?;
+    let minute = cursor.digits(2, "expected 2-digit minutes")?;
+    let mut second = 0;
+    let mut millis = 0;
+    if cursor.eat(b":").is_some() {
+        second = cursor.digits(2, "expected 2-digit seconds")?;
+        if cursor.eat(b".,").is_some() {
+            let start = cursor.pos;
+            while let Some(b) = cursor.peek().filter(u8::is_ascii_digit) {
+                if cursor.pos - start < 3 {
+                    millis = millis * 10 + (b - b'0') as i64;
+                }
+                cursor.pos += 1;
+            }
+            match cursor.pos - start {
+                0 => return Err("expected digits after the decimal point"),
+                1 => millis *= 100,
+                2 => millis *= 10,
+                _ => {}
+            }
+        }
+    }
+    if hour > 23 {
+        return Err("hour out of range");
+    }
+    if minute > 59 {
+        return Err("minutes out of range");
+    }
+    if second == 60 {
+        return Err("leap seconds are not supported");
+    }
+    if second > 59 {
+        return Err("seconds out of range");
+    }
+
+    let offset_minutes = match cursor.eat(b"Zz+-") {
+        Some(b'Z' | b'z') => 0,
+        Some(sign) => {
+            let hours = cursor.digits(2, "expected a 2-digit offset hour")?;
+            let minutes = match cursor.peek() {
+                None => 0,
+                Some(b':') => {
+                    cursor.pos += 1;
+                    cursor.digits(2, "expected 2-digit offset minutes")?
+                }
+                Some(_) => cursor.digits(2, "expected 2-digit offset minutes")?,
+            };
+            if hours > 23 || minutes > 59 {
+                return Err("UTC offset out of range");
+            }
+            let offset = (hours * 60 + minutes) as i64;
+            if sign == b'-' {
+                -offset
+            } else {
+                offset
+            }
+        }
+        None if cursor.peek().is_none() => {
+            return Err("no UTC offset, so the time is ambiguous; add 'Z' or e.g. '+02:00'");
+        }
+        None => return Err("unexpected character after the time"),
+    };
+    if cursor.peek().is_some() {
+        return Err("unexpected text after the UTC offset");
+    }
+
+    let seconds = days_from_civil(year, month, day) * 86_400
+        + (hour * 3600 + minute * 60 + second) as i64
+        - offset_minutes * 60;
+    Ok(seconds * 1000 + millis)
+}
+
+/// Generate a ULID from an ISO 8601 / RFC 3339 timestamp string, parsed in Rust.
+///
+/// Accepts `YYYY-MM-DDTHH:MM[:SS[.fraction]]` (`T`, `t` or a space between date and
+/// time) with a `Z` or `±HH:MM`, `±HHMM` or `±HH` offset. Fractions finer than a
+/// millisecond are truncated. Strings without an offset are rejected as ambiguous,
+/// as are times before 1970.
+#[pyfunction]
+pub fn ulid_from_iso8601<'py>(py: Python<'py>, ts: &str) -> PyResult<Bound<'py, PyString>> {
+    let ms = parse_ms(ts).map_err(|reason| {
+        pyo3::exceptions::PyValueError::new_err(format!(
+            "Invalid ISO 8601 timestamp '{}': {}",
+            ts.escape_debug(),
+            reason
+        ))
+    })?;
+    if ms < 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "ISO 8601 timestamp '{}' is before 1970; ULID timestamps start at the Unix epoch",
+            ts.escape_debug()
+        )));
+    }
+    ulid_with_timestamp(py, TimestampArg(ms as u64), false)
+}
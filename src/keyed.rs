@@ -0,0 +1,175 @@
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::sync::{Mutex, MutexGuard};
+
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyInt, PyString};
+
+use crate::UlidState;
+
+/// Hashable key identifying an independent monotonic sequence
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Key {
+    Str(String),
+    Bytes(Vec<u8>),
+    Int(i128),
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Key::Str(s) => write!(f, "'{}'", s),
+            Key::Bytes(b) => write!(f, "b'{}'", b.escape_ascii()),
+            Key::Int(i) => write!(f, "{}", i),
+        }
+    }
+}
+
+impl<'py> FromPyObject<'py> for Key {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(s) = obj.downcast::<PyString>() {
+            return Ok(Key::Str(s.to_cow()?.into_owned()));
+        }
+        if let Ok(b) = obj.downcast::<PyBytes>() {
+            return Ok(Key::Bytes(b.as_bytes().to_vec()));
+        }
+        if obj.is_instance_of::<PyInt>() {
+            return obj.extract().map(Key::Int).map_err(|_| {
+                pyo3::exceptions::PyValueError::new_err("int key must fit in 128 bits")
+            });
+        }
+
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "key must be str, bytes or int, got {}",
+            obj.get_type().name()?
+        )))
+    }
+}
+
+struct Entry {
+    state: UlidState,
+    tick: u64,
+}
+
+struct KeyedState {
+    entries: HashMap<Key, Entry>,
+    // Access order: oldest tick first
+    lru: BTreeMap<u64, Key>,
+    tick: u64,
+    // Largest (timestamp, random) ever issued by an evicted key
+    evicted_high_water: Option<(u64, u128)>,
+}
+
+impl KeyedState {
+    fn state_for(&mut self, key: Key, max_keys: usize) -> &mut UlidState {
+        self.tick += 1;
+        let tick = self.tick;
+
+        if let Some(entry) = self.entries.get_mut(&key) {
+            self.lru.remove(&entry.tick);
+            entry.tick = tick;
+        } else {
+            if self.entries.len() >= max_keys {
+                self.evict_oldest();
+            }
+
+            // A re-created key must not go back before anything an evicted key issued,
+            // so new sequences resume from the high-water mark while it is still current
+            let state = match self.evicted_high_water {
+                Some((timestamp, random)) if timestamp >= crate::current_timestamp_ms() => {
+                    UlidState::resume(timestamp, random)
+                }
+                _ => UlidState::new(),
+            };
+            self.entries.insert(key.clone(), Entry { state, tick });
+        }
+
+        self.lru.insert(tick, key.clone());
+        &mut self.entries.get_mut(&key).unwrap().state
+    }
+
+    fn evict_oldest(&mut self) {
+        if let Some((_, key)) = self.lru.pop_first() {
+            if let Some(entry) = self.entries.remove(&key) {
+                let last = (entry.state.last_timestamp, entry.state.last_random);
+                crate::logging::emit(crate::logging::DEBUG, || {
+                    format!("evicted least recently used key {}", key)
+                });
+                if self.evicted_high_water.is_none_or(|hw| last > hw) {
+                    self.evicted_high_water = Some(last);
+                }
+            }
+        }
+    }
+}
+
+/// Generator keeping an independent monotonic sequence per key.
+///
+/// Live keys are bounded by `max_keys`; the least recently used key is evicted first.
+#[pyclass(module = "pyulid", frozen)]
+pub struct KeyedUlidGenerator {
+    max_keys: usize,
+    inner: Mutex<KeyedState>,
+}
+
+impl KeyedUlidGenerator {
+    // Poison-tolerant for the same reason as the global state lock
+    fn lock(&self) -> MutexGuard<'_, KeyedState> {
+        self.inner.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+#[pymethods]
+impl KeyedUlidGenerator {
+    #[new]
+    #[pyo3(signature = (max_keys = 10_000))]
+    fn new(max_keys: usize) -> PyResult<Self> {
+        if max_keys == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "max_keys must be at least 1",
+            ));
+        }
+
+        Ok(KeyedUlidGenerator {
+            max_keys,
+            inner: Mutex::new(KeyedState {
+                entries: HashMap::new(),
+                lru: BTreeMap::new(),
+                tick: 0,
+                evicted_high_water: None,
+            }),
+        })
+    }
+
+    /// Generate the next ULID in the sequence for `key`
+    fn generate(&self, py: Python<'_>, key: Key) -> PyResult<String> {
+        crate::errors::guard(py, "KeyedUlidGenerator.generate", || {
+            let result = self.lock().state_for(key, self.max_keys).generate_string();
+            crate::logging::flush(py);
+
+            result.map_err(PyErr::from)
+        })
+    }
+
+    #[getter]
+    fn max_keys(&self) -> usize {
+        self.max_keys
+    }
+
+    /// Number of keys currently tracked
+    fn __len__(&self) -> usize {
+        self.lock().entries.len()
+    }
+
+    fn __contains__(&self, key: Key) -> bool {
+        self.lock().entries.contains_key(&key)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "KeyedUlidGenerator(max_keys={}, live_keys={})",
+            self.max_keys,
+            self.__len__()
+        )
+    }
+}
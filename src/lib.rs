@@ -1,18 +1,60 @@
-use std::{
-    sync::{Mutex, OnceLock},
-    usize,
-};
+// The `forbid-unsafe` build has no unsafe code of its own (dependencies aside)
+#![cfg_attr(feature = "forbid-unsafe", forbid(unsafe_code))]
+
+use std::{borrow::Cow, fmt, net::Ipv6Addr};
 
-use pyo3::prelude::*;
-use rand::Rng;
-use std::time::{SystemTime, UNIX_EPOCH};
+use pyo3::{
+    intern,
+    prelude::*,
+    pybacked::{PyBackedBytes, PyBackedStr},
+    types::{PyByteArray, PyBytes, PyDict, PyFloat, PyInt, PyString, PyTuple},
+};
+use rand::{
+    rngs::{OsRng, StdRng},
+    Rng, SeedableRng, TryRngCore,
+};
 
-// Unified ULID state for both regular and monotonic generation
-static ULID_STATE: OnceLock<Mutex<UlidState>> = OnceLock::new();
+mod abbrev;
+mod analytics;
+mod array;
+mod cache;
+mod checkpoint;
+mod clean;
+mod clock;
+mod columns;
+mod config;
+mod convert;
+mod entropy;
+mod errors;
+mod fork;
+mod generator;
+mod interval;
+mod iso8601;
+mod keyed;
+mod load;
+mod logging;
+mod replay;
+mod set;
+mod shared;
+mod signals;
+mod tag;
 
 // Crockford's Base32 alphabet (exclude I, L, O, U)
 const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
 
+// The same alphabet in lowercase, for `lowercase=True` output
+const ALPHABET_LOWER: &[u8; 32] = b"0123456789abcdefghjkmnpqrstvwxyz";
+
+// RFC 4648 standard Base32 alphabet, used only for interop renderings
+const RFC4648_ALPHABET: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+// RFC 4648 URL-safe Base64 alphabet, for ulid_to_base64
+const BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+// Crockford check symbols: the alphabet followed by the five extra mod 37 symbols
+const CHECK_SYMBOLS: &[u8; 37] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U";
+
 // Pre-computed lookup table for O(1) Base32 decoding
 const DECODE_TABLE: [u8; 256] = {
     let mut table = [0xFF; 256]; // 0xFF = invalid character marker
@@ -51,23 +93,52 @@ const DECODE_TABLE: [u8; 256] = {
     table
 };
 
+/// Milliseconds since the Unix epoch for new ULIDs, never below the minimum timestamp
+fn current_timestamp_ms() -> u64 {
+    clock::effective_ms()
+}
+
+/// Monotonic state owned by one generator object and used under its lock; the
+/// process-wide sequence lives in `shared`
 #[derive(Debug)]
 struct UlidState {
     last_timestamp: u64,
     last_random: u128,
     timestamp_str: [u8; 10], // Pre-encoded timestamp
     buffer: [u8; 26],        // Reusable buffer for string construction
+    rng: StdRng,
+    // Seeded states skip the OS entropy mixed in at rollover, so a seed always gives
+    // the same sequence
+    deterministic: bool,
+    // Fixed high bits of every random component (a node ID) and the mask of the bits
+    // below them, which alone are drawn and incremented
+    node_prefix: u128,
+    random_mask: u128,
+    // `fork::generation()` when the RNG was last drawn; a forked child sees it change
+    fork_generation: u64,
+}
+
+/// A few bytes straight from the OS, mixed in on every millisecond rollover so
+/// cloned processes diverge even if nobody calls reseed()
+fn fresh_entropy() -> u128 {
+    OsRng.try_next_u64().unwrap_or(0) as u128
+}
+
+/// The random component a new millisecond starts from
+fn rollover_random(rng: &mut StdRng) -> u128 {
+    match entropy::mode_rng(rng) {
+        entropy::ModeRng::Fast(rng) => (rng.random::<u128>() ^ fresh_entropy()) & Ulid::bitmask(80),
+        // Straight from the OS already, so there is nothing to mix in
+        mut os => os.random::<u128>() & Ulid::bitmask(80),
+    }
 }
 
 impl UlidState {
     fn new() -> Self {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
+        let timestamp = current_timestamp_ms();
 
-        let mut rng = rand::rng();
-        let random = rng.random::<u128>() & Ulid::bitmask(80);
+        let mut rng = StdRng::from_rng(&mut rand::rng());
+        let random = entropy::mode_rng(&mut rng).random::<u128>() & Ulid::bitmask(80);
 
         // Pre-encode initial timestamp
         let timestamp_str = encode_timestamp(timestamp);
@@ -77,43 +148,197 @@ impl UlidState {
             last_random: random,
             timestamp_str,
             buffer: [b'0'; 26],
+            rng,
+            deterministic: false,
+            node_prefix: 0,
+            random_mask: Ulid::bitmask(80),
+            fork_generation: fork::generation(),
+        }
+    }
+
+    /// Continue from a previously issued ULID (used to keep a high-water mark)
+    fn resume(timestamp: u64, random: u128) -> Self {
+        UlidState {
+            last_timestamp: timestamp,
+            last_random: random,
+            timestamp_str: encode_timestamp(timestamp),
+            buffer: [b'0'; 26],
+            rng: StdRng::from_rng(&mut rand::rng()),
+            deterministic: false,
+            node_prefix: 0,
+            random_mask: Ulid::bitmask(80),
+            fork_generation: fork::generation(),
+        }
+    }
+
+    /// Reproducible state for `UlidGenerator`: everything follows from `seed`
+    fn seeded(seed: u64, timestamp: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        UlidState {
+            last_timestamp: timestamp,
+            last_random: rng.random::<u128>() & Ulid::bitmask(80),
+            timestamp_str: encode_timestamp(timestamp),
+            buffer: [b'0'; 26],
+            rng,
+            deterministic: true,
+            node_prefix: 0,
+            random_mask: Ulid::bitmask(80),
+            fork_generation: fork::generation(),
+        }
+    }
+
+    /// Reserve the top `bits` of every random component for `node_id`
+    fn with_node(mut self, node_id: u128, bits: u8) -> Self {
+        self.node_prefix = node_id << (Ulid::RAND_BITS - bits);
+        self.random_mask = Ulid::bitmask(Ulid::RAND_BITS - bits);
+        self.last_random = self.node_prefix | (self.last_random & self.random_mask);
+        self
+    }
+
+    /// Continue from `timestamp` and `random` as if just issued, keeping any node ID
+    fn resume_at(&mut self, timestamp: u64, random: u128) {
+        self.last_timestamp = timestamp;
+        self.last_random = self.node_prefix | (random & self.random_mask);
+        self.timestamp_str = encode_timestamp(timestamp);
+    }
+
+    /// Jump the random component forward by a random amount below the node ID, so
+    /// copies resumed from the same state diverge within the current millisecond
+    fn jump(&mut self) {
+        let room = self.random_mask - (self.last_random & self.random_mask);
+        if room > 0 {
+            self.last_random += 1 + self.rng.random::<u64>() as u128 % room;
         }
     }
 
     /// string generation using pre-cached timestamp encoding
     #[inline(always)]
-    fn generate_string(&mut self) -> Result<String, String> {
+    fn generate_string(&mut self) -> Result<String, errors::GenerateError> {
+        let words = clean::active();
+        self.generate_string_avoiding(words.as_deref())
+    }
+
+    /// `generate_string`, avoiding the blocked words of `words` if given
+    #[inline(always)]
+    fn generate_string_avoiding(
+        &mut self,
+        words: Option<&clean::Matcher>,
+    ) -> Result<String, errors::GenerateError> {
+        self.advance_avoiding(words)?;
+
+        // String construction using cached timestamp
+        let random_bytes = encode_random(self.last_random);
+        self.buffer[0..10].copy_from_slice(&self.timestamp_str);
+        self.buffer[10..26].copy_from_slice(&random_bytes);
+
+        Ok(buffer_to_string(self.buffer))
+    }
+
+    /// Move the state forward by one ULID, redrawing the random component while the ULID
+    /// spells a word of `words`.
+    ///
+    /// Within a millisecond a redraw steps just past the word (keeping ordering), which
+    /// uses up a little of that millisecond's remaining sequence space.
+    fn advance_avoiding(
+        &mut self,
+        words: Option<&clean::Matcher>,
+    ) -> Result<(), errors::GenerateError> {
+        self.advance_at(current_timestamp_ms(), words)
+    }
+
+    /// `advance_avoiding` with the clock reading supplied by the caller
+    fn advance_at(
+        &mut self,
+        current_timestamp: u64,
+        words: Option<&clean::Matcher>,
+    ) -> Result<(), errors::GenerateError> {
+        // Inherited across a fork: redraw the RNG and jump like the global state, so
+        // parent and child don't continue the same sequence. Seeded states are left
+        // alone, since a seed always reproduces the same IDs
+        if !self.deterministic && self.fork_generation != fork::generation() {
+            self.rng = StdRng::from_rng(&mut rand::rng());
+            self.fork_generation = fork::generation();
+            self.jump();
+        }
         // This random default using monotonic so it can be ordered better
-        let current_timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap()
-            .as_millis() as u64;
+
+        // A step back within the drift tolerance holds the last timestamp instead
+        let current_timestamp = if current_timestamp < self.last_timestamp
+            && self.last_timestamp - current_timestamp <= clock::drift_tolerance_ms()
+        {
+            logging::emit(logging::DEBUG, || {
+                format!(
+                    "clock moved backwards by {} ms within the drift tolerance, holding \
+                     timestamp {}",
+                    self.last_timestamp - current_timestamp,
+                    self.last_timestamp
+                )
+            });
+            self.last_timestamp
+        } else {
+            current_timestamp
+        };
 
         // Update state
         if current_timestamp == self.last_timestamp {
-            if self.last_random == Ulid::bitmask(80) {
-                return Err(
+            if self.last_random & self.random_mask == self.random_mask {
+                logging::emit(logging::WARNING, || {
+                    format!(
+                        "random component overflow at timestamp {}",
+                        current_timestamp
+                    )
+                });
+                return Err(errors::GenerateError::Overflow(
                     "Random component overflow, too many ULIDs in same millisecond".to_string(),
-                );
+                ));
             } else {
                 self.last_random += 1;
+                if let Some(words) = words {
+                    let mut rng = entropy::mode_rng(&mut self.rng);
+                    self.last_random = words.pick(
+                        &self.timestamp_str,
+                        self.last_random,
+                        |rejected, low_bits| {
+                            // Smallest step that changes the word's last character, with
+                            // fresh low bits; jumping further would waste sequence space
+                            let low_mask = Ulid::bitmask(low_bits as u8);
+                            let next = ((rejected >> low_bits) + 1) << low_bits;
+                            (next <= Ulid::bitmask(80))
+                                .then(|| next | (rng.random::<u128>() & low_mask))
+                        },
+                    );
+                }
             }
         } else if current_timestamp > self.last_timestamp {
             self.last_timestamp = current_timestamp;
-            let mut rng = rand::rng();
-            self.last_random = rng.random::<u128>() & Ulid::bitmask(80);
+            self.last_random = if self.deterministic {
+                self.rng.random::<u128>() & Ulid::bitmask(80)
+            } else {
+                rollover_random(&mut self.rng)
+            };
+            self.last_random = self.node_prefix | (self.last_random & self.random_mask);
             self.timestamp_str = encode_timestamp(current_timestamp);
+            if let Some(words) = words {
+                let rng = &mut self.rng;
+                self.last_random = words.pick(&self.timestamp_str, self.last_random, |_, _| {
+                    Some(rollover_random(rng))
+                });
+            }
         } else {
-            return Err("Clock moved backwards, cannot generate ULID".to_string());
+            logging::emit(logging::WARNING, || {
+                format!(
+                    "clock moved backwards by {} ms (last {}, now {})",
+                    self.last_timestamp - current_timestamp,
+                    self.last_timestamp,
+                    current_timestamp
+                )
+            });
+            return Err(errors::GenerateError::Clock(
+                "Clock moved backwards, cannot generate ULID".to_string(),
+            ));
         }
 
-        // String construction using cached timestamp
-        let random_bytes = encode_random(self.last_random);
-        self.buffer[0..10].copy_from_slice(&self.timestamp_str);
-        self.buffer[10..26].copy_from_slice(&random_bytes);
-
-        // Efficient string creation - avoid intermediate Vec
-        Ok(unsafe { String::from_utf8_unchecked(Vec::from(self.buffer)) })
+        Ok(())
     }
 }
 
@@ -127,7 +352,12 @@ fn decode_base32(encoded: &str) -> PyResult<u128> {
     decode_base32_internal(encoded)
 }
 
-#[derive(Debug, Clone, Copy)]
+/// A ULID as a value object.
+///
+/// Compares, sorts and hashes by its 128-bit value, so object order matches the
+/// lexicographic order of the strings. `str()` gives the canonical string.
+#[pyclass(module = "pyulid", frozen, eq, ord, hash, str)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub struct Ulid(u128);
 
 impl Ulid {
@@ -140,6 +370,19 @@ impl Ulid {
         Ulid((time_part << Self::RAND_BITS) | rand_part)
     }
 
+    /// `from_parts`, but ValueError instead of masking a part that doesn't fit
+    pub fn try_from_parts(timestamp_ms: u64, random: u128) -> PyResult<Self> {
+        if timestamp_ms > Self::bitmask(Self::TIME_BITS) as u64 {
+            return Err(timestamp_range_error(timestamp_ms));
+        }
+        if random > Self::bitmask(Self::RAND_BITS) {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "random must be in range 0 <= value < 2**80",
+            ));
+        }
+        Ok(Self::from_parts(timestamp_ms, random))
+    }
+
     pub fn timestamp_ms(&self) -> u64 {
         (self.0 >> Self::RAND_BITS) as u64
     }
@@ -148,36 +391,114 @@ impl Ulid {
         self.0 & Self::bitmask(Self::RAND_BITS)
     }
 
-    pub fn to_string(&self) -> String {
-        encode_base32_internal(self.0)
-    }
-
     const fn bitmask(len: u8) -> u128 {
         (1 << len) - 1
     }
 }
 
+impl fmt::Display for Ulid {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&encode_base32_internal(self.0))
+    }
+}
+
+#[pymethods]
+impl Ulid {
+    /// A new ULID from the global monotonic generator, same as `ulid()`
+    #[staticmethod]
+    #[pyo3(name = "new")]
+    fn py_new(py: Python<'_>) -> PyResult<Self> {
+        errors::guard(py, "Ulid.new", || {
+            let result = shared::next_value();
+            logging::flush(py);
+            result.map(Ulid).map_err(PyErr::from)
+        })
+    }
+
+    /// Parse a ULID string (case-insensitive); raises ValueError if it is invalid
+    #[staticmethod]
+    fn from_string(ulid: UlidArg) -> Self {
+        ulid.0
+    }
+
+    /// Build from 16 big-endian bytes (the `ulid_to_bytes` format)
+    #[staticmethod]
+    fn from_bytes(data: &[u8]) -> PyResult<Self> {
+        let bytes: [u8; 16] = data.try_into().map_err(|_| {
+            errors::decode_error(format!(
+                "ULID bytes must be exactly 16 bytes, got {}",
+                data.len()
+            ))
+        })?;
+        Ok(Ulid(u128::from_be_bytes(bytes)))
+    }
+
+    /// The 16 big-endian bytes, same as `ulid_to_bytes`
+    #[pyo3(name = "to_bytes")]
+    fn py_to_bytes<'py>(&self, py: Python<'py>) -> Bound<'py, PyBytes> {
+        PyBytes::new(py, &self.0.to_be_bytes())
+    }
+
+    #[getter(timestamp_ms)]
+    fn py_timestamp_ms(&self) -> u64 {
+        self.timestamp_ms()
+    }
+
+    #[getter(random)]
+    fn py_random(&self) -> u128 {
+        self.random()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Ulid('{}')", self)
+    }
+
+    // Pickles as `Ulid.from_bytes(<16 bytes>)`, far smaller than the string form
+    fn __reduce__<'py>(
+        slf: &Bound<'py, Self>,
+    ) -> PyResult<(Bound<'py, PyAny>, (Bound<'py, PyBytes>,))> {
+        let py = slf.py();
+        Ok((
+            slf.get_type().getattr(intern!(py, "from_bytes"))?,
+            (slf.get().py_to_bytes(py),),
+        ))
+    }
+
+    // Immutable, so copies can share the object
+    fn __copy__(slf: Bound<'_, Self>) -> Bound<'_, Self> {
+        slf
+    }
+
+    fn __deepcopy__<'py>(slf: Bound<'py, Self>, _memo: &Bound<'py, PyAny>) -> Bound<'py, Self> {
+        slf
+    }
+}
+
 fn encode_base32_internal(mut number: u128) -> String {
     let mut buffer = [b'0'; 26]; // Pre-allocated array
-    let mut pos = 25;
-    while number > 0 && pos > 0 {
+
+    // All 26 positions are written so the top 3 bits of a full u128 land in the first character
+    for pos in (0..26).rev() {
         buffer[pos] = ALPHABET[(number & 0x1f) as usize]; // Direct access to mem pre-allocated
         number >>= 5;
-        pos -= 1;
     }
 
-    String::from_utf8(buffer.to_vec()).unwrap()
+    buffer.iter().map(|&b| b as char).collect()
 }
 
 fn decode_base32_internal(encoded: &str) -> Result<u128, pyo3::PyErr> {
     let mut result: u128 = 0;
 
-    for byte in encoded.bytes() {
+    for (index, byte) in encoded.bytes().enumerate() {
         let value = DECODE_TABLE[byte as usize];
         if value == 0xFF {
-            return Err(pyo3::exceptions::PyValueError::new_err(format!(
-                "Invalid character '{}' in Base32 string",
-                byte as char
+            // Every byte before this one was ASCII, so `index` is also the character
+            // position and starts a whole character, even a multi-byte one
+            let c = encoded[index..].chars().next().unwrap_or_default();
+            return Err(errors::decode_error(format!(
+                "Invalid character '{}' at position {} in Base32 string",
+                c.escape_debug(),
+                index
             )));
         }
         result = (result << 5) | (value as u128);
@@ -186,160 +507,2526 @@ fn decode_base32_internal(encoded: &str) -> Result<u128, pyo3::PyErr> {
     Ok(result)
 }
 
-#[inline(always)]
-fn encode_timestamp(mut timestamp: u64) -> [u8; 10] {
-    let mut buffer = [b'0'; 10];
-
-    // Encode from right to left
-    for i in (0..10).rev() {
-        buffer[i] = ALPHABET[(timestamp & 0x1F) as usize];
-        timestamp >>= 5; // Bit shift equals to n /= 32
+/// Shared length + charset validation for every function that parses a ULID string
+fn parse_ulid(ulid_str: &str) -> PyResult<Ulid> {
+    if ulid_str.len() != 26 {
+        return Err(check_ulid(ulid_str).unwrap_err().into());
     }
 
-    buffer
+    if let Some(value) = cache::lookup(ulid_str.as_bytes()) {
+        return Ok(Ulid(value));
+    }
+    match decode_ascii(ulid_str.as_bytes()) {
+        Some(value) if leading_in_range(ulid_str) => {
+            cache::store(ulid_str.as_bytes(), value);
+            Ok(Ulid(value))
+        }
+        // Only failures pay for the character-by-character diagnosis
+        _ => Err(check_ulid(ulid_str).unwrap_err().into()),
+    }
 }
 
+/// The first of 26 characters holds only the top 3 of the 128 bits, so above '7' the
+/// value would need 130 bits
 #[inline(always)]
-fn encode_random(mut random: u128) -> [u8; 16] {
-    let mut buffer = [b'0'; 16];
+fn leading_in_range(ulid_str: &str) -> bool {
+    ulid_str.as_bytes().first().is_some_and(|&b| b <= b'7')
+}
 
-    // Encode from right to left like
-    for i in (0..16).rev() {
-        buffer[i] = ALPHABET[(random & 0x1F) as usize];
-        random >>= 5; // Bit shift instead of division
+/// Why a string is not a ULID; every parser reports through this so the messages agree
+enum Invalid {
+    /// Length in characters (not bytes)
+    Length(usize),
+    /// The first character outside the Base32 alphabet, and its character index
+    Character(char, usize),
+    /// The leading character, above '7'
+    Overflow(char),
+}
+
+impl Invalid {
+    fn reason(&self) -> &'static str {
+        match self {
+            Invalid::Length(_) => "length",
+            Invalid::Character(..) => "character",
+            Invalid::Overflow(_) => "overflow",
+        }
     }
 
-    buffer
+    fn message(&self) -> String {
+        match self {
+            Invalid::Length(length) => {
+                format!("ULID must be exactly 26 characters, got {}", length)
+            }
+            Invalid::Character(c, index) => format!(
+                "Invalid character '{}' at position {} in ULID",
+                c.escape_debug(),
+                index
+            ),
+            Invalid::Overflow(c) => format!(
+                "ULID must start with 0-7, got '{}'; larger values don't fit in 128 bits",
+                c
+            ),
+        }
+    }
 }
 
-#[pyfunction]
-fn ulid() -> PyResult<String> {
-    let state_mutex = ULID_STATE.get_or_init(|| Mutex::new(UlidState::new()));
-    let mut state = state_mutex.lock().unwrap();
-
-    match state.generate_string() {
-        Ok(ulid_str) => Ok(ulid_str),
-        Err(e) => Err(pyo3::exceptions::PyRuntimeError::new_err(e)),
+impl From<Invalid> for PyErr {
+    fn from(invalid: Invalid) -> Self {
+        errors::decode_error(invalid.message())
     }
 }
 
-#[pyfunction]
-fn ulid_timestamp(ulid_str: &str) -> PyResult<u64> {
-    if ulid_str.len() != 26 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "ULID must be exactly 26 characters",
+/// Length, alphabet and range checks of a ULID string, in that order
+fn check_ulid(ulid_str: &str) -> Result<(), Invalid> {
+    if ulid_str.len() != 26 || !ulid_str.is_ascii() {
+        let length = ulid_str.chars().count();
+        if length != 26 {
+            return Err(Invalid::Length(length));
+        }
+    }
+
+    for (index, c) in ulid_str.chars().enumerate() {
+        if !c.is_ascii() || DECODE_TABLE[c as usize] == 0xFF {
+            return Err(Invalid::Character(c, index));
+        }
+    }
+
+    if !leading_in_range(ulid_str) {
+        return Err(Invalid::Overflow(
+            ulid_str.chars().next().unwrap_or_default(),
         ));
     }
+    Ok(())
+}
+
+/// Decode without any checks, for trusted input only.
+///
+/// Garbage gives an unspecified value but never panics or reads out of bounds: invalid
+/// bytes are masked into the 5-bit range and extra characters just shift out.
+#[inline(always)]
+fn decode_unchecked(raw: &[u8]) -> u128 {
+    fn fold(raw: &[u8]) -> u64 {
+        raw.iter().fold(0u64, |acc, &b| {
+            (acc << 5) | (DECODE_TABLE[b as usize] & 0x1F) as u64
+        })
+    }
 
-    let decoded = decode_base32_internal(ulid_str)?;
-    let ulid = Ulid(decoded);
+    match raw.len() {
+        // Three independent 64-bit chains instead of one 128-bit one
+        26 => {
+            let (high, low) = raw.split_at(16);
+            let (high, mid) = high.split_at(6);
+            ((fold(high) as u128) << 100) | ((fold(mid) as u128) << 50) | fold(low) as u128
+        }
+        _ => raw.iter().fold(0u128, |acc, &b| {
+            (acc << 5) | (DECODE_TABLE[b as usize] & 0x1F) as u128
+        }),
+    }
+}
 
-    Ok(ulid.timestamp_ms())
+/// The `ulid_str` argument of a function with `validate=False`, left unextracted
+///
+/// Unvalidated calls read the text in place, with no `PyBackedStr`, ASCII, length or
+/// alphabet checks, and decode only the characters the result needs. Validated calls
+/// extract it like a typed parameter would, error messages included.
+struct MaybeUnchecked<'a, 'py> {
+    arg: &'a Bound<'py, PyAny>,
+    validate: bool,
+    name: &'static str,
 }
 
-#[pyfunction]
-fn ulid_random(ulid_str: &str) -> PyResult<u128> {
-    if ulid_str.len() != 26 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "ULID must be exactly 26 characters",
-        ));
+impl<'a, 'py> MaybeUnchecked<'a, 'py> {
+    fn new(arg: &'a Bound<'py, PyAny>, validate: bool) -> Self {
+        MaybeUnchecked {
+            arg,
+            validate,
+            name: "ulid_str",
+        }
+    }
+
+    /// The raw text when unvalidated, `None` when it must be parsed; `bytes` is only
+    /// read in place for functions that accept it
+    #[inline(always)]
+    fn unchecked(&self, bytes_ok: bool) -> Option<&'a [u8]> {
+        if self.validate {
+            return None;
+        }
+        if let Ok(s) = self.arg.downcast::<PyString>() {
+            return s.to_str().ok().map(str::as_bytes);
+        }
+        match self.arg.downcast::<PyBytes>() {
+            Ok(b) if bytes_ok => Some(b.as_bytes()),
+            _ => None,
+        }
     }
 
-    let decoded = decode_base32_internal(ulid_str)?;
-    let ulid = Ulid(decoded);
+    /// The ULID of a `str`, `bytes` or `bytearray` argument
+    fn text(&self) -> PyResult<Ulid> {
+        if let Some(raw) = self.unchecked(true) {
+            return Ok(Ulid(decode_unchecked(raw)));
+        }
+        let text: UlidText = self.extract()?;
+        let text = text.as_str()?;
+        if self.validate {
+            parse_ulid(text)
+        } else {
+            // bytearray, which can't be borrowed in place
+            Ok(Ulid(decode_unchecked(text.as_bytes())))
+        }
+    }
+
+    /// The ULID of a `str` argument
+    fn str(&self) -> PyResult<Ulid> {
+        if let Some(raw) = self.unchecked(false) {
+            return Ok(Ulid(decode_unchecked(raw)));
+        }
+        parse_ulid(&self.extract::<PyBackedStr>()?)
+    }
+
+    /// The ULID of a `UlidArg` argument; `Ulid` and `ULID` objects are already valid
+    fn ulid_arg(&self) -> PyResult<Ulid> {
+        if let Some(raw) = self.unchecked(false) {
+            return Ok(Ulid(decode_unchecked(raw)));
+        }
+        Ok(self.extract::<UlidArg>()?.0)
+    }
+
+    /// `self.text()?.timestamp_ms()`, decoding just the 10 timestamp characters
+    fn timestamp_ms(&self) -> PyResult<u64> {
+        match self.unchecked(true) {
+            Some(raw) => {
+                Ok((decode_unchecked(&raw[..raw.len().min(10)]) & Ulid::bitmask(48)) as u64)
+            }
+            None => Ok(self.text()?.timestamp_ms()),
+        }
+    }
+
+    /// `self.text()?.random()`, decoding just the 16 random characters
+    fn random(&self) -> PyResult<u128> {
+        match self.unchecked(true) {
+            Some(raw) => Ok(decode_unchecked(&raw[raw.len().saturating_sub(16)..])),
+            None => Ok(self.text()?.random()),
+        }
+    }
 
-    Ok(ulid.random())
+    fn extract<T: FromPyObject<'py>>(&self) -> PyResult<T> {
+        self.arg.extract().map_err(|e| {
+            let py = self.arg.py();
+            if e.get_type(py)
+                .is(&py.get_type::<pyo3::exceptions::PyTypeError>())
+            {
+                pyo3::exceptions::PyTypeError::new_err(format!(
+                    "argument '{}': {}",
+                    self.name,
+                    e.value(py)
+                ))
+            } else {
+                e
+            }
+        })
+    }
 }
 
-#[pyfunction]
-fn ulid_is_valid(ulid_str: &str) -> bool {
-    if ulid_str.len() != 26 {
-        return false;
+/// A ULID argument: a string, a `Ulid`, or a `ULID` object from the Python wrapper
+struct UlidArg(Ulid);
+
+impl<'py> FromPyObject<'py> for UlidArg {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(s) = obj.downcast::<PyString>() {
+            return Ok(UlidArg(parse_ulid(&s.to_cow()?)?));
+        }
+        if let Ok(ulid) = obj.downcast::<Ulid>() {
+            return Ok(UlidArg(*ulid.get()));
+        }
+
+        // The Python `ULID` wrapper keeps its canonical string in `_ulid`
+        if let Ok(inner) = obj.getattr(intern!(obj.py(), "_ulid")) {
+            if let Ok(s) = inner.downcast::<PyString>() {
+                return Ok(UlidArg(parse_ulid(&s.to_cow()?)?));
+            }
+        }
+
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "expected a ULID string or ULID object, got {}",
+            obj.get_type().name()?
+        )))
     }
+}
 
-    // Validation to check all characters is base 32
-    ulid_str
-        .chars()
-        .all(|c| ALPHABET.contains(&(c.to_ascii_uppercase() as u8)))
+/// A ULID string argument: `str`, or ASCII `bytes`/`bytearray` as read off the wire
+enum UlidText {
+    Str(PyBackedStr),
+    Bytes(PyBackedBytes),
 }
 
-#[pyfunction]
-fn ulid_with_timestamp(timestamp_ms: u64) -> PyResult<String> {
-    let mut rng = rand::rng();
-    let random: u128 = rng.random::<u128>() & Ulid::bitmask(80);
-    let ulid = Ulid::from_parts(timestamp_ms, random);
-    Ok(ulid.to_string())
+impl<'py> FromPyObject<'py> for UlidText {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(s) = obj.downcast::<PyString>() {
+            return Ok(UlidText::Str(s.clone().try_into()?));
+        }
+        if let Ok(b) = obj.downcast::<PyBytes>() {
+            return Ok(UlidText::Bytes(b.clone().into()));
+        }
+        if let Ok(b) = obj.downcast::<PyByteArray>() {
+            return Ok(UlidText::Bytes(b.clone().into()));
+        }
+
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "ULID must be str, bytes or bytearray, got {}",
+            obj.get_type().name()?
+        )))
+    }
 }
 
-#[pyfunction]
-fn ulid_to_uuid(ulid_str: &str) -> PyResult<String> {
-    if ulid_str.len() != 26 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "ULID must be exactly 26 characters",
-        ));
+impl UlidText {
+    /// The text to parse; bytes outside ASCII raise ValueError rather than being decoded
+    fn as_str(&self) -> PyResult<&str> {
+        match self {
+            UlidText::Str(s) => Ok(s),
+            UlidText::Bytes(b) => {
+                if let Some(index) = b.iter().position(|b| !b.is_ascii()) {
+                    return Err(errors::decode_error(format!(
+                        "Invalid byte 0x{:02X} at position {} in ULID, expected ASCII",
+                        b[index], index
+                    )));
+                }
+                std::str::from_utf8(b).map_err(|e| errors::decode_error(e.to_string()))
+            }
+        }
     }
+}
 
-    let decoded = decode_base32_internal(ulid_str)?;
+/// The last millisecond a ULID timestamp can hold, 2**48 - 1
+const MAX_TIMESTAMP_DATE: &str = "10889-08-02T05:31:50.655Z";
 
-    let hex = format!("{:032x}", decoded);
-    let uuid = format!(
-        "{}-{}-{}-{}-{}",
-        &hex[0..8],
-        &hex[8..12],
-        &hex[12..16],
-        &hex[16..20],
-        &hex[20..32]
-    );
+/// ValueError for a millisecond timestamp outside the 48 bits of a ULID
+fn timestamp_range_error(ms: impl fmt::Display) -> PyErr {
+    pyo3::exceptions::PyValueError::new_err(format!(
+        "timestamp must be in range 0 <= ms < 2**48 (up to {}), got {}",
+        MAX_TIMESTAMP_DATE, ms
+    ))
+}
+
+/// A timestamp argument: integer milliseconds or a timezone-aware `datetime`
+struct TimestampArg(u64);
+
+impl<'py> FromPyObject<'py> for TimestampArg {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let py = obj.py();
+        let ms: i128 = if obj.is_instance_of::<PyInt>() {
+            // Anything beyond i128 is out of range anyway
+            obj.extract().unwrap_or(i128::MAX)
+        } else if obj.hasattr(intern!(py, "utcoffset"))? {
+            if obj.call_method0(intern!(py, "utcoffset"))?.is_none() {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "naive datetime is ambiguous; attach a tzinfo (e.g. timezone.utc)",
+                ));
+            }
+            // Exact integer arithmetic on the timedelta, avoiding float rounding of .timestamp()
+            let datetime = py.import(intern!(py, "datetime"))?;
+            let utc = datetime
+                .getattr(intern!(py, "timezone"))?
+                .getattr(intern!(py, "utc"))?;
+            let epoch = datetime
+                .getattr(intern!(py, "datetime"))?
+                .call((1970, 1, 1, 0, 0, 0, 0, utc), None)?;
+            let delta = obj.sub(epoch)?;
+            let days: i128 = delta.getattr(intern!(py, "days"))?.extract()?;
+            let seconds: i128 = delta.getattr(intern!(py, "seconds"))?.extract()?;
+            let micros: i128 = delta.getattr(intern!(py, "microseconds"))?.extract()?;
+            days * 86_400_000 + seconds * 1000 + micros / 1000
+        } else {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "timestamp must be int milliseconds or datetime, got {}",
+                obj.get_type().name()?
+            )));
+        };
 
-    Ok(uuid)
+        if ms < 0 || ms > Ulid::bitmask(Ulid::TIME_BITS) as i128 {
+            return Err(timestamp_range_error(ms));
+        }
+        Ok(TimestampArg(ms as u64))
+    }
 }
 
-#[pyfunction]
-fn uuid_to_ulid(uuid_str: &str) -> PyResult<String> {
-    let hex_only: String = uuid_str.chars().filter(|c| *c != '-').collect();
+/// A random-component argument: an 80-bit integer or exactly 10 big-endian bytes
+struct RandomArg(u128);
 
-    if hex_only.len() != 32 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "UUID must be 32 hex characters (with or without dashes)",
-        ));
+impl<'py> FromPyObject<'py> for RandomArg {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(b) = obj.downcast::<PyBytes>() {
+            let bytes = b.as_bytes();
+            if bytes.len() != 10 {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "random bytes must be exactly 10 bytes, got {}",
+                    bytes.len()
+                )));
+            }
+            return Ok(RandomArg(
+                bytes.iter().fold(0u128, |acc, &b| (acc << 8) | b as u128),
+            ));
+        }
+
+        if obj.is_instance_of::<PyInt>() {
+            let value: i128 = obj.extract().map_err(|_| {
+                pyo3::exceptions::PyValueError::new_err(
+                    "random must be in range 0 <= value < 2**80",
+                )
+            })?;
+            if value < 0 || value > Ulid::bitmask(Ulid::RAND_BITS) as i128 {
+                return Err(pyo3::exceptions::PyValueError::new_err(
+                    "random must be in range 0 <= value < 2**80",
+                ));
+            }
+            return Ok(RandomArg(value as u128));
+        }
+
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "random must be int or bytes, got {}",
+            obj.get_type().name()?
+        )))
     }
+}
 
-    let decoded = u128::from_str_radix(&hex_only, 16)
-        .map_err(|_| pyo3::exceptions::PyValueError::new_err("Invalid hex characters in UUID"))?;
+/// Encoding table for generated and normalized ULIDs
+#[inline(always)]
+fn alphabet(lowercase: bool) -> &'static [u8; 32] {
+    if lowercase {
+        ALPHABET_LOWER
+    } else {
+        ALPHABET
+    }
+}
 
-    Ok(encode_base32_internal(decoded))
+#[inline(always)]
+fn encode_timestamp(timestamp: u64) -> [u8; 10] {
+    encode_timestamp_in(timestamp, ALPHABET)
 }
 
-#[pyfunction]
-fn ulid_from_str(ulid_str: &str) -> PyResult<String> {
-    if ulid_str.len() != 26 {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "ULID must be exactly 26 characters",
-        ));
+#[inline(always)]
+fn encode_timestamp_in(mut timestamp: u64, alphabet: &[u8; 32]) -> [u8; 10] {
+    let mut buffer = [b'0'; 10];
+
+    // Encode from right to left
+    for i in (0..10).rev() {
+        buffer[i] = alphabet[(timestamp & 0x1F) as usize];
+        timestamp >>= 5; // Bit shift equals to n /= 32
     }
 
-    if !ulid_is_valid(ulid_str) {
-        return Err(pyo3::exceptions::PyValueError::new_err(
-            "Invalid ULID string format",
-        ));
+    buffer
+}
+
+#[inline(always)]
+fn encode_random(random: u128) -> [u8; 16] {
+    encode_random_in(random, ALPHABET)
+}
+
+#[inline(always)]
+fn encode_random_in(mut random: u128, alphabet: &[u8; 32]) -> [u8; 16] {
+    let mut buffer = [b'0'; 16];
+
+    // Encode from right to left like
+    for i in (0..16).rev() {
+        buffer[i] = alphabet[(random & 0x1F) as usize];
+        random >>= 5; // Bit shift instead of division
     }
 
-    // Return normalized (uppercase) version
-    Ok(ulid_str.to_ascii_uppercase())
+    buffer
+}
+
+/// The 26 ASCII characters of a ULID in the given alphabet
+#[inline(always)]
+fn encode_ascii(timestamp: u64, random: u128, alphabet: &[u8; 32]) -> [u8; 26] {
+    let mut buffer = [0u8; 26];
+    buffer[..10].copy_from_slice(&encode_timestamp_in(timestamp, alphabet));
+    buffer[10..].copy_from_slice(&encode_random_in(random, alphabet));
+    buffer
+}
+
+/// View of a buffer filled only from `ALPHABET` or `ALPHABET_LOWER` as text
+#[cfg(not(feature = "forbid-unsafe"))]
+#[inline(always)]
+fn buffer_as_str(buffer: &[u8; 26]) -> &str {
+    // SAFETY: every byte comes from the ASCII alphabet, so the buffer is valid UTF-8
+    unsafe { std::str::from_utf8_unchecked(buffer) }
+}
+
+/// View of a buffer filled only from `ALPHABET` or `ALPHABET_LOWER` as text, checked
+/// instead of trusted
+#[cfg(feature = "forbid-unsafe")]
+#[inline(always)]
+fn buffer_as_str(buffer: &[u8; 26]) -> &str {
+    std::str::from_utf8(buffer).expect("the Base32 alphabets are ASCII")
+}
+
+/// Owned string from an encoded buffer, for callers that keep it on the Rust side.
+/// Functions returning straight to Python build the `str` from `buffer_as_str` instead,
+/// which copies once, into the Python object.
+#[inline(always)]
+fn buffer_to_string(buffer: [u8; 26]) -> String {
+    buffer_as_str(&buffer).to_owned()
+}
+
+/// A new monotonic ULID; `lowercase=True` spells it in lowercase Crockford Base32
+#[pyfunction]
+#[pyo3(signature = (*, lowercase = false))]
+fn ulid(py: Python<'_>, lowercase: bool) -> PyResult<Bound<'_, PyString>> {
+    errors::guard(py, "ulid", || {
+        let result = shared::next_ascii(alphabet(lowercase));
+        logging::flush(py);
+
+        match result {
+            Ok(ascii) => Ok(PyString::new(py, buffer_as_str(&ascii))),
+            Err(e) => Err(e.into()),
+        }
+    })
+}
+
+/// `ulid()` built the old way, through an owned `String` that pyo3 then copies into
+/// the `str`; only in `test-hooks` builds, as the string-construction benchmark baseline
+#[cfg(feature = "test-hooks")]
+#[pyfunction]
+#[pyo3(signature = (*, lowercase = false))]
+fn _ulid_via_string(py: Python<'_>, lowercase: bool) -> PyResult<String> {
+    errors::guard(py, "_ulid_via_string", || {
+        let result = shared::next_ascii(alphabet(lowercase));
+        logging::flush(py);
+        Ok(buffer_to_string(result?))
+    })
+}
+
+/// A new ULID as 16 big-endian bytes, without building the string.
+///
+/// Shares the monotonic sequence with `ulid()`, so the two can be interleaved freely.
+#[pyfunction]
+fn ulid_bytes(py: Python<'_>) -> PyResult<Bound<'_, PyBytes>> {
+    errors::guard(py, "ulid_bytes", || {
+        let result = shared::next_value();
+        logging::flush(py);
+
+        result
+            .map(|value| PyBytes::new(py, &value.to_be_bytes()))
+            .map_err(PyErr::from)
+    })
+}
+
+/// Generate a monotonic ULID straight into `buffer` at `offset`, returning 26.
+///
+/// `buffer` is any writable, C-contiguous object with the buffer protocol and
+/// single-byte items: `bytearray`, `memoryview`, a `uint8` NumPy array. The 26 ASCII
+/// characters are copied in place with no `str` allocated. Shares the monotonic
+/// sequence with `ulid()`. A read-only or non-contiguous buffer raises `BufferError`,
+/// one with fewer than 26 bytes at `offset` raises `ValueError`; either way no ULID is
+/// generated.
+#[pyfunction]
+#[pyo3(signature = (buffer, offset = 0))]
+fn ulid_into(py: Python<'_>, buffer: &Bound<'_, PyAny>, offset: usize) -> PyResult<usize> {
+    let target = WritableBytes::get(buffer)?;
+    let available = target.len().saturating_sub(offset);
+    if available < 26 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "buffer too small: a ULID needs 26 bytes at offset {}, only {} available",
+            offset, available
+        )));
+    }
+
+    let ascii = errors::guard(py, "ulid_into", || {
+        let result = shared::next_ascii(ALPHABET);
+        logging::flush(py);
+
+        result.map_err(PyErr::from)
+    })?;
+    target.write(py, offset, &ascii)?;
+    Ok(ascii.len())
+}
+
+/// A writable byte buffer borrowed from a Python object
+#[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+struct WritableBytes(pyo3::buffer::PyBuffer<u8>);
+
+#[cfg(any(not(Py_LIMITED_API), Py_3_11))]
+impl WritableBytes {
+    fn get(obj: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let buffer = pyo3::buffer::PyBuffer::<u8>::get(obj)?;
+        if buffer.readonly() {
+            return Err(pyo3::exceptions::PyBufferError::new_err(
+                "buffer is read-only",
+            ));
+        }
+        if !buffer.is_c_contiguous() {
+            return Err(pyo3::exceptions::PyBufferError::new_err(
+                "buffer must be C-contiguous",
+            ));
+        }
+        Ok(WritableBytes(buffer))
+    }
+
+    fn len(&self) -> usize {
+        self.0.item_count()
+    }
+
+    fn write(&self, py: Python<'_>, offset: usize, bytes: &[u8]) -> PyResult<()> {
+        let cells = self.0.as_mut_slice(py).ok_or_else(|| {
+            pyo3::exceptions::PyBufferError::new_err("buffer is no longer writable")
+        })?;
+        for (cell, &b) in cells[offset..offset + bytes.len()].iter().zip(bytes) {
+            cell.set(b);
+        }
+        Ok(())
+    }
+}
+
+/// A writable byte buffer borrowed from a Python object, through `memoryview` since
+/// the buffer protocol only joined the limited API in 3.11
+#[cfg(all(Py_LIMITED_API, not(Py_3_11)))]
+struct WritableBytes<'py> {
+    view: Bound<'py, PyAny>,
+    nbytes: usize,
+}
+
+#[cfg(all(Py_LIMITED_API, not(Py_3_11)))]
+impl<'py> WritableBytes<'py> {
+    fn get(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let py = obj.py();
+        let view = pyo3::types::PyMemoryView::from(obj)?.into_any();
+        if view.getattr(intern!(py, "readonly"))?.is_truthy()? {
+            return Err(pyo3::exceptions::PyBufferError::new_err(
+                "buffer is read-only",
+            ));
+        }
+        if !view.getattr(intern!(py, "c_contiguous"))?.is_truthy()? {
+            return Err(pyo3::exceptions::PyBufferError::new_err(
+                "buffer must be C-contiguous",
+            ));
+        }
+        if view.getattr(intern!(py, "itemsize"))?.extract::<usize>()? != 1 {
+            return Err(pyo3::exceptions::PyBufferError::new_err(
+                "buffer contents are not compatible with u8",
+            ));
+        }
+        let nbytes = view.getattr(intern!(py, "nbytes"))?.extract()?;
+        Ok(WritableBytes { view, nbytes })
+    }
+
+    fn len(&self) -> usize {
+        self.nbytes
+    }
+
+    fn write(&self, py: Python<'_>, offset: usize, bytes: &[u8]) -> PyResult<()> {
+        let view = self
+            .view
+            .call_method1(intern!(py, "cast"), (intern!(py, "B"),))?;
+        let slice =
+            pyo3::types::PySlice::new(py, offset as isize, (offset + bytes.len()) as isize, 1);
+        view.set_item(slice, PyBytes::new(py, bytes))
+    }
+}
+
+/// `n` monotonic ULID values, for batch functions running without the GIL.
+///
+/// Values are claimed a run at a time from the shared state, so ULIDs from other
+/// threads may interleave with a large batch; the batch itself is still increasing. An
+/// overflow error says how many of the `n` had been generated.
+pub(crate) fn generate_batch(n: usize) -> PyResult<Vec<u128>> {
+    let mut values: Vec<u128> = Vec::with_capacity(n);
+    generate_runs(n, |timestamp, first, count| {
+        values.extend((0..count).map(|i| Ulid::from_parts(timestamp, first + i as u128).0));
+    })?;
+    Ok(values)
+}
+
+/// `generate_batch` without the Vec: each claimed run goes to `emit` as (timestamp,
+/// first random component, count), for callers that write the records out themselves
+fn generate_runs(n: usize, mut emit: impl FnMut(u64, u128, u64)) -> PyResult<()> {
+    let mut done = 0usize;
+    while done < n {
+        let chunk_end = n.min(done + signals::CHECK_INTERVAL);
+        while done < chunk_end {
+            let (timestamp, first, count) =
+                shared::claim((chunk_end - done) as u64).map_err(|e| match e {
+                    shared::ClaimError::Failed(error) => error
+                        .with_suffix(&format!(" (after {} of {} ULIDs)", done, n))
+                        .into(),
+                    shared::ClaimError::Source(e) => e,
+                })?;
+            emit(timestamp, first, count);
+            done += count as usize;
+        }
+
+        if done < n {
+            signals::check()?;
+        }
+    }
+    Ok(())
+}
+
+/// Generate `n` monotonic ULIDs as a list of strings, without the GIL.
+///
+/// Cheaper than `n` calls to `ulid()`; use `ulid_batch_array` to skip the strings too.
+#[pyfunction]
+fn ulid_batch(py: Python<'_>, n: usize) -> PyResult<Vec<String>> {
+    errors::guard(py, "ulid_batch", || {
+        let ulids = py.allow_threads(|| {
+            let values = generate_batch(n)?;
+            Ok::<_, PyErr>(values.into_iter().map(encode_base32_internal).collect())
+        });
+        logging::flush(py);
+        ulids
+    })
+}
+
+/// A minimum: a ULID to sort after, a timestamp, or None to lower the floor
+enum FloorArg {
+    Ulid(Ulid),
+    Timestamp(u64),
+    Clear,
+}
+
+impl<'py> FromPyObject<'py> for FloorArg {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if obj.is_none() {
+            Ok(FloorArg::Clear)
+        } else if obj.is_instance_of::<PyString>()
+            || obj.is_instance_of::<Ulid>()
+            || obj.hasattr(intern!(obj.py(), "_ulid"))?
+        {
+            Ok(FloorArg::Ulid(obj.extract::<UlidArg>()?.0))
+        } else {
+            Ok(FloorArg::Timestamp(obj.extract::<TimestampArg>()?.0))
+        }
+    }
+}
+
+/// Make every ULID generated from now on sort after `minimum`, even if the wall clock
+/// is behind it (e.g. after restoring a backup taken on a host with a faster clock).
+///
+/// `minimum` is the newest restored ULID (new ones sort strictly after it), a timestamp
+/// in int milliseconds or a datetime (new ones use at least that millisecond), or None.
+/// While the wall clock is behind the floor, ULIDs keep its timestamp and increment
+/// monotonically. The floor stays until the wall clock passes it or it is lowered
+/// again; it is never lowered below a ULID already issued. `stats()` reports it.
+#[pyfunction]
+fn set_minimum_timestamp(py: Python<'_>, minimum: FloorArg) -> PyResult<()> {
+    errors::guard(py, "set_minimum_timestamp", || {
+        let (after, mut floor) = match minimum {
+            FloorArg::Ulid(ulid) => (Some(ulid), ulid.timestamp_ms()),
+            FloorArg::Timestamp(timestamp) => (None, timestamp),
+            FloorArg::Clear => (None, 0),
+        };
+
+        // ULIDs already issued ahead of the clock still bound what may come next
+        let last_timestamp = shared::raise_past(after);
+        if last_timestamp > clock::now_ms() {
+            floor = floor.max(last_timestamp);
+        }
+        clock::set_floor(floor);
+        logging::flush(py);
+        Ok(())
+    })
+}
+
+/// Generate a monotonic ULID that spells none of the blocked words.
+///
+/// A one-shot form of `configure(clean_words=True)`: uses the configured list, or the
+/// built-in one while filtering is off. The random component is redrawn up to 16 times;
+/// a word inside the timestamp itself can't be avoided and is returned as is.
+#[pyfunction]
+fn ulid_clean(py: Python<'_>) -> PyResult<String> {
+    errors::guard(py, "ulid_clean", || {
+        entropy::check_no_source("ulid_clean()")?;
+        let words = clean::active_or_default();
+        let result = shared::next_string_avoiding(&words, ALPHABET);
+        logging::flush(py);
+
+        result.map_err(PyErr::from)
+    })
+}
+
+/// Discard the generator's RNG state and reseed it from OS entropy.
+///
+/// Call this from post-restore hooks after a VM snapshot, container clone or CRIU
+/// restore, where two instances would otherwise resume with identical state.
+/// Monotonic ordering is preserved.
+#[pyfunction]
+fn reseed(py: Python<'_>) -> PyResult<()> {
+    errors::guard(py, "reseed", || {
+        let result = shared::reseed();
+        logging::flush(py);
+
+        result.map_err(pyo3::exceptions::PyRuntimeError::new_err)
+    })
+}
+
+#[pyfunction]
+#[pyo3(signature = (ulid_str, *, validate = true))]
+fn ulid_timestamp(ulid_str: &Bound<'_, PyAny>, validate: bool) -> PyResult<u64> {
+    MaybeUnchecked::new(ulid_str, validate).timestamp_ms()
+}
+
+/// The timestamp in seconds since the Unix epoch, comparable with `time.time()`
+#[pyfunction]
+fn ulid_timestamp_seconds(ulid: UlidArg) -> f64 {
+    ulid.0.timestamp_ms() as f64 / 1000.0
+}
+
+/// The timestamp as a timezone-aware UTC `datetime` with millisecond precision.
+///
+/// Raises OverflowError for timestamps past `datetime.max` (year 9999), which 48 bits
+/// can encode but `datetime` can't represent.
+#[pyfunction]
+fn ulid_datetime<'py>(py: Python<'py>, ulid_str: &str) -> PyResult<Bound<'py, PyAny>> {
+    timestamp_datetime(py, parse_ulid(ulid_str)?.timestamp_ms())
+}
+
+/// `timestamp_ms` as an aware UTC `datetime`, for `ulid_datetime` and `ulid_parse`
+fn timestamp_datetime(py: Python<'_>, timestamp_ms: u64) -> PyResult<Bound<'_, PyAny>> {
+    // Built through the datetime module so it also works under the limited API
+    let datetime = py.import(intern!(py, "datetime"))?;
+    let utc = datetime
+        .getattr(intern!(py, "timezone"))?
+        .getattr(intern!(py, "utc"))?;
+    let epoch = datetime
+        .getattr(intern!(py, "datetime"))?
+        .call((1970, 1, 1, 0, 0, 0, 0, utc), None)?;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item(intern!(py, "milliseconds"), timestamp_ms)?;
+    let delta = datetime
+        .getattr(intern!(py, "timedelta"))?
+        .call((), Some(&kwargs))?;
+    epoch.add(delta).map_err(|e| {
+        if e.is_instance_of::<pyo3::exceptions::PyOverflowError>(py) {
+            pyo3::exceptions::PyOverflowError::new_err(format!(
+                "ULID timestamp {} ms is past datetime.max",
+                timestamp_ms
+            ))
+        } else {
+            e
+        }
+    })
+}
+
+/// Every component of a ULID from a single validation and decode.
+///
+/// Returns `{"timestamp_ms": int, "datetime": datetime, "random": int, "bytes": bytes,
+/// "canonical": str}`, agreeing with `ulid_timestamp`, `ulid_datetime`, `ulid_random`,
+/// `ulid_to_bytes` and `ulid_from_str`. `datetime` is None for timestamps past
+/// `datetime.max`, where `ulid_datetime` would raise. Invalid input raises the same
+/// ValueError as the single-purpose functions.
+#[pyfunction]
+fn ulid_parse<'py>(py: Python<'py>, ulid_str: &str) -> PyResult<Bound<'py, PyDict>> {
+    let ulid = parse_ulid(ulid_str)?;
+    let datetime = match timestamp_datetime(py, ulid.timestamp_ms()) {
+        Ok(datetime) => Some(datetime),
+        Err(e) if e.is_instance_of::<pyo3::exceptions::PyOverflowError>(py) => None,
+        Err(e) => return Err(e),
+    };
+
+    let parts = PyDict::new(py);
+    parts.set_item(intern!(py, "timestamp_ms"), ulid.timestamp_ms())?;
+    parts.set_item(intern!(py, "datetime"), datetime)?;
+    parts.set_item(intern!(py, "random"), ulid.random())?;
+    parts.set_item(
+        intern!(py, "bytes"),
+        PyBytes::new(py, &ulid.0.to_be_bytes()),
+    )?;
+    parts.set_item(intern!(py, "canonical"), ulid.to_string())?;
+    Ok(parts)
+}
+
+#[pyfunction]
+#[pyo3(signature = (ulid_str, *, validate = true))]
+fn ulid_random(ulid_str: &Bound<'_, PyAny>, validate: bool) -> PyResult<u128> {
+    MaybeUnchecked::new(ulid_str, validate).random()
+}
+
+#[pyfunction]
+fn ulid_is_valid(ulid_str: UlidText) -> bool {
+    ulid_str.as_str().is_ok_and(|s| check_ulid(s).is_ok())
+}
+
+/// Why `ulid_str` is not a valid ULID, or None if it is.
+///
+/// Returns a dict with `reason` ("length", "character" or "overflow") and the same
+/// `message` the parsing functions raise, plus `length` for length errors,
+/// `character` and `index` for characters outside the alphabet, and `character` for a
+/// leading character above '7'.
+#[pyfunction]
+fn ulid_validate<'py>(py: Python<'py>, ulid_str: &str) -> PyResult<Option<Bound<'py, PyDict>>> {
+    let Err(invalid) = check_ulid(ulid_str) else {
+        return Ok(None);
+    };
+
+    let report = PyDict::new(py);
+    report.set_item("reason", invalid.reason())?;
+    report.set_item("message", invalid.message())?;
+    match invalid {
+        Invalid::Length(length) => report.set_item("length", length)?,
+        Invalid::Character(c, index) => {
+            report.set_item("character", c)?;
+            report.set_item("index", index)?;
+        }
+        Invalid::Overflow(c) => report.set_item("character", c)?,
+    }
+    Ok(Some(report))
+}
+
+#[pyfunction]
+#[pyo3(signature = (timestamp_ms, *, lowercase = false))]
+fn ulid_with_timestamp(
+    py: Python<'_>,
+    timestamp_ms: TimestampArg,
+    lowercase: bool,
+) -> PyResult<Bound<'_, PyString>> {
+    let timestamp_ms = timestamp_ms.0;
+    errors::guard(py, "ulid_with_timestamp", || {
+        if entropy::source_set() {
+            let ulid = Ulid::try_from_parts(timestamp_ms, entropy::draw()?)?;
+            let buffer = encode_ascii(ulid.timestamp_ms(), ulid.random(), alphabet(lowercase));
+            return Ok(PyString::new(py, buffer_as_str(&buffer)));
+        }
+
+        let mut rng = entropy::mode_rng(rand::rng());
+        let mut random: u128 = rng.random::<u128>() & Ulid::bitmask(80);
+        if let Some(words) = clean::active() {
+            let timestamp_str = encode_timestamp(timestamp_ms);
+            random = words.pick(&timestamp_str, random, |_, _| {
+                Some(rng.random::<u128>() & Ulid::bitmask(80))
+            });
+        }
+        let ulid = Ulid::try_from_parts(timestamp_ms, random)?;
+        let buffer = encode_ascii(ulid.timestamp_ms(), ulid.random(), alphabet(lowercase));
+        Ok(PyString::new(py, buffer_as_str(&buffer)))
+    })
+}
+
+/// `ulid_with_timestamp` for float seconds since the Unix epoch, e.g. `time.time()`.
+///
+/// Seconds are converted to milliseconds rounding half to even, so the result's
+/// `ulid_timestamp_seconds` is within half a millisecond of `seconds`.
+#[pyfunction]
+fn ulid_at(py: Python<'_>, seconds: f64) -> PyResult<Bound<'_, PyString>> {
+    ulid_with_timestamp(py, TimestampArg(seconds_to_ms(seconds)?), false)
+}
+
+/// Float seconds to milliseconds, rounding half to even, as `ulid_at` does
+fn seconds_to_ms(seconds: f64) -> PyResult<u64> {
+    let ms = (seconds * 1000.0).round_ties_even();
+    if !(0.0..=Ulid::bitmask(Ulid::TIME_BITS) as f64).contains(&ms) {
+        return Err(timestamp_range_error(format_args!("{} seconds", seconds)));
+    }
+    Ok(ms as u64)
+}
+
+/// A ULID with a fresh 80-bit random component, even within the same millisecond.
+///
+/// Unlike `ulid()`, IDs from one millisecond are unordered and don't reveal how many
+/// were generated. The shared monotonic state is neither read nor locked, so this
+/// never contends with other threads and never raises on overflow.
+#[pyfunction]
+fn ulid_non_monotonic(py: Python<'_>) -> PyResult<Bound<'_, PyString>> {
+    ulid_with_timestamp(py, TimestampArg(current_timestamp_ms()), false)
+}
+
+/// `ulid_with_timestamp` for a timezone-aware `datetime`.
+///
+/// The timestamp is the millisecond containing `dt` (sub-millisecond digits are
+/// floored, never rounded up into the next millisecond). Naive datetimes are rejected
+/// rather than guessed as UTC or local time. Dates before 1970 raise ValueError; every
+/// later `datetime` (up to year 9999) fits in the 48-bit timestamp.
+#[pyfunction]
+fn ulid_from_datetime<'py>(
+    py: Python<'py>,
+    dt: &Bound<'_, PyAny>,
+) -> PyResult<Bound<'py, PyString>> {
+    if !dt.hasattr(intern!(py, "utcoffset"))? {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "dt must be a datetime, got {}",
+            dt.get_type().name()?
+        )));
+    }
+    ulid_with_timestamp(py, dt.extract::<TimestampArg>()?, false)
+}
+
+#[derive(IntoPyObject)]
+enum Siblings {
+    One(String),
+    Many(Vec<String>),
+}
+
+/// Fresh ULIDs sharing the timestamp of `ulid_str`, never equal to it.
+///
+/// Returns one ULID, or with `count=n` a sorted list of `n` distinct ones. The random
+/// components come from the thread RNG, so the global generator state is untouched.
+#[pyfunction]
+#[pyo3(signature = (ulid_str, count = None))]
+fn ulid_sibling(py: Python<'_>, ulid_str: UlidArg, count: Option<usize>) -> PyResult<Siblings> {
+    errors::guard(py, "ulid_sibling", || {
+        let timestamp = ulid_str.0.timestamp_ms();
+        let original_random = ulid_str.0.random();
+        let mut rng = entropy::mode_rng(rand::rng());
+
+        let n = count.unwrap_or(1);
+        let mut randoms: Vec<u128> = Vec::with_capacity(n);
+        while randoms.len() < n {
+            randoms.extend(
+                (randoms.len()..n)
+                    .map(|_| rng.random::<u128>() & Ulid::bitmask(80))
+                    .filter(|&r| r != original_random),
+            );
+            // Collisions are astronomically unlikely; top up if any were dropped
+            randoms.sort_unstable();
+            randoms.dedup();
+        }
+
+        let mut siblings = randoms
+            .into_iter()
+            .map(|r| Ulid::from_parts(timestamp, r).to_string());
+        Ok(match count {
+            None => Siblings::One(siblings.next().unwrap()),
+            Some(_) => Siblings::Many(siblings.collect()),
+        })
+    })
+}
+
+/// Hyphenated lowercase UUID form of a 128-bit value
+fn format_uuid(value: u128) -> String {
+    let hex = format!("{:032x}", value);
+    format!(
+        "{}-{}-{}-{}-{}",
+        &hex[0..8],
+        &hex[8..12],
+        &hex[12..16],
+        &hex[16..20],
+        &hex[20..32]
+    )
+}
+
+#[pyfunction]
+#[pyo3(signature = (ulid_str, *, validate = true))]
+fn ulid_to_uuid(ulid_str: &Bound<'_, PyAny>, validate: bool) -> PyResult<String> {
+    let decoded = MaybeUnchecked::new(ulid_str, validate).text()?.0;
+    Ok(format_uuid(decoded))
+}
+
+/// `ulid_to_uuid` as a `uuid.UUID` object, built from its 128-bit int
+#[pyfunction]
+#[pyo3(signature = (ulid_str, *, validate = true))]
+fn ulid_to_uuid_obj<'py>(
+    py: Python<'py>,
+    ulid_str: &Bound<'py, PyAny>,
+    validate: bool,
+) -> PyResult<Bound<'py, PyAny>> {
+    let decoded = MaybeUnchecked::new(ulid_str, validate).str()?.0;
+    let kwargs = PyDict::new(py);
+    kwargs.set_item(intern!(py, "int"), decoded)?;
+    py.import(intern!(py, "uuid"))?
+        .getattr(intern!(py, "UUID"))?
+        .call((), Some(&kwargs))
+}
+
+/// A UUID argument: hex text (dashes optional, any case) or a `uuid.UUID`
+struct UuidArg(u128);
+
+impl<'py> FromPyObject<'py> for UuidArg {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let py = obj.py();
+        if let Ok(s) = obj.downcast::<PyString>() {
+            return parse_uuid_str(&s.to_cow()?).map(UuidArg);
+        }
+        let uuid_type = py
+            .import(intern!(py, "uuid"))?
+            .getattr(intern!(py, "UUID"))?;
+        if obj.is_instance(&uuid_type)? {
+            return Ok(UuidArg(obj.getattr(intern!(py, "int"))?.extract()?));
+        }
+        Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "expected a UUID string or uuid.UUID, got {}",
+            obj.get_type().name()?
+        )))
+    }
+}
+
+fn parse_uuid_str(uuid_str: &str) -> PyResult<u128> {
+    let hex_only: String = uuid_str.chars().filter(|c| *c != '-').collect();
+
+    if hex_only.len() != 32 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "UUID must be 32 hex characters (with or without dashes)",
+        ));
+    }
+
+    u128::from_str_radix(&hex_only, 16)
+        .map_err(|_| pyo3::exceptions::PyValueError::new_err("Invalid hex characters in UUID"))
+}
+
+#[pyfunction]
+fn uuid_to_ulid(uuid_str: UuidArg) -> String {
+    encode_base32_internal(uuid_str.0)
+}
+
+// UUIDv7 (RFC 9562): 48-bit unix_ts_ms, 4-bit version, 12-bit rand_a, 2-bit variant,
+// 62-bit rand_b. That leaves 74 random bits for the ULID's 80.
+const UUID7_VERSION: u128 = 0x7 << 76;
+const UUID7_VARIANT: u128 = 0b10 << 62;
+const UUID7_DROPPED_BITS: u8 = Ulid::RAND_BITS - 74;
+
+/// UUIDv7 string with the ULID's timestamp and the top 74 bits of its random part.
+///
+/// The lowest 6 random bits don't fit and are dropped, so `uuid7_to_ulid` gives back
+/// the ULID with those bits zeroed. Ordering is preserved, apart from ULIDs that
+/// differ only in the dropped bits.
+#[pyfunction]
+#[pyo3(signature = (ulid_str, *, validate = true))]
+fn ulid_to_uuid7(ulid_str: &Bound<'_, PyAny>, validate: bool) -> PyResult<String> {
+    let ulid = MaybeUnchecked::new(ulid_str, validate).str()?;
+    let random = ulid.random() >> UUID7_DROPPED_BITS;
+    let rand_a = random >> 62;
+    let rand_b = random & Ulid::bitmask(62);
+    Ok(format_uuid(
+        ((ulid.timestamp_ms() as u128) << 80)
+            | UUID7_VERSION
+            | (rand_a << 64)
+            | UUID7_VARIANT
+            | rand_b,
+    ))
+}
+
+fn check_uuid7(value: u128) -> PyResult<()> {
+    let version = (value >> 76) & 0xF;
+    if version != 7 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "expected a version 7 UUID, got version {}",
+            version
+        )));
+    }
+    Ok(())
+}
+
+/// The ULID for a UUIDv7, with its timestamp; the inverse of `ulid_to_uuid7`
+#[pyfunction]
+fn uuid7_to_ulid(uuid_str: UuidArg) -> PyResult<String> {
+    let value = uuid_str.0;
+    check_uuid7(value)?;
+    let rand_a = (value >> 64) & Ulid::bitmask(12);
+    let rand_b = value & Ulid::bitmask(62);
+    let random = ((rand_a << 62) | rand_b) << UUID7_DROPPED_BITS;
+    Ok(encode_base32_internal(
+        Ulid::from_parts((value >> 80) as u64, random).0,
+    ))
+}
+
+/// The leading 48 bits of a UUID as a millisecond timestamp, without converting it.
+///
+/// Right for both `ulid_to_uuid` output and real UUIDv7s. Other UUIDs (such as v4,
+/// whose leading bits are random) give a meaningless number; `require_v7=True` raises
+/// ValueError for anything but version 7.
+#[pyfunction]
+#[pyo3(signature = (uuid_str, *, require_v7 = false))]
+fn uuid_timestamp(uuid_str: UuidArg, require_v7: bool) -> PyResult<u64> {
+    if require_v7 {
+        check_uuid7(uuid_str.0)?;
+    }
+    Ok(Ulid(uuid_str.0).timestamp_ms())
+}
+
+/// Validate a ULID and return its canonical uppercase form, or lowercase with
+/// `lowercase=True`; either case is accepted as input.
+///
+/// With `strict=False`, surrounding ASCII whitespace and any hyphens or underscores
+/// (visual group separators) are dropped first, and error positions refer to what is
+/// left.
+#[pyfunction]
+#[pyo3(signature = (ulid_str, *, lowercase = false, strict = true))]
+fn ulid_from_str(ulid_str: UlidText, lowercase: bool, strict: bool) -> PyResult<String> {
+    let ulid_str = if strict {
+        Cow::Borrowed(ulid_str.as_str()?)
+    } else {
+        strip_separators(ulid_str.as_str()?)
+    };
+    let ulid_str = ulid_str.as_ref();
+    if ulid_str.len() != 26 {
+        return Err(check_ulid(ulid_str).unwrap_err().into());
+    }
+
+    // A cached string already decoded cleanly, so it needs no character scan
+    if cache::lookup(ulid_str.as_bytes()).is_none() {
+        check_ulid(ulid_str)?;
+        if cache::enabled() {
+            if let Ok(value) = decode_base32_internal(ulid_str) {
+                cache::store(ulid_str.as_bytes(), value);
+            }
+        }
+    }
+
+    // Return normalized version; every character is in the alphabet, so a plain case
+    // mapping gives the same string as re-encoding with the chosen table
+    if lowercase {
+        Ok(ulid_str.to_ascii_lowercase())
+    } else {
+        Ok(ulid_str.to_ascii_uppercase())
+    }
+}
+
+/// `text` without surrounding ASCII whitespace, hyphens or underscores; borrowed when
+/// there is no separator to drop
+fn strip_separators(text: &str) -> Cow<'_, str> {
+    let trimmed = text.trim_matches(|c: char| c.is_ascii_whitespace());
+    if trimmed.contains(['-', '_']) {
+        Cow::Owned(trimmed.chars().filter(|&c| c != '-' && c != '_').collect())
+    } else {
+        Cow::Borrowed(trimmed)
+    }
+}
+
+/// `ulid_from_str` for hand-typed input, with Crockford's decoding aliases.
+///
+/// Hyphens are dropped, `I`/`L` read as `1` and `O` as `0` (either case), and the result
+/// must then be a valid ULID; error positions refer to that cleaned-up string. `U` has
+/// no alias and stays invalid. Returns the canonical uppercase form.
+#[pyfunction]
+fn ulid_from_str_lenient(ulid_str: UlidText) -> PyResult<String> {
+    let normalized: String = ulid_str
+        .as_str()?
+        .chars()
+        .filter(|&c| c != '-')
+        .map(|c| match c.to_ascii_uppercase() {
+            'I' | 'L' => '1',
+            'O' => '0',
+            c => c,
+        })
+        .collect();
+    check_ulid(&normalized)?;
+    Ok(normalized)
+}
+
+/// The full 128-bit value of a ULID as a Python int
+#[pyfunction]
+#[pyo3(signature = (ulid_str, *, validate = true))]
+fn ulid_to_int(ulid_str: &Bound<'_, PyAny>, validate: bool) -> PyResult<u128> {
+    Ok(MaybeUnchecked::new(ulid_str, validate).str()?.0)
+}
+
+/// Canonical ULID string for a 128-bit integer
+#[pyfunction]
+fn ulid_from_int(value: &Bound<'_, PyAny>) -> PyResult<String> {
+    let number: u128 = value.extract().map_err(|e| {
+        if e.is_instance_of::<pyo3::exceptions::PyOverflowError>(value.py()) {
+            pyo3::exceptions::PyOverflowError::new_err(
+                "ULID integer must be in range 0 <= value < 2**128",
+            )
+        } else {
+            e
+        }
+    })?;
+
+    Ok(encode_base32_internal(number))
+}
+
+/// The 128-bit value as 16 big-endian bytes, which sort in the same order as the strings
+#[pyfunction]
+#[pyo3(signature = (ulid, *, validate = true))]
+fn ulid_to_bytes<'py>(
+    py: Python<'py>,
+    ulid: &Bound<'py, PyAny>,
+    validate: bool,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let ulid = MaybeUnchecked {
+        arg: ulid,
+        validate,
+        name: "ulid",
+    }
+    .ulid_arg()?;
+    Ok(PyBytes::new(py, &ulid.0.to_be_bytes()))
+}
+
+/// Canonical ULID string for 16 big-endian bytes (the `ulid_to_bytes` format)
+#[pyfunction]
+fn ulid_from_bytes(data: &[u8]) -> PyResult<String> {
+    Ok(Ulid::from_bytes(data)?.to_string())
+}
+
+/// The 48-bit timestamp as 6 big-endian bytes, the first 6 of `ulid_to_bytes`
+#[pyfunction]
+fn ulid_timestamp_bytes(py: Python<'_>, ulid: UlidArg) -> Bound<'_, PyBytes> {
+    PyBytes::new(py, &ulid.0 .0.to_be_bytes()[..6])
+}
+
+/// The 80-bit random component as 10 big-endian bytes, the last 10 of `ulid_to_bytes`
+#[pyfunction]
+fn ulid_random_bytes(py: Python<'_>, ulid: UlidArg) -> Bound<'_, PyBytes> {
+    PyBytes::new(py, &ulid.0 .0.to_be_bytes()[6..])
+}
+
+/// The 128-bit value as 32 lowercase hex digits, zero-padded
+#[pyfunction]
+fn ulid_to_hex(ulid: UlidArg) -> String {
+    format!("{:032x}", ulid.0 .0)
+}
+
+/// Canonical ULID string for 32 hex digits in either case (the `ulid_to_hex` format)
+#[pyfunction]
+fn ulid_from_hex(hex_str: &str) -> PyResult<String> {
+    // Characters first, so a non-ASCII one is named rather than miscounted as bytes;
+    // from_str_radix alone would also take a leading '+'
+    if let Some((index, c)) = hex_str
+        .chars()
+        .enumerate()
+        .find(|(_, c)| !c.is_ascii_hexdigit())
+    {
+        return Err(errors::decode_error(format!(
+            "Invalid hex character '{}' at position {}",
+            c, index
+        )));
+    }
+    if hex_str.len() != 32 {
+        return Err(errors::decode_error(format!(
+            "ULID hex must be exactly 32 characters, got {}",
+            hex_str.len()
+        )));
+    }
+    let value = u128::from_str_radix(hex_str, 16).expect("checked above");
+    Ok(encode_base32_internal(value))
+}
+
+/// The lexicographically next ULID (value + 1), carrying from the random part into the timestamp
+#[pyfunction]
+fn ulid_next(ulid_str: &str) -> PyResult<String> {
+    let value = parse_ulid(ulid_str)?.0;
+    let next = value.checked_add(1).ok_or_else(|| {
+        pyo3::exceptions::PyOverflowError::new_err("ULID is already the maximum value")
+    })?;
+
+    Ok(encode_base32_internal(next))
+}
+
+/// The lexicographically previous ULID (value - 1), borrowing from the timestamp when needed
+#[pyfunction]
+fn ulid_prev(ulid_str: &str) -> PyResult<String> {
+    let value = parse_ulid(ulid_str)?.0;
+    let prev = value.checked_sub(1).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err("ULID is already the minimum value")
+    })?;
+
+    Ok(encode_base32_internal(prev))
+}
+
+/// Smallest ULID in the same millisecond (random component all zeros)
+#[pyfunction]
+fn ulid_floor(ulid: UlidArg) -> String {
+    Ulid::from_parts(ulid.0.timestamp_ms(), 0).to_string()
+}
+
+/// Largest ULID in the same millisecond (random component all ones)
+#[pyfunction]
+fn ulid_ceil(ulid: UlidArg) -> String {
+    Ulid::from_parts(ulid.0.timestamp_ms(), Ulid::bitmask(Ulid::RAND_BITS)).to_string()
+}
+
+/// Smallest possible ULID for a millisecond (int ms or aware datetime), for range queries
+#[pyfunction]
+fn ulid_min_for_timestamp(timestamp: TimestampArg) -> String {
+    Ulid::from_parts(timestamp.0, 0).to_string()
+}
+
+/// Largest possible ULID for a millisecond (int ms or aware datetime), for range queries
+#[pyfunction]
+fn ulid_max_for_timestamp(timestamp: TimestampArg) -> String {
+    Ulid::from_parts(timestamp.0, Ulid::bitmask(Ulid::RAND_BITS)).to_string()
+}
+
+/// The nil ULID, all 128 bits zero: `MIN_ULID`, which sorts before every other ULID
+#[pyfunction]
+fn ulid_nil() -> String {
+    Ulid(0).to_string()
+}
+
+/// The largest valid ULID, all 128 bits set: `MAX_ULID`, which sorts after every other
+#[pyfunction]
+fn ulid_max() -> String {
+    Ulid(u128::MAX).to_string()
+}
+
+/// A `ulid_range` bound: float seconds (as for `ulid_at`), or anything `TimestampArg` takes
+struct WindowBound(u64);
+
+impl<'py> FromPyObject<'py> for WindowBound {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        if let Ok(seconds) = obj.downcast::<PyFloat>() {
+            // Floored like the clock: a ULID from the millisecond holding the instant
+            // belongs to the window, and one from the next millisecond never does
+            let seconds = seconds.value();
+            let ms = (seconds * 1000.0).floor();
+            if !(0.0..=Ulid::bitmask(Ulid::TIME_BITS) as f64).contains(&ms) {
+                return Err(timestamp_range_error(format_args!("{} seconds", seconds)));
+            }
+            return Ok(WindowBound(ms as u64));
+        }
+        Ok(WindowBound(obj.extract::<TimestampArg>()?.0))
+    }
+}
+
+/// `(lowest ULID of start, highest ULID of end)`, for `BETWEEN` queries on a time window.
+///
+/// Both bounds are inclusive: every ULID whose timestamp lies in `[start, end]` sorts
+/// within the pair, and no other does. Bounds are int milliseconds, float seconds
+/// (floored to the millisecond holding the instant, as the clock and datetimes are)
+/// or aware datetimes; None leaves that side open, giving `MIN_ULID` or `MAX_ULID`.
+#[pyfunction]
+fn ulid_range(start: Option<WindowBound>, end: Option<WindowBound>) -> PyResult<(String, String)> {
+    if let (Some(start), Some(end)) = (&start, &end) {
+        if end.0 < start.0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "end must not be before start, got start={} ms, end={} ms",
+                start.0, end.0
+            )));
+        }
+    }
+
+    let low = start.map_or(Ulid(0), |start| Ulid::from_parts(start.0, 0));
+    let high = end.map_or(Ulid(u128::MAX), |end| {
+        Ulid::from_parts(end.0, Ulid::bitmask(Ulid::RAND_BITS))
+    });
+    Ok((low.to_string(), high.to_string()))
+}
+
+/// `parts + 1` boundary ULIDs splitting the window `[start, end]` into contiguous ranges.
+///
+/// The first boundary is the floor of `start`, the last the ceiling of `end`, and the
+/// interior ones sit on evenly spaced timestamps with a zero random component. Worker `i`
+/// scans `b[i] <= key < b[i + 1]`; the last worker also includes `b[parts]`.
+#[pyfunction]
+fn ulid_scan_partitions(
+    start: TimestampArg,
+    end: TimestampArg,
+    parts: i64,
+) -> PyResult<Vec<String>> {
+    if parts < 1 {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "parts must be >= 1, got {}",
+            parts
+        )));
+    }
+    let (start, end) = (start.0, end.0);
+    if start > end {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "start must not be after end",
+        ));
+    }
+
+    let span = (end - start) as u128;
+    let parts = parts as u128;
+    let mut boundaries: Vec<String> = (0..parts)
+        .map(|i| Ulid::from_parts(start + (span * i / parts) as u64, 0).to_string())
+        .collect();
+    boundaries.push(Ulid::from_parts(end, Ulid::bitmask(Ulid::RAND_BITS)).to_string());
+    Ok(boundaries)
+}
+
+/// Encode a millisecond timestamp as the 10-character Crockford prefix of a ULID
+#[pyfunction]
+fn encode_timestamp_base32(timestamp_ms: TimestampArg) -> String {
+    encode_timestamp(timestamp_ms.0)
+        .iter()
+        .map(|&b| b as char)
+        .collect()
+}
+
+/// Parse a 10-character Crockford timestamp prefix back to milliseconds
+#[pyfunction]
+fn decode_timestamp_base32(encoded: &str) -> PyResult<u64> {
+    let length = encoded.chars().count();
+    if length != 10 {
+        return Err(errors::decode_error(format!(
+            "Timestamp must be exactly 10 characters, got {}",
+            length
+        )));
+    }
+
+    let value = decode_base32_internal(encoded)?;
+    if value > Ulid::bitmask(Ulid::TIME_BITS) {
+        return Err(errors::decode_error(
+            "Timestamp exceeds 48 bits (first character must be 0-7)",
+        ));
+    }
+    Ok(value as u64)
+}
+
+/// Widest suffix supported by `ulid_with_suffix` (32**12 == 2**60)
+const MAX_SUFFIX_WIDTH: usize = 12;
+
+fn check_suffix_width(width: usize) -> PyResult<()> {
+    if width == 0 || width > MAX_SUFFIX_WIDTH {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "width must be in range 1..={}, got {}",
+            MAX_SUFFIX_WIDTH, width
+        )));
+    }
+    Ok(())
+}
+
+/// Composite key: the ULID followed by `n` as `width` Crockford characters.
+///
+/// Keys of the same width sort by ULID first and suffix value second, and use only the
+/// ULID alphabet, so they stay URL-safe and case-insensitive.
+#[pyfunction]
+#[pyo3(signature = (ulid_str, n, width = 2))]
+fn ulid_with_suffix(ulid_str: UlidArg, n: i128, width: usize) -> PyResult<String> {
+    check_suffix_width(width)?;
+    let limit = 1i128 << (5 * width);
+    if !(0..limit).contains(&n) {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "suffix must be in range 0 <= n < 32**{} ({}), got {}",
+            width, limit, n
+        )));
+    }
+
+    let mut key = ulid_str.0.to_string();
+    key.extend(
+        (0..width)
+            .rev()
+            .map(|i| ALPHABET[((n >> (5 * i)) & 0x1F) as usize] as char),
+    );
+    Ok(key)
+}
+
+/// Split a `ulid_with_suffix` key back into the canonical ULID and the suffix value
+#[pyfunction]
+#[pyo3(signature = (key, width = 2))]
+fn ulid_parse_suffix(key: &str, width: usize) -> PyResult<(String, u64)> {
+    check_suffix_width(width)?;
+    let length = key.chars().count();
+    if length != 26 + width {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "key must be exactly {} characters (26 + width {}), got {}",
+            26 + width,
+            width,
+            length
+        )));
+    }
+    // Split by characters, so a non-ASCII character is reported by the part it is in
+    let split = key.char_indices().nth(26).map_or(key.len(), |(i, _)| i);
+    let (ulid_part, suffix) = key.split_at(split);
+
+    let ulid = parse_ulid(ulid_part)?;
+    let n = decode_base32_internal(suffix)?;
+    Ok((ulid.to_string(), n as u64))
+}
+
+/// Regex character class body for the given alphabet bytes, collapsing runs into ranges
+fn regex_class(chars: &[u8], case_insensitive: bool) -> String {
+    let mut class = String::new();
+    let mut push_ranges = |bytes: &[u8]| {
+        let mut i = 0;
+        while i < bytes.len() {
+            let start = i;
+            while i + 1 < bytes.len() && bytes[i + 1] == bytes[i] + 1 {
+                i += 1;
+            }
+            match i - start {
+                0 => class.push(bytes[start] as char),
+                1 => {
+                    class.push(bytes[start] as char);
+                    class.push(bytes[i] as char);
+                }
+                _ => {
+                    class.push(bytes[start] as char);
+                    class.push('-');
+                    class.push(bytes[i] as char);
+                }
+            }
+            i += 1;
+        }
+    };
+
+    push_ranges(chars);
+    if case_insensitive {
+        let lower: Vec<u8> = chars
+            .iter()
+            .filter(|b| b.is_ascii_uppercase())
+            .map(|b| b.to_ascii_lowercase())
+            .collect();
+        push_ranges(&lower);
+    }
+    class
+}
+
+/// Regex matching a ULID, generated from `ALPHABET` so it cannot drift from the decoder.
+///
+/// The first character is limited to 0-7 because 26 symbols carry 130 bits for a 128-bit value.
+/// Only character classes are used, so the pattern is valid in both Python and ECMA 262
+/// (JSON Schema) dialects.
+#[pyfunction]
+#[pyo3(signature = (anchored = true, case_insensitive = true))]
+fn ulid_pattern(anchored: bool, case_insensitive: bool) -> String {
+    let lead = regex_class(&ALPHABET[..8], case_insensitive);
+    let rest = regex_class(ALPHABET, case_insensitive);
+    let body = format!("[{}][{}]{{25}}", lead, rest);
+
+    if anchored {
+        format!("^{}$", body)
+    } else {
+        body
+    }
+}
+
+/// Replace the timestamp component, keeping the random component intact
+#[pyfunction]
+fn ulid_set_timestamp(ulid: UlidArg, timestamp: TimestampArg) -> String {
+    Ulid::from_parts(timestamp.0, ulid.0.random()).to_string()
+}
+
+/// Replace the random component, keeping the timestamp component intact
+#[pyfunction]
+fn ulid_set_random(ulid: UlidArg, random: RandomArg) -> String {
+    Ulid::from_parts(ulid.0.timestamp_ms(), random.0).to_string()
+}
+
+/// Canonical ULID from explicit components: `timestamp_ms` (int milliseconds or an
+/// aware datetime) and the 80-bit `random` (int or 10 big-endian bytes). Out-of-range
+/// values raise instead of being masked.
+#[pyfunction]
+fn ulid_from_parts(timestamp_ms: TimestampArg, random: RandomArg) -> String {
+    Ulid::from_parts(timestamp_ms.0, random.0).to_string()
+}
+
+/// Render the 16 ULID bytes in RFC 4648 Base32 (A-Z, 2-7).
+///
+/// Unlike the native Crockford form this encoding does NOT sort chronologically.
+#[pyfunction]
+#[pyo3(signature = (ulid, padding = true))]
+fn ulid_to_b32std(ulid: UlidArg, padding: bool) -> String {
+    // 128 bits → 26 symbols; the last symbol holds 3 data bits and 2 zero pad bits
+    let bits = ulid.0 .0;
+    let mut out = String::with_capacity(32);
+    for i in 0..26i32 {
+        let shift = 128 - 5 * (i + 1);
+        let symbol = if shift >= 0 {
+            (bits >> shift) & 0x1F
+        } else {
+            (bits << -shift) & 0x1F
+        };
+        out.push(RFC4648_ALPHABET[symbol as usize] as char);
+    }
+
+    if padding {
+        out.push_str("======");
+    }
+    out
+}
+
+/// Parse an RFC 4648 Base32 rendering (padded or not, any case) back to a ULID string
+#[pyfunction]
+fn ulid_from_b32std(encoded: &str) -> PyResult<String> {
+    let trimmed = encoded.trim_end_matches('=');
+    let pad_len = encoded.len() - trimmed.len();
+    if trimmed.len() != 26 || (pad_len != 0 && pad_len != 6) {
+        return Err(errors::decode_error(
+            "RFC 4648 Base32 ULID must be 26 characters plus optional 6 '=' padding",
+        ));
+    }
+
+    let mut bits: u128 = 0;
+    for (i, c) in trimmed.chars().enumerate() {
+        // Compare whole chars: casting to u8 would fold non-ASCII input onto the alphabet
+        let upper = c.to_ascii_uppercase();
+        let value = RFC4648_ALPHABET
+            .iter()
+            .position(|&a| a as char == upper)
+            .ok_or_else(|| {
+                errors::decode_error(format!(
+                    "Invalid character '{}' in RFC 4648 Base32 string",
+                    c
+                ))
+            })? as u128;
+
+        if i < 25 {
+            bits = (bits << 5) | value;
+        } else {
+            // The last symbol carries 3 data bits and 2 pad bits that must be zero
+            if value & 0b11 != 0 {
+                return Err(errors::decode_error(
+                    "Non-zero trailing bits in RFC 4648 Base32 string",
+                ));
+            }
+            bits = (bits << 3) | (value >> 2);
+        }
+    }
+
+    Ok(encode_base32_internal(bits))
+}
+
+/// Render the 16 ULID bytes as unpadded base64url (RFC 4648 section 5), 22 characters.
+///
+/// Like `ulid_to_b32std`, this encoding does NOT sort chronologically.
+#[pyfunction]
+fn ulid_to_base64(ulid: UlidArg) -> String {
+    // 128 bits → 22 symbols; the last symbol holds 2 data bits and 4 zero pad bits
+    let bits = ulid.0 .0;
+    (0..22i32)
+        .map(|i| {
+            let shift = 128 - 6 * (i + 1);
+            let symbol = if shift >= 0 {
+                (bits >> shift) & 0x3F
+            } else {
+                (bits << -shift) & 0x3F
+            };
+            BASE64URL_ALPHABET[symbol as usize] as char
+        })
+        .collect()
+}
+
+/// Parse base64url of the 16 ULID bytes (padded or not) back to a ULID string
+#[pyfunction]
+fn ulid_from_base64(encoded: &str) -> PyResult<String> {
+    let trimmed = encoded.trim_end_matches('=');
+    let pad_len = encoded.len() - trimmed.len();
+    if trimmed.len() != 22 || (pad_len != 0 && pad_len != 2) {
+        return Err(errors::decode_error(
+            "base64url ULID must be 22 characters plus optional '==' padding",
+        ));
+    }
+
+    let mut bits: u128 = 0;
+    for (i, c) in trimmed.chars().enumerate() {
+        let value = BASE64URL_ALPHABET
+            .iter()
+            .position(|&a| a as char == c)
+            .ok_or_else(|| {
+                errors::decode_error(format!("Invalid character '{}' in base64url string", c))
+            })? as u128;
+
+        if i < 21 {
+            bits = (bits << 6) | value;
+        } else {
+            // The last symbol carries 2 data bits and 4 pad bits that must be zero
+            if value & 0b1111 != 0 {
+                return Err(errors::decode_error(
+                    "Non-zero trailing bits in base64url string",
+                ));
+            }
+            bits = (bits << 2) | (value >> 4);
+        }
+    }
+
+    Ok(encode_base32_internal(bits))
+}
+
+/// Crockford check symbol of a ULID: its 128-bit value mod 37
+fn check_symbol(ulid: Ulid) -> char {
+    CHECK_SYMBOLS[(ulid.0 % 37) as usize] as char
+}
+
+/// The ULID of a 27-character checked ULID, raising if the check symbol doesn't match
+fn strip_check(checked: &str) -> PyResult<Ulid> {
+    let length = checked.chars().count();
+    let Some((split, symbol)) = checked.char_indices().last().filter(|_| length == 27) else {
+        return Err(errors::decode_error(format!(
+            "checked ULID must be exactly 27 characters, got {}",
+            length
+        )));
+    };
+
+    let ulid = parse_ulid(&checked[..split])?;
+    let upper = symbol.to_ascii_uppercase();
+    if !upper.is_ascii() || !CHECK_SYMBOLS.contains(&(upper as u8)) {
+        return Err(errors::decode_error(format!(
+            "Invalid check symbol '{}' in checked ULID",
+            symbol.escape_debug()
+        )));
+    }
+
+    let expected = check_symbol(ulid);
+    if upper != expected {
+        return Err(errors::decode_error(format!(
+            "Check symbol mismatch: expected '{}', got '{}'",
+            expected, symbol
+        )));
+    }
+    Ok(ulid)
+}
+
+/// The ULID with its Crockford check symbol appended, 27 characters.
+///
+/// The symbol is the 128-bit value mod 37, written with the Base32 alphabet plus
+/// `*~$=U`, so a mistyped character is caught before the ID is looked up.
+#[pyfunction]
+fn ulid_with_check(ulid: UlidArg) -> String {
+    let mut checked = ulid.0.to_string();
+    checked.push(check_symbol(ulid.0));
+    checked
+}
+
+/// Whether `checked` is a ULID followed by its correct check symbol (either case)
+#[pyfunction]
+fn ulid_verify_check(checked: &str) -> bool {
+    strip_check(checked).is_ok()
+}
+
+/// The plain 26-character ULID of a checked ULID, after verifying the check symbol
+#[pyfunction]
+fn ulid_strip_check(checked: &str) -> PyResult<String> {
+    Ok(strip_check(checked)?.to_string())
+}
+
+/// Field names of the JSON form, in tuple order. This layout is stable.
+const JSON_FIELDS: [(&str, u32); 3] = [("ts", 48), ("rhi", 40), ("rlo", 40)];
+
+/// Split a ULID into `{"ts", "rhi", "rlo"}`: the 48-bit timestamp and the high and
+/// low 40 bits of the random part. Every field stays below 2**53, so it survives
+/// a round trip through JavaScript numbers.
+#[pyfunction]
+fn ulid_to_json<'py>(py: Python<'py>, ulid: UlidArg) -> PyResult<Bound<'py, PyDict>> {
+    let random = ulid.0.random();
+    let dict = PyDict::new(py);
+    dict.set_item(intern!(py, "ts"), ulid.0.timestamp_ms())?;
+    dict.set_item(intern!(py, "rhi"), (random >> 40) as u64)?;
+    dict.set_item(intern!(py, "rlo"), (random & 0xFF_FFFF_FFFF) as u64)?;
+    Ok(dict)
+}
+
+/// Rebuild a ULID from the `ulid_to_json` dict or a `(ts, rhi, rlo)` tuple
+#[pyfunction]
+fn ulid_from_json(obj: &Bound<'_, PyAny>) -> PyResult<String> {
+    let items: Vec<Bound<'_, PyAny>> = if let Ok(dict) = obj.downcast::<PyDict>() {
+        JSON_FIELDS
+            .iter()
+            .map(|(name, _)| {
+                dict.get_item(name)?.ok_or_else(|| {
+                    errors::decode_error(format!("ULID JSON object is missing field '{}'", name))
+                })
+            })
+            .collect::<PyResult<_>>()?
+    } else if let Ok(tuple) = obj.downcast::<PyTuple>() {
+        if tuple.len() != 3 {
+            return Err(errors::decode_error(format!(
+                "ULID JSON tuple must have 3 items (ts, rhi, rlo), got {}",
+                tuple.len()
+            )));
+        }
+        tuple.iter().collect()
+    } else {
+        return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+            "expected a dict or (ts, rhi, rlo) tuple, got {}",
+            obj.get_type().name()?
+        )));
+    };
+
+    let mut fields = [0u64; 3];
+    for (slot, (item, (name, bits))) in fields.iter_mut().zip(items.iter().zip(JSON_FIELDS)) {
+        if !item.is_instance_of::<PyInt>() {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "ULID JSON field '{}' must be an int",
+                name
+            )));
+        }
+        *slot = item
+            .extract::<u64>()
+            .ok()
+            .filter(|&value| value >> bits == 0)
+            .ok_or_else(|| {
+                errors::decode_error(format!(
+                    "ULID JSON field '{}' must be in range 0 <= value < 2**{}",
+                    name, bits
+                ))
+            })?;
+    }
+
+    let [ts, rhi, rlo] = fields;
+    Ok(Ulid::from_parts(ts, ((rhi as u128) << 40) | rlo as u128).to_string())
+}
+
+/// The 128 ULID bits as an RFC 5952 canonical IPv6 address.
+///
+/// Groups are lowercase hex without leading zeros and the longest run of two or more
+/// zero groups (the first one on a tie) is compressed to `::`. IPv4-mapped values are
+/// still printed as hex groups, never in dotted form.
+#[pyfunction]
+fn ulid_to_ipv6(ulid: UlidArg) -> String {
+    let groups = Ipv6Addr::from(ulid.0 .0).segments();
+
+    // Longest run of zero groups as (start, len)
+    let (mut best, mut run) = ((0, 0), (0, 0));
+    for (i, &group) in groups.iter().enumerate() {
+        if group == 0 {
+            run = if run.1 == 0 {
+                (i, 1)
+            } else {
+                (run.0, run.1 + 1)
+            };
+            if run.1 > best.1 {
+                best = run;
+            }
+        } else {
+            run = (0, 0);
+        }
+    }
+
+    let hex = |range: &[u16]| {
+        range
+            .iter()
+            .map(|g| format!("{:x}", g))
+            .collect::<Vec<_>>()
+            .join(":")
+    };
+    if best.1 < 2 {
+        return hex(&groups);
+    }
+    format!(
+        "{}::{}",
+        hex(&groups[..best.0]),
+        hex(&groups[best.0 + best.1..])
+    )
+}
+
+/// Parse any textual IPv6 address (including `::` compression) to a ULID.
+///
+/// Dotted IPv4 suffixes such as `::ffff:1.2.3.4` are rejected: the same bits can be
+/// written with or without them, so only the hex-group form is accepted.
+#[pyfunction]
+fn ulid_from_ipv6(address: &str) -> PyResult<String> {
+    if address.contains('.') {
+        return Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "IPv4-embedded notation is not accepted, write '{}' as hex groups",
+            address
+        )));
+    }
+
+    let parsed: Ipv6Addr = address.parse().map_err(|_| {
+        pyo3::exceptions::PyValueError::new_err(format!("Invalid IPv6 address '{}'", address))
+    })?;
+    Ok(encode_base32_internal(u128::from(parsed)))
+}
+
+/// `datetime64` "Not a Time" sentinel (the minimum int64)
+const NAT: i64 = i64::MIN;
+
+/// Decode a raw 26-byte ULID without the GIL, or None if any byte is outside the alphabet
+fn decode_ascii(raw: &[u8]) -> Option<u128> {
+    if raw.len() != 26 {
+        return None;
+    }
+
+    let mut value: u128 = 0;
+    for &b in raw {
+        let digit = DECODE_TABLE[b as usize];
+        if digit == 0xFF {
+            return None;
+        }
+        value = (value << 5) | digit as u128;
+    }
+    Some(value)
+}
+
+/// Millisecond timestamp of a raw 26-byte ULID, or None if it isn't a valid ULID
+fn timestamp_from_ascii(raw: &[u8]) -> Option<i64> {
+    decode_ascii_checked(raw).map(|value| Ulid(value).timestamp_ms() as i64)
+}
+
+/// Decode ULID timestamps into a bytearray of native-endian int64 milliseconds.
+///
+/// Backs `pyulid.ulid_timestamps_np`, which views the result as `datetime64[ms]`.
+/// Invalid entries become NaT with `errors="coerce"`; otherwise the first one raises.
+#[pyfunction]
+#[pyo3(signature = (seq, errors = "raise"))]
+fn _ulid_timestamps_ms<'py>(
+    py: Python<'py>,
+    seq: &Bound<'py, PyAny>,
+    errors: &str,
+) -> PyResult<Bound<'py, PyByteArray>> {
+    let coerce = match errors {
+        "raise" => false,
+        "coerce" => true,
+        other => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "errors must be 'raise' or 'coerce', got '{}'",
+                other
+            )))
+        }
+    };
+
+    let (raw, count) = copy_ascii(py, seq, !coerce)?;
+
+    let mut first_invalid: Option<usize> = None;
+    let out = PyByteArray::new_with(py, count * 8, |buffer| {
+        // The bytearray is not yet visible to Python, so it is safe to fill without the GIL
+        py.allow_threads(|| {
+            for (i, (chunk, slot)) in raw
+                .chunks_exact(26)
+                .zip(buffer.chunks_exact_mut(8))
+                .enumerate()
+            {
+                signals::check_every(i)?;
+                let value = match timestamp_from_ascii(chunk) {
+                    Some(ts) => ts,
+                    None => {
+                        if !coerce {
+                            first_invalid = Some(i);
+                            break;
+                        }
+                        NAT
+                    }
+                };
+                slot.copy_from_slice(&value.to_ne_bytes());
+            }
+            Ok(())
+        })
+    })?;
+
+    if let Some(index) = first_invalid {
+        return Err(invalid_at(seq, index));
+    }
+
+    Ok(out)
+}
+
+/// Copy every entry of `seq` into one flat buffer of 26-byte records, under the GIL.
+///
+/// Entries of the wrong length are left zeroed and fail the alphabet check when
+/// decoded, as are entries other than str and bytes unless `strict`, which raises
+/// TypeError for them instead. Returns the buffer and the number of entries.
+fn copy_ascii(py: Python<'_>, seq: &Bound<'_, PyAny>, strict: bool) -> PyResult<(Vec<u8>, usize)> {
+    let mut raw: Vec<u8> = Vec::new();
+    let mut count = 0usize;
+    for item in seq.try_iter()? {
+        let item = item?;
+        signals::check_every_with_gil(py, count)?;
+        let start = raw.len();
+        raw.resize(start + 26, 0);
+
+        if let Ok(s) = item.downcast::<PyString>() {
+            let text = s.to_cow()?;
+            if text.len() == 26 {
+                raw[start..].copy_from_slice(text.as_bytes());
+            }
+        } else if let Ok(b) = item.downcast::<PyBytes>() {
+            if b.as_bytes().len() == 26 {
+                raw[start..].copy_from_slice(b.as_bytes());
+            }
+        } else if strict {
+            return Err(pyo3::exceptions::PyTypeError::new_err(format!(
+                "ULID at index {} must be str or bytes, got {}",
+                count,
+                item.get_type().name()?
+            )));
+        }
+        count += 1;
+    }
+    Ok((raw, count))
+}
+
+/// UlidDecodeError for the entry of `seq` at `index`
+fn invalid_at(seq: &Bound<'_, PyAny>, index: usize) -> PyErr {
+    // Plain iterables can't be indexed back into, so the offending value is best effort
+    let shown = seq
+        .get_item(index)
+        .and_then(|item| item.repr())
+        .map(|r| format!(": {}", r))
+        .unwrap_or_default();
+    errors::decode_error(format!("Invalid ULID at index {}{}", index, shown))
+}
+
+/// Decode a raw 26-byte ULID with the same checks as `ulid_is_valid`
+fn decode_ascii_checked(raw: &[u8]) -> Option<u128> {
+    decode_ascii(raw).filter(|_| raw[0] <= b'7')
+}
+
+/// `ulid_is_valid` for every entry of `seq`, in order.
+///
+/// Entries are copied out under the GIL and checked without it. Entries that aren't
+/// str or bytes are reported as invalid rather than raising.
+#[pyfunction]
+fn ulid_is_valid_many(py: Python<'_>, seq: &Bound<'_, PyAny>) -> PyResult<Vec<bool>> {
+    let (raw, _) = copy_ascii(py, seq, false)?;
+    py.allow_threads(|| {
+        raw.chunks_exact(26)
+            .enumerate()
+            .map(|(i, chunk)| {
+                signals::check_every(i)?;
+                Ok(decode_ascii_checked(chunk).is_some())
+            })
+            .collect()
+    })
+}
+
+/// `ulid_timestamp` for every entry of `seq`, in order.
+///
+/// Entries are copied out under the GIL and decoded without it. `errors` sets what
+/// happens to an invalid entry: `"raise"` (the default) raises UlidDecodeError naming
+/// the first one's index, `"skip"` leaves it out of the result and `"none"` puts None
+/// in its place.
+#[pyfunction]
+#[pyo3(signature = (seq, errors = "raise"))]
+fn ulid_timestamp_many(
+    py: Python<'_>,
+    seq: &Bound<'_, PyAny>,
+    errors: &str,
+) -> PyResult<Vec<Option<u64>>> {
+    let (raise, skip) = match errors {
+        "raise" => (true, false),
+        "skip" => (false, true),
+        "none" => (false, false),
+        other => {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "errors must be 'raise', 'skip' or 'none', got '{}'",
+                other
+            )))
+        }
+    };
+
+    let (raw, count) = copy_ascii(py, seq, raise)?;
+    let mut first_invalid: Option<usize> = None;
+    let timestamps = py.allow_threads(|| {
+        let mut timestamps = Vec::with_capacity(count);
+        for (i, chunk) in raw.chunks_exact(26).enumerate() {
+            signals::check_every(i)?;
+            match decode_ascii_checked(chunk) {
+                Some(value) => timestamps.push(Some(Ulid(value).timestamp_ms())),
+                None if raise => {
+                    first_invalid = Some(i);
+                    break;
+                }
+                None if skip => {}
+                None => timestamps.push(None),
+            }
+        }
+        Ok::<_, PyErr>(timestamps)
+    })?;
+
+    match first_invalid {
+        Some(index) => Err(invalid_at(seq, index)),
+        None => Ok(timestamps),
+    }
+}
+
+/// Generate `n` monotonic ULIDs into a bytearray of fixed-width records.
+///
+/// Backs `pyulid.ulid_batch_numpy`: 26 ASCII bytes per ULID (viewed as `S26`), or
+/// with `binary=True` 16 big-endian bytes per ULID (viewed as `uint8` rows). Each
+/// claimed run is encoded straight into the output, so peak memory is the output.
+#[cfg(feature = "numpy")]
+#[pyfunction]
+#[pyo3(signature = (n, binary = false))]
+fn _ulid_batch_raw(py: Python<'_>, n: usize, binary: bool) -> PyResult<Bound<'_, PyByteArray>> {
+    errors::guard(py, "ulid_batch_numpy", || {
+        let width = if binary { 16 } else { 26 };
+        let len = n
+            .checked_mul(width)
+            .ok_or_else(|| pyo3::exceptions::PyOverflowError::new_err("batch size is too large"))?;
+
+        let out = PyByteArray::new_with(py, len, |buffer| {
+            // The bytearray is not yet visible to Python, so it is safe to fill without the GIL
+            py.allow_threads(|| {
+                let mut records = buffer.chunks_exact_mut(width);
+                generate_runs(n, |timestamp, first, count| {
+                    let encoded_timestamp = encode_timestamp(timestamp);
+                    // The range goes first so zip never takes a record it can't fill
+                    for (i, record) in (0..count).zip(records.by_ref()) {
+                        let random = first + i as u128;
+                        if binary {
+                            record.copy_from_slice(
+                                &Ulid::from_parts(timestamp, random).0.to_be_bytes(),
+                            );
+                        } else {
+                            record[..10].copy_from_slice(&encoded_timestamp);
+                            record[10..].copy_from_slice(&encode_random(random));
+                        }
+                    }
+                })
+            })
+        });
+        logging::flush(py);
+        out
+    })
+}
+
+/// Shared binary search: first index with element `>= probe` (left) or `> probe` (right)
+fn bisect(
+    seq: &Bound<'_, PyAny>,
+    value: UlidArg,
+    lo: usize,
+    hi: Option<usize>,
+    right: bool,
+) -> PyResult<usize> {
+    let probe = value.0 .0;
+
+    // Arrays compare raw values; other sequences decode only the probed elements
+    let array = seq.downcast::<array::UlidArray>().ok().map(|a| a.borrow());
+    let element_at = |index: usize| -> PyResult<u128> {
+        if let Some(array) = &array {
+            return array.values().get(index).copied().ok_or_else(|| {
+                pyo3::exceptions::PyIndexError::new_err("UlidArray index out of range")
+            });
+        }
+
+        let element: UlidArg = seq
+            .get_item(index)?
+            .extract()
+            .map_err(|e| errors::decode_error(format!("Invalid ULID at index {}: {}", index, e)))?;
+        Ok(element.0 .0)
+    };
+
+    let (mut lo, mut hi) = (lo, hi.map_or_else(|| seq.len(), Ok)?);
+    while lo < hi {
+        let mid = lo + (hi - lo) / 2;
+        let element = element_at(mid)?;
+
+        let go_right = if right {
+            element <= probe
+        } else {
+            element < probe
+        };
+        if go_right {
+            lo = mid + 1;
+        } else {
+            hi = mid;
+        }
+    }
+    Ok(lo)
+}
+
+/// Like `bisect.bisect_left`, but comparing decoded ULID values (case-insensitive)
+#[pyfunction]
+#[pyo3(signature = (sorted_seq, value, lo = 0, hi = None))]
+fn ulid_bisect_left(
+    sorted_seq: &Bound<'_, PyAny>,
+    value: UlidArg,
+    lo: isize,
+    hi: Option<usize>,
+) -> PyResult<usize> {
+    if lo < 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "lo must be non-negative",
+        ));
+    }
+    bisect(sorted_seq, value, lo as usize, hi, false)
+}
+
+/// Like `bisect.bisect_right`, but comparing decoded ULID values (case-insensitive)
+#[pyfunction]
+#[pyo3(signature = (sorted_seq, value, lo = 0, hi = None))]
+fn ulid_bisect_right(
+    sorted_seq: &Bound<'_, PyAny>,
+    value: UlidArg,
+    lo: isize,
+    hi: Option<usize>,
+) -> PyResult<usize> {
+    if lo < 0 {
+        return Err(pyo3::exceptions::PyValueError::new_err(
+            "lo must be non-negative",
+        ));
+    }
+    bisect(sorted_seq, value, lo as usize, hi, true)
+}
+
+// 2020-01-01 and 2100-01-01 in Unix milliseconds: the sane window for the wall clock
+const SELF_CHECK_MIN_CLOCK_MS: u64 = 1_577_836_800_000;
+const SELF_CHECK_MAX_CLOCK_MS: u64 = 4_102_444_800_000;
+
+// Test vector from the ULID spec, with its decoded timestamp
+const SELF_CHECK_VECTOR: &str = "01ARZ3NDEKTSV4RRFFQ69G5FAV";
+const SELF_CHECK_VECTOR_MS: u64 = 1_469_922_850_259;
+
+/// A named health check: Ok(detail) on success, Err(detail) on failure
+type SelfCheck = fn() -> Result<String, String>;
+
+fn check_entropy() -> Result<String, String> {
+    let mut rng = rand::rng();
+    let samples: Vec<u128> = (0..8).map(|_| rng.random()).collect();
+
+    if samples.iter().all(|&s| s == samples[0]) {
+        return Err(format!(
+            "entropy source returned a constant ({:#x})",
+            samples[0]
+        ));
+    }
+    Ok("8 samples, not constant".to_string())
+}
+
+fn check_clock() -> Result<String, String> {
+    let now = clock::now_ms();
+    if now < SELF_CHECK_MIN_CLOCK_MS {
+        return Err(format!("clock reads {} ms, before 2020-01-01", now));
+    }
+    if now >= SELF_CHECK_MAX_CLOCK_MS {
+        return Err(format!("clock reads {} ms, after 2100-01-01", now));
+    }
+    Ok(format!("clock reads {} ms", now))
+}
+
+fn check_roundtrip() -> Result<String, String> {
+    let ulid = parse_ulid(SELF_CHECK_VECTOR).map_err(|e| e.to_string())?;
+    if ulid.timestamp_ms() != SELF_CHECK_VECTOR_MS {
+        return Err(format!(
+            "{} decoded to timestamp {}, expected {}",
+            SELF_CHECK_VECTOR,
+            ulid.timestamp_ms(),
+            SELF_CHECK_VECTOR_MS
+        ));
+    }
+
+    let encoded = ulid.to_string();
+    if encoded != SELF_CHECK_VECTOR {
+        return Err(format!("{} re-encoded as {}", SELF_CHECK_VECTOR, encoded));
+    }
+    Ok(format!("{} round-trips", SELF_CHECK_VECTOR))
+}
+
+fn check_monotonic() -> Result<String, String> {
+    let first = shared::next_value().map_err(|e| e.to_string())?;
+    let second = shared::next_value().map_err(|e| e.to_string())?;
+
+    if second <= first {
+        return Err(format!(
+            "{} was not greater than {}",
+            Ulid(second),
+            Ulid(first)
+        ));
+    }
+    Ok("two consecutive ULIDs strictly increase".to_string())
+}
+
+/// Verify entropy, clock, codec and monotonic generation are sane before serving traffic.
+///
+/// Returns `{check_name: {"passed": bool, "detail": str}}`. Only generates two ULIDs from
+/// the shared state, exactly as two `ulid()` calls would.
+#[pyfunction]
+#[pyo3(signature = (raise_on_failure = true))]
+fn self_check(py: Python<'_>, raise_on_failure: bool) -> PyResult<Bound<'_, PyDict>> {
+    errors::guard(py, "self_check", || run_self_check(py, raise_on_failure))
+}
+
+fn run_self_check(py: Python<'_>, raise_on_failure: bool) -> PyResult<Bound<'_, PyDict>> {
+    let checks: [(&str, SelfCheck); 4] = [
+        ("entropy", check_entropy),
+        ("clock", check_clock),
+        ("roundtrip", check_roundtrip),
+        ("monotonic", check_monotonic),
+    ];
+
+    let report = PyDict::new(py);
+    let mut first_failure: Option<(&str, String)> = None;
+    for (name, check) in checks {
+        let (passed, detail) = match check() {
+            Ok(detail) => (true, detail),
+            Err(detail) => (false, detail),
+        };
+        if !passed && first_failure.is_none() {
+            first_failure = Some((name, detail.clone()));
+        }
+
+        let entry = PyDict::new(py);
+        entry.set_item("passed", passed)?;
+        entry.set_item("detail", detail)?;
+        report.set_item(name, entry)?;
+    }
+    logging::flush(py);
+
+    if let (true, Some((name, detail))) = (raise_on_failure, first_failure) {
+        return Err(pyo3::exceptions::PyRuntimeError::new_err(format!(
+            "self-check '{}' failed: {}",
+            name, detail
+        )));
+    }
+    Ok(report)
+}
+
+#[derive(IntoPyObject)]
+enum TimeDelta {
+    Millis(i64),
+    Seconds(f64),
+}
+
+/// Signed time difference `timestamp(a) - timestamp(b)`.
+///
+/// A negative result means `a` is older than `b`. Only the 48-bit timestamps are
+/// compared; the random components are ignored entirely. `unit` is "ms" (int) or "s" (float).
+#[pyfunction]
+#[pyo3(signature = (a, b, unit = "ms"))]
+fn ulid_delta(a: UlidArg, b: UlidArg, unit: &str) -> PyResult<TimeDelta> {
+    let delta = a.0.timestamp_ms() as i64 - b.0.timestamp_ms() as i64;
+
+    match unit {
+        "ms" => Ok(TimeDelta::Millis(delta)),
+        "s" => Ok(TimeDelta::Seconds(delta as f64 / 1000.0)),
+        _ => Err(pyo3::exceptions::PyValueError::new_err(format!(
+            "unit must be 'ms' or 's', got '{}'",
+            unit
+        ))),
+    }
+}
+
+/// Extract a ULID argument, naming it in the error so the caller can tell which of
+/// several failed; the exception type is kept
+fn named_ulid_arg(obj: &Bound<'_, PyAny>, name: &str) -> PyResult<Ulid> {
+    obj.extract::<UlidArg>().map(|ulid| ulid.0).map_err(|e| {
+        let py = obj.py();
+        PyErr::from_type(
+            e.get_type(py),
+            format!("argument '{}': {}", name, e.value(py)),
+        )
+    })
+}
+
+/// Signed milliseconds `timestamp(a) - timestamp(b)`, negative when `a` is older.
+///
+/// `ulid_delta(a, b)` without the unit handling; an invalid argument is named in the
+/// error.
+#[pyfunction]
+fn ulid_diff_ms(a: &Bound<'_, PyAny>, b: &Bound<'_, PyAny>) -> PyResult<i64> {
+    let (a, b) = (named_ulid_arg(a, "a")?, named_ulid_arg(b, "b")?);
+    Ok(a.timestamp_ms() as i64 - b.timestamp_ms() as i64)
+}
+
+/// -1, 0 or 1 as `a` sorts before, equal to or after `b` by the full 128-bit value,
+/// e.g. for `functools.cmp_to_key`; an invalid argument is named in the error
+#[pyfunction]
+fn ulid_compare(a: &Bound<'_, PyAny>, b: &Bound<'_, PyAny>) -> PyResult<i32> {
+    let (a, b) = (named_ulid_arg(a, "a")?, named_ulid_arg(b, "b")?);
+    Ok(a.0.cmp(&b.0) as i32)
+}
+
+/// Milliseconds from the timestamp of `ulid_str` to now, negative for a ULID from the
+/// future. "Now" is the wall clock generation reads, so `_freeze_time()` applies too.
+#[pyfunction]
+fn ulid_age_ms(ulid_str: UlidArg) -> i64 {
+    clock::now_ms() as i64 - ulid_str.0.timestamp_ms() as i64
+}
+
+/// Whether `ttl_ms` milliseconds have passed since the timestamp of `ulid_str`, i.e.
+/// `ulid_age_ms(ulid_str) >= ttl_ms`
+#[pyfunction]
+fn ulid_is_expired(ulid_str: UlidArg, ttl_ms: u64) -> bool {
+    ulid_age_ms(ulid_str) >= ttl_ms.min(i64::MAX as u64) as i64
 }
 
 /// A Python module implemented in Rust.
-#[pymodule]
+///
+/// Safe without the GIL on free-threaded builds: shared state is atomics or behind
+/// its own locks, and non-frozen classes rely on PyO3's borrow checking.
+#[pymodule(gil_used = false)]
 fn pyulid(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(encode_base32, m)?)?;
     m.add_function(wrap_pyfunction!(decode_base32, m)?)?;
     m.add_function(wrap_pyfunction!(ulid, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_into, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_batch, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_non_monotonic, m)?)?;
+    m.add_function(wrap_pyfunction!(replay::ulid_with_timestamp_monotonic, m)?)?;
     m.add_function(wrap_pyfunction!(ulid_timestamp, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_timestamp_seconds, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_at, m)?)?;
+    m.add_function(wrap_pyfunction!(iso8601::ulid_from_iso8601, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_datetime, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_parse, m)?)?;
     m.add_function(wrap_pyfunction!(ulid_random, m)?)?;
     m.add_function(wrap_pyfunction!(ulid_is_valid, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_validate, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_clean, m)?)?;
     m.add_function(wrap_pyfunction!(ulid_with_timestamp, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_from_datetime, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_sibling, m)?)?;
     m.add_function(wrap_pyfunction!(ulid_to_uuid, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_to_uuid_obj, m)?)?;
     m.add_function(wrap_pyfunction!(uuid_to_ulid, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_to_uuid7, m)?)?;
+    m.add_function(wrap_pyfunction!(uuid7_to_ulid, m)?)?;
+    m.add_function(wrap_pyfunction!(uuid_timestamp, m)?)?;
+    m.add_function(wrap_pyfunction!(convert::convert_stream, m)?)?;
     m.add_function(wrap_pyfunction!(ulid_from_str, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_from_str_lenient, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_to_int, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_from_int, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_to_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_from_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_timestamp_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_random_bytes, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_to_hex, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_from_hex, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_next, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_prev, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_delta, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_diff_ms, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_compare, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_age_ms, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_is_expired, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_floor, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_ceil, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_min_for_timestamp, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_max_for_timestamp, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_nil, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_max, m)?)?;
+    m.add("MIN_ULID", ulid_nil())?;
+    m.add("MAX_ULID", ulid_max())?;
+    m.add_function(wrap_pyfunction!(ulid_range, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_scan_partitions, m)?)?;
+    m.add_function(wrap_pyfunction!(encode_timestamp_base32, m)?)?;
+    m.add_function(wrap_pyfunction!(decode_timestamp_base32, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_with_suffix, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_parse_suffix, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_pattern, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_set_timestamp, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_set_random, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_from_parts, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_to_b32std, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_from_b32std, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_to_base64, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_from_base64, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_with_check, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_verify_check, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_strip_check, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_to_json, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_from_json, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_to_ipv6, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_from_ipv6, m)?)?;
+    m.add_function(wrap_pyfunction!(_ulid_timestamps_ms, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_is_valid_many, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_timestamp_many, m)?)?;
+    #[cfg(feature = "numpy")]
+    m.add_function(wrap_pyfunction!(_ulid_batch_raw, m)?)?;
+    m.add_function(wrap_pyfunction!(columns::_ulid_column, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_bisect_left, m)?)?;
+    m.add_function(wrap_pyfunction!(ulid_bisect_right, m)?)?;
+    m.add_function(wrap_pyfunction!(array::ulid_batch_array, m)?)?;
+    m.add_class::<array::UlidArray>()?;
+    m.add_class::<Ulid>()?;
+    m.add_class::<generator::UlidGenerator>()?;
+    m.add_function(wrap_pyfunction!(generator::ulid_node_id, m)?)?;
+    m.add_class::<keyed::KeyedUlidGenerator>()?;
+    m.add_class::<set::UlidSet>()?;
+    m.add_class::<load::UlidLoadGenerator>()?;
+    m.add_class::<interval::UlidInterval>()?;
+    m.add_class::<checkpoint::UlidCheckpoint>()?;
+    m.add_function(wrap_pyfunction!(logging::_set_log_level, m)?)?;
+    m.add_function(wrap_pyfunction!(self_check, m)?)?;
+    m.add_function(wrap_pyfunction!(entropy::ulid_entropy_check, m)?)?;
+    m.add_function(wrap_pyfunction!(entropy::set_entropy_source, m)?)?;
+    m.add_function(wrap_pyfunction!(entropy::set_rng_mode, m)?)?;
+    m.add_function(wrap_pyfunction!(entropy::get_rng_mode, m)?)?;
+    m.add_function(wrap_pyfunction!(reseed, m)?)?;
+    m.add_function(wrap_pyfunction!(set_minimum_timestamp, m)?)?;
+    m.add_function(wrap_pyfunction!(abbrev::ulid_abbreviate, m)?)?;
+    m.add_function(wrap_pyfunction!(abbrev::ulid_expand, m)?)?;
+    m.add_function(wrap_pyfunction!(abbrev::ulid_closest, m)?)?;
+    m.add_function(wrap_pyfunction!(analytics::ulid_sessionize, m)?)?;
+    m.add_function(wrap_pyfunction!(analytics::ulid_sample, m)?)?;
+    m.add_function(wrap_pyfunction!(tag::ulid_embed_tag, m)?)?;
+    m.add_function(wrap_pyfunction!(tag::ulid_extract_tag, m)?)?;
+    m.add(
+        "AmbiguousPrefixError",
+        m.py().get_type::<abbrev::AmbiguousPrefixError>(),
+    )?;
+    m.add_function(wrap_pyfunction!(config::configure, m)?)?;
+    #[cfg(feature = "test-hooks")]
+    m.add_function(wrap_pyfunction!(errors::_inject_panic, m)?)?;
+    #[cfg(feature = "test-hooks")]
+    m.add_function(wrap_pyfunction!(_ulid_via_string, m)?)?;
+    m.add_function(wrap_pyfunction!(clock::_freeze_time, m)?)?;
+    m.add_function(wrap_pyfunction!(clock::_unfreeze_time, m)?)?;
+    m.add_function(wrap_pyfunction!(replay::_resume_timestamp_sequence, m)?)?;
+    errors::register(m)?;
+    fork::register(m)?;
+    m.add_function(wrap_pyfunction!(config::get_config, m)?)?;
+    m.add_function(wrap_pyfunction!(config::stats, m)?)?;
     Ok(())
 }
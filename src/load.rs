@@ -0,0 +1,115 @@
+use std::thread;
+use std::time::{Duration, Instant};
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+
+use crate::{errors, logging, shared, ALPHABET};
+
+/// Endless iterator of monotonic ULIDs paced to `rate_per_sec`, for load testing.
+///
+/// Pacing is a token bucket holding up to `burst` tokens, tracked in its GCRA form (a
+/// theoretical arrival time advanced by one interval per ULID) so oversleeping never
+/// accumulates into drift. Waits release the GIL. With `burst=1` a late wakeup can't be
+/// made up afterwards; a larger `burst` lets the stream catch up after scheduler hiccups.
+#[pyclass(module = "pyulid")]
+pub struct UlidLoadGenerator {
+    rate_per_sec: f64,
+    burst: usize,
+    interval: f64,
+    origin: Instant,
+    // Theoretical arrival time of the next ULID, in seconds since `origin`
+    tat: f64,
+}
+
+impl UlidLoadGenerator {
+    /// Seconds since `origin` at which the next ULID may be emitted
+    fn ready_at(&self, now: f64) -> f64 {
+        self.tat.max(now) - (self.burst - 1) as f64 * self.interval
+    }
+
+    fn wait_and_generate(&mut self, py: Python<'_>) -> PyResult<String> {
+        let now = self.origin.elapsed().as_secs_f64();
+        let ready_at = self.ready_at(now);
+        if ready_at > now {
+            py.allow_threads(|| thread::sleep(Duration::from_secs_f64(ready_at - now)));
+            py.check_signals()?;
+        }
+        self.tat = self.tat.max(now) + self.interval;
+
+        let result = shared::next_string(ALPHABET);
+        logging::flush(py);
+        result.map_err(PyErr::from)
+    }
+}
+
+#[pymethods]
+impl UlidLoadGenerator {
+    #[new]
+    #[pyo3(signature = (rate_per_sec, burst = 1))]
+    fn new(rate_per_sec: f64, burst: usize) -> PyResult<Self> {
+        if !(rate_per_sec.is_finite() && rate_per_sec > 0.0) {
+            return Err(PyValueError::new_err(format!(
+                "rate_per_sec must be a positive number, got {}",
+                rate_per_sec
+            )));
+        }
+        if burst == 0 {
+            return Err(PyValueError::new_err("burst must be at least 1"));
+        }
+
+        Ok(UlidLoadGenerator {
+            rate_per_sec,
+            burst,
+            interval: 1.0 / rate_per_sec,
+            origin: Instant::now(),
+            tat: 0.0,
+        })
+    }
+
+    #[getter]
+    fn rate_per_sec(&self) -> f64 {
+        self.rate_per_sec
+    }
+
+    #[getter]
+    fn burst(&self) -> usize {
+        self.burst
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> PyResult<String> {
+        errors::guard(py, "UlidLoadGenerator.__next__", || {
+            self.wait_and_generate(py)
+        })
+    }
+
+    /// Every ULID the pacing allows within the next `duration_s` seconds
+    fn take(&mut self, py: Python<'_>, duration_s: f64) -> PyResult<Vec<String>> {
+        if !(duration_s.is_finite() && duration_s >= 0.0) {
+            return Err(PyValueError::new_err(format!(
+                "duration_s must be a non-negative number, got {}",
+                duration_s
+            )));
+        }
+
+        errors::guard(py, "UlidLoadGenerator.take", || {
+            let deadline = self.origin.elapsed().as_secs_f64() + duration_s;
+            let mut ulids = Vec::new();
+            while self.ready_at(self.origin.elapsed().as_secs_f64()) < deadline {
+                ulids.push(self.wait_and_generate(py)?);
+            }
+            Ok(ulids)
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "UlidLoadGenerator(rate_per_sec={}, burst={})",
+            self.rate_per_sec, self.burst
+        )
+    }
+}
@@ -0,0 +1,96 @@
+use std::cell::RefCell;
+use std::sync::atomic::{AtomicBool, AtomicU8, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use pyo3::prelude::*;
+
+// Python `logging` levels
+pub(crate) const DEBUG: u8 = 10;
+pub(crate) const INFO: u8 = 20;
+pub(crate) const WARNING: u8 = 30;
+
+// Minimum level forwarded to Python; 0 means logging is off
+static THRESHOLD: AtomicU8 = AtomicU8::new(0);
+static HAS_PENDING: AtomicBool = AtomicBool::new(false);
+
+// Events are buffered rather than logged in place because they are raised while the
+// generator state lock is held; calling into Python there could deadlock against a
+// handler that itself generates a ULID
+static PENDING: Mutex<Events> = Mutex::new(Vec::new());
+
+/// Queued (level, message) pairs
+type Events = Vec<(u8, String)>;
+
+/// Queue an event for the `pyulid` logger. `message` only runs when the level is enabled.
+#[inline(always)]
+pub(crate) fn emit(level: u8, message: impl FnOnce() -> String) {
+    let threshold = THRESHOLD.load(Ordering::Relaxed);
+    if threshold == 0 || level < threshold {
+        return;
+    }
+
+    PENDING
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+        .push((level, message()));
+    HAS_PENDING.store(true, Ordering::Release);
+}
+
+/// Forward queued events to `logging.getLogger("pyulid")`. Call only with no locks held.
+#[inline(always)]
+pub(crate) fn flush(py: Python<'_>) {
+    if !HAS_PENDING.load(Ordering::Relaxed) {
+        return;
+    }
+
+    let events = {
+        let mut pending = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+        HAS_PENDING.store(false, Ordering::Relaxed);
+        std::mem::take(&mut *pending)
+    };
+
+    let result = (|| -> PyResult<()> {
+        let logger = py
+            .import("logging")?
+            .call_method1("getLogger", ("pyulid",))?;
+        for (level, message) in events {
+            logger.call_method1("log", (level, message))?;
+        }
+        Ok(())
+    })();
+
+    // A broken handler must never turn ID generation into an error
+    if let Err(e) = result {
+        e.write_unraisable(py, None);
+    }
+}
+
+thread_local! {
+    // Same fork protocol as the shared generator's writer lock
+    static FORK_GUARD: RefCell<Option<MutexGuard<'static, Events>>> =
+        const { RefCell::new(None) };
+}
+
+/// Fork hook, before: take the queue lock, last, since events are queued while the
+/// state locks are held
+pub(crate) fn before_fork() {
+    let guard = PENDING.lock().unwrap_or_else(|e| e.into_inner());
+    FORK_GUARD.with(|held| *held.borrow_mut() = Some(guard));
+}
+
+/// Fork hook, after in the parent or the child: release the queue lock
+pub(crate) fn after_fork() {
+    FORK_GUARD.with(|held| held.borrow_mut().take());
+}
+
+/// Set the minimum forwarded level (0 disables). Backs `pyulid.enable_logging`.
+#[pyfunction]
+pub fn _set_log_level(py: Python<'_>, level: u8) {
+    THRESHOLD.store(level, Ordering::Relaxed);
+    if level == 0 {
+        PENDING.lock().unwrap_or_else(|e| e.into_inner()).clear();
+        HAS_PENDING.store(false, Ordering::Relaxed);
+    } else {
+        flush(py);
+    }
+}
@@ -0,0 +1,242 @@
+use std::cell::RefCell;
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Mutex, MutexGuard};
+
+use pyo3::prelude::*;
+use rand::Rng;
+
+use crate::{clean, encode_timestamp, entropy, errors, logging, TimestampArg, Ulid, UlidArg};
+
+/// Timestamps whose sequences are remembered; the least recently used is forgotten first
+const MAX_TIMESTAMPS: usize = 65_536;
+
+/// Ranges of forgotten timestamps kept apart; beyond this the closest two are merged
+const MAX_FORGOTTEN_RANGES: usize = 1024;
+
+/// Floors for forgotten timestamps, summarized as disjoint ranges of timestamps that
+/// each keep the largest random component any of them issued. A timestamp outside
+/// every range was never forgotten and needs no floor.
+#[derive(Default)]
+struct Forgotten {
+    // First timestamp → (last timestamp, floor); ranges never touch or overlap
+    ranges: BTreeMap<u64, (u64, u128)>,
+}
+
+impl Forgotten {
+    /// The floor for `timestamp`, if it may have been forgotten
+    fn floor(&self, timestamp: u64) -> Option<u128> {
+        let (_, &(end, floor)) = self.ranges.range(..=timestamp).next_back()?;
+        (timestamp <= end).then_some(floor)
+    }
+
+    /// Remember that `timestamp` was forgotten after issuing `random`
+    fn record(&mut self, timestamp: u64, random: u128) {
+        let (mut start, mut end, mut floor) = (timestamp, timestamp, random);
+        // Join the range covering or just before `timestamp`, then the one just after
+        if let Some((&prev_start, &(prev_end, prev_floor))) =
+            self.ranges.range(..=timestamp).next_back()
+        {
+            if prev_end.saturating_add(1) >= timestamp {
+                self.ranges.remove(&prev_start);
+                (start, end, floor) = (prev_start, end.max(prev_end), floor.max(prev_floor));
+            }
+        }
+        if let Some((&next_start, &(next_end, next_floor))) =
+            self.ranges.range(timestamp.saturating_add(1)..).next()
+        {
+            if next_start <= end.saturating_add(1) {
+                self.ranges.remove(&next_start);
+                (end, floor) = (next_end, floor.max(next_floor));
+            }
+        }
+        self.ranges.insert(start, (end, floor));
+
+        if self.ranges.len() > MAX_FORGOTTEN_RANGES {
+            self.merge_closest();
+        }
+    }
+
+    /// Merge the two neighbouring ranges with the smallest gap between them
+    fn merge_closest(&mut self) {
+        let closest = self
+            .ranges
+            .iter()
+            .zip(self.ranges.iter().skip(1))
+            .min_by_key(|((_, &(end, _)), (&next_start, _))| next_start - end)
+            .map(|((&start, _), (&next_start, _))| (start, next_start));
+        if let Some((start, next_start)) = closest {
+            let (next_end, next_floor) = self.ranges.remove(&next_start).unwrap_or_default();
+            if let Some((end, floor)) = self.ranges.get_mut(&start) {
+                *end = next_end;
+                *floor = (*floor).max(next_floor);
+            }
+        }
+    }
+}
+
+/// Last random component issued for each caller-supplied timestamp
+struct Sequences {
+    last: HashMap<u64, (u128, u64)>,
+    // Access order: oldest tick first, same scheme as KeyedUlidGenerator
+    lru: BTreeMap<u64, u64>,
+    tick: u64,
+    forgotten: Forgotten,
+}
+
+impl Sequences {
+    /// Record `random` as the last value issued at `timestamp`
+    fn store(&mut self, timestamp: u64, random: u128) {
+        self.tick += 1;
+        if let Some((_, tick)) = self.last.get(&timestamp) {
+            self.lru.remove(tick);
+        } else if self.last.len() >= MAX_TIMESTAMPS {
+            if let Some((_, oldest)) = self.lru.pop_first() {
+                if let Some((last, _)) = self.last.remove(&oldest) {
+                    self.forgotten.record(oldest, last);
+                }
+                logging::emit(logging::DEBUG, || {
+                    format!("forgot the sequence for timestamp {}", oldest)
+                });
+            }
+        }
+        self.last.insert(timestamp, (random, self.tick));
+        self.lru.insert(self.tick, timestamp);
+    }
+
+    fn next(
+        &mut self,
+        timestamp: u64,
+        words: Option<&clean::Matcher>,
+    ) -> Result<u128, errors::GenerateError> {
+        let mut rng = entropy::mode_rng(rand::rng());
+        let timestamp_str = encode_timestamp(timestamp);
+
+        let known = self.last.get(&timestamp).map(|&(last, _)| last);
+        let floor = match known {
+            Some(_) => None,
+            None => self.forgotten.floor(timestamp),
+        };
+
+        let random = match (known, floor) {
+            (Some(last), _) | (None, Some(last)) if last == Ulid::bitmask(80) => {
+                logging::emit(logging::WARNING, || {
+                    format!("random component overflow at timestamp {}", timestamp)
+                });
+                return Err(errors::GenerateError::Overflow(format!(
+                    "Random component overflow, too many ULIDs for timestamp {}",
+                    timestamp
+                )));
+            }
+            (Some(last), _) => match words {
+                Some(words) => words.pick(&timestamp_str, last + 1, |rejected, low_bits| {
+                    step_past(&mut rng, rejected, low_bits)
+                }),
+                None => last + 1,
+            },
+            // The timestamp may have been forgotten, so start above everything its
+            // range of forgotten timestamps issued rather than risk going back
+            (None, Some(floor)) => {
+                let span = Ulid::bitmask(80) - floor;
+                let random = floor + 1 + rng.random::<u128>() % span;
+                match words {
+                    Some(words) => words.pick(&timestamp_str, random, |rejected, low_bits| {
+                        step_past(&mut rng, rejected, low_bits)
+                    }),
+                    None => random,
+                }
+            }
+            (None, None) => {
+                let random = rng.random::<u128>() & Ulid::bitmask(80);
+                match words {
+                    Some(words) => words.pick(&timestamp_str, random, |_, _| {
+                        Some(rng.random::<u128>() & Ulid::bitmask(80))
+                    }),
+                    None => random,
+                }
+            }
+        };
+
+        self.store(timestamp, random);
+        Ok(random)
+    }
+}
+
+// Same step-past redraw as the main generator, so order is kept
+fn step_past(rng: &mut impl Rng, rejected: u128, low_bits: u32) -> Option<u128> {
+    let low_mask = Ulid::bitmask(low_bits as u8);
+    let next = ((rejected >> low_bits) + 1) << low_bits;
+    (next <= Ulid::bitmask(80)).then(|| next | (rng.random::<u128>() & low_mask))
+}
+
+static SEQUENCES: Mutex<Option<Sequences>> = Mutex::new(None);
+
+// Poison-tolerant for the same reason as the global state lock
+fn lock() -> MutexGuard<'static, Option<Sequences>> {
+    SEQUENCES.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+fn with_sequences<T>(f: impl FnOnce(&mut Sequences) -> T) -> T {
+    let mut sequences = lock();
+    f(sequences.get_or_insert_with(|| Sequences {
+        last: HashMap::new(),
+        lru: BTreeMap::new(),
+        tick: 0,
+        forgotten: Forgotten::default(),
+    }))
+}
+
+thread_local! {
+    // Same fork protocol as the shared generator's writer lock
+    static FORK_GUARD: RefCell<Option<MutexGuard<'static, Option<Sequences>>>> =
+        const { RefCell::new(None) };
+}
+
+/// Fork hook, before: take the sequences lock
+pub(crate) fn before_fork() {
+    let guard = lock();
+    FORK_GUARD.with(|held| *held.borrow_mut() = Some(guard));
+}
+
+/// Fork hook, after in the parent: release the sequences lock untouched
+pub(crate) fn after_fork_in_parent() {
+    FORK_GUARD.with(|held| held.borrow_mut().take());
+}
+
+/// Fork hook, after in the child: forget every sequence, so the child starts each
+/// timestamp afresh instead of repeating what the parent issues next
+pub(crate) fn after_fork_in_child() {
+    if let Some(mut sequences) = FORK_GUARD.with(|held| held.borrow_mut().take()) {
+        *sequences = None;
+    }
+}
+
+/// `ulid_with_timestamp`, but strictly increasing across calls with the same timestamp.
+///
+/// Each timestamp keeps its own sequence: the first call draws a fresh random component
+/// and later calls increment it, raising on overflow like `ulid()`. Timestamps may come
+/// in any order, e.g. when re-stamping historical events. Sequences for the 65,536 most
+/// recently used timestamps are kept; an older one that is used again resumes above
+/// the largest random component issued by the forgotten timestamps around it, so it
+/// never goes back.
+#[pyfunction]
+pub fn ulid_with_timestamp_monotonic(
+    py: Python<'_>,
+    timestamp_ms: TimestampArg,
+) -> PyResult<String> {
+    let timestamp = timestamp_ms.0;
+    errors::guard(py, "ulid_with_timestamp_monotonic", || {
+        let words = clean::active();
+        let result = with_sequences(|sequences| sequences.next(timestamp, words.as_deref()));
+        logging::flush(py);
+
+        let random = result?;
+        Ok(Ulid::try_from_parts(timestamp, random)?.to_string())
+    })
+}
+
+/// Test hook: continue the sequence of `ulid`'s timestamp right after `ulid`
+#[pyfunction]
+pub fn _resume_timestamp_sequence(ulid: UlidArg) {
+    let ulid = ulid.0;
+    with_sequences(|sequences| sequences.store(ulid.timestamp_ms(), ulid.random()));
+}
@@ -0,0 +1,220 @@
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes};
+
+use crate::array::collect_values;
+use crate::{encode_base32_internal, errors, UlidArg};
+
+/// Set of ULIDs stored as a sorted, de-duplicated `Vec<u128>` (16 bytes per element).
+///
+/// Set algebra runs as a linear merge with the GIL released. `add` is O(n), so build
+/// large sets from an iterable in one go.
+#[pyclass(module = "pyulid")]
+pub struct UlidSet {
+    values: Vec<u128>,
+}
+
+fn parse_blob(data: &[u8]) -> PyResult<Vec<u128>> {
+    if !data.len().is_multiple_of(16) {
+        return Err(errors::decode_error(format!(
+            "byte length must be a multiple of 16, got {}",
+            data.len()
+        )));
+    }
+
+    Ok(data
+        .chunks_exact(16)
+        .map(|chunk| u128::from_be_bytes(chunk.try_into().unwrap()))
+        .collect())
+}
+
+fn sorted_unique(py: Python<'_>, mut values: Vec<u128>) -> Vec<u128> {
+    py.allow_threads(|| {
+        values.sort_unstable();
+        values.dedup();
+        values
+    })
+}
+
+/// Sorted, unique values of a UlidSet, bytes blob or iterable of ULIDs
+fn operand_values(py: Python<'_>, other: &Bound<'_, PyAny>) -> PyResult<Vec<u128>> {
+    if let Ok(set) = other.downcast::<UlidSet>() {
+        return Ok(set.borrow().values.clone());
+    }
+
+    let values = if let Ok(blob) = other.downcast::<PyBytes>() {
+        parse_blob(blob.as_bytes())?
+    } else if let Ok(blob) = other.downcast::<PyByteArray>() {
+        parse_blob(&blob.to_vec())?
+    } else {
+        collect_values(py, other)?
+    };
+    Ok(sorted_unique(py, values))
+}
+
+/// Linear merge of two sorted, unique slices, keeping the requested regions
+fn merge(a: &[u128], b: &[u128], only_a: bool, both: bool, only_b: bool) -> Vec<u128> {
+    let mut out = Vec::new();
+    let (mut i, mut j) = (0, 0);
+    while i < a.len() && j < b.len() {
+        if a[i] < b[j] {
+            if only_a {
+                out.push(a[i]);
+            }
+            i += 1;
+        } else if a[i] > b[j] {
+            if only_b {
+                out.push(b[j]);
+            }
+            j += 1;
+        } else {
+            if both {
+                out.push(a[i]);
+            }
+            i += 1;
+            j += 1;
+        }
+    }
+    if only_a {
+        out.extend_from_slice(&a[i..]);
+    }
+    if only_b {
+        out.extend_from_slice(&b[j..]);
+    }
+    out
+}
+
+impl UlidSet {
+    fn combine(
+        &self,
+        py: Python<'_>,
+        other: &Bound<'_, PyAny>,
+        only_a: bool,
+        both: bool,
+        only_b: bool,
+    ) -> PyResult<UlidSet> {
+        let other = operand_values(py, other)?;
+        let values = py.allow_threads(|| merge(&self.values, &other, only_a, both, only_b));
+        Ok(UlidSet { values })
+    }
+}
+
+#[pymethods]
+impl UlidSet {
+    /// Build from an iterable of ULIDs, a UlidArray or a blob of 16-byte records
+    #[new]
+    #[pyo3(signature = (iterable = None))]
+    fn new(py: Python<'_>, iterable: Option<&Bound<'_, PyAny>>) -> PyResult<Self> {
+        let values = match iterable {
+            Some(iterable) => operand_values(py, iterable)?,
+            None => Vec::new(),
+        };
+        Ok(UlidSet { values })
+    }
+
+    /// Build from big-endian 16-byte records (the `to_bytes()` format)
+    #[staticmethod]
+    fn from_bytes(py: Python<'_>, data: &[u8]) -> PyResult<Self> {
+        let values = sorted_unique(py, parse_blob(data)?);
+        Ok(UlidSet { values })
+    }
+
+    /// Sorted big-endian 16-byte records
+    fn to_bytes<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyBytes>> {
+        PyBytes::new_with(py, self.values.len() * 16, |buffer| {
+            for (chunk, value) in buffer.chunks_exact_mut(16).zip(&self.values) {
+                chunk.copy_from_slice(&value.to_be_bytes());
+            }
+            Ok(())
+        })
+    }
+
+    fn __len__(&self) -> usize {
+        self.values.len()
+    }
+
+    fn __contains__(&self, item: &Bound<'_, PyAny>) -> bool {
+        match item.extract::<UlidArg>() {
+            Ok(ulid) => self.values.binary_search(&ulid.0 .0).is_ok(),
+            Err(_) => false,
+        }
+    }
+
+    fn __iter__(slf: PyRef<'_, Self>) -> UlidSetIter {
+        UlidSetIter {
+            set: slf.into(),
+            index: 0,
+        }
+    }
+
+    fn __eq__(&self, other: PyRef<'_, Self>) -> bool {
+        self.values == other.values
+    }
+
+    fn __repr__(&self) -> String {
+        format!("UlidSet(len={})", self.values.len())
+    }
+
+    fn __sizeof__(&self) -> usize {
+        std::mem::size_of::<Self>() + self.values.capacity() * std::mem::size_of::<u128>()
+    }
+
+    /// Add one ULID (string or ULID object)
+    fn add(&mut self, value: UlidArg) {
+        if let Err(index) = self.values.binary_search(&value.0 .0) {
+            self.values.insert(index, value.0 .0);
+        }
+    }
+
+    fn union(&self, py: Python<'_>, other: &Bound<'_, PyAny>) -> PyResult<UlidSet> {
+        self.combine(py, other, true, true, true)
+    }
+
+    fn intersection(&self, py: Python<'_>, other: &Bound<'_, PyAny>) -> PyResult<UlidSet> {
+        self.combine(py, other, false, true, false)
+    }
+
+    fn difference(&self, py: Python<'_>, other: &Bound<'_, PyAny>) -> PyResult<UlidSet> {
+        self.combine(py, other, true, false, false)
+    }
+
+    fn symmetric_difference(&self, py: Python<'_>, other: &Bound<'_, PyAny>) -> PyResult<UlidSet> {
+        self.combine(py, other, true, false, true)
+    }
+
+    // Operators only take another UlidSet, like the built-in set
+    fn __or__(&self, py: Python<'_>, other: &Bound<'_, UlidSet>) -> PyResult<UlidSet> {
+        self.union(py, other.as_any())
+    }
+
+    fn __and__(&self, py: Python<'_>, other: &Bound<'_, UlidSet>) -> PyResult<UlidSet> {
+        self.intersection(py, other.as_any())
+    }
+
+    fn __sub__(&self, py: Python<'_>, other: &Bound<'_, UlidSet>) -> PyResult<UlidSet> {
+        self.difference(py, other.as_any())
+    }
+
+    fn __xor__(&self, py: Python<'_>, other: &Bound<'_, UlidSet>) -> PyResult<UlidSet> {
+        self.symmetric_difference(py, other.as_any())
+    }
+}
+
+#[pyclass(module = "pyulid")]
+pub struct UlidSetIter {
+    set: Py<UlidSet>,
+    index: usize,
+}
+
+#[pymethods]
+impl UlidSetIter {
+    fn __iter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __next__(&mut self, py: Python<'_>) -> Option<String> {
+        let set = self.set.borrow(py);
+        let value = set.values.get(self.index)?;
+        self.index += 1;
+        Some(encode_base32_internal(*value))
+    }
+}
@@ -0,0 +1,580 @@
+// Every ULID of a millisecond is `base + offset` for that millisecond's random `base`,
+// so issuing one only has to bump an offset counter, which threads do with a
+// compare-and-swap and no lock. The writer lock is only taken to start a new
+// millisecond or to move the base (clean-word redraws, `reseed()`,
+// `set_minimum_timestamp()`). Nothing it guards can be left half-updated, so a panic
+// never poisons generation.
+//
+// The published `(timestamp, base)` pairs live in two slots used alternately, tagged
+// with a generation number. `COUNTER` packs the generation its offsets belong to with
+// the number claimed so far, so a claim against an outdated pair always fails.
+
+use std::cell::RefCell;
+use std::fmt;
+use std::sync::atomic::{fence, AtomicBool, AtomicU64, Ordering};
+use std::sync::{Mutex, MutexGuard, OnceLock};
+
+use pyo3::PyErr;
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+use crate::{
+    buffer_to_string, clean, clock, current_timestamp_ms, encode_ascii, encode_timestamp, entropy,
+    errors::GenerateError, logging, rollover_random, Ulid,
+};
+
+const OFFSET_BITS: u32 = 24;
+const GENERATION_MASK: u64 = (1 << (64 - OFFSET_BITS)) - 1;
+/// Offsets claimed, as a count; reaching it closes the generation until a new base is
+/// published, which the writer also does on purpose before moving the base
+const CLOSED: u64 = (1 << OFFSET_BITS) - 1;
+/// One past the largest random component
+const RANDOM_END: u128 = 1 << Ulid::RAND_BITS;
+const MAX_TIMESTAMP: u64 = Ulid::bitmask(Ulid::TIME_BITS) as u64;
+
+struct Slot {
+    timestamp: AtomicU64,
+    base_high: AtomicU64,
+    base_low: AtomicU64,
+}
+
+impl Slot {
+    const fn empty() -> Self {
+        Slot {
+            timestamp: AtomicU64::new(0),
+            base_high: AtomicU64::new(0),
+            base_low: AtomicU64::new(0),
+        }
+    }
+}
+
+static SLOTS: [Slot; 2] = [Slot::empty(), Slot::empty()];
+// Generation of the newest published pair, in SLOTS[generation % 2]; 0 until the first
+static GENERATION: AtomicU64 = AtomicU64::new(0);
+// (generation << OFFSET_BITS) | offsets claimed from that generation's base
+static COUNTER: AtomicU64 = AtomicU64::new(0);
+
+// configure(overflow="carry"): move on to the next millisecond instead of raising
+static CARRY: AtomicBool = AtomicBool::new(false);
+
+pub(crate) fn carry_enabled() -> bool {
+    CARRY.load(Ordering::Relaxed)
+}
+
+pub(crate) fn set_carry(enabled: bool) {
+    CARRY.store(enabled, Ordering::Relaxed);
+}
+
+/// Why no ULID could be claimed
+pub(crate) enum ClaimError {
+    /// The clock moved backwards or the random component ran out
+    Failed(GenerateError),
+    /// The entropy source raised or returned something unusable
+    Source(PyErr),
+}
+
+impl From<ClaimError> for PyErr {
+    fn from(error: ClaimError) -> PyErr {
+        match error {
+            ClaimError::Failed(error) => error.into(),
+            ClaimError::Source(error) => error,
+        }
+    }
+}
+
+impl fmt::Display for ClaimError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClaimError::Failed(error) => write!(f, "{}", error),
+            ClaimError::Source(error) => write!(f, "entropy source failed: {}", error),
+        }
+    }
+}
+
+/// Owns the RNG and is the only publisher of new pairs
+struct Writer {
+    rng: StdRng, // Owned so reseed() can replace it after a snapshot restore
+}
+
+static WRITER: OnceLock<Mutex<Writer>> = OnceLock::new();
+
+/// Lock the writer. Poison-tolerant: the RNG is the only thing it guards.
+fn writer() -> MutexGuard<'static, Writer> {
+    WRITER
+        .get_or_init(|| {
+            Mutex::new(Writer {
+                rng: StdRng::from_rng(&mut rand::rng()),
+            })
+        })
+        .lock()
+        .unwrap_or_else(|e| e.into_inner())
+}
+
+#[derive(Clone, Copy)]
+struct Pair {
+    generation: u64,
+    timestamp: u64,
+    base: u128,
+}
+
+impl Pair {
+    fn tag(&self) -> u64 {
+        self.generation & GENERATION_MASK
+    }
+}
+
+/// The newest published pair, read without locking
+fn current() -> Pair {
+    loop {
+        let generation = GENERATION.load(Ordering::Acquire);
+        let slot = &SLOTS[(generation % 2) as usize];
+        let timestamp = slot.timestamp.load(Ordering::Relaxed);
+        let base = ((slot.base_high.load(Ordering::Relaxed) as u128) << 64)
+            | slot.base_low.load(Ordering::Relaxed) as u128;
+
+        // A writer reusing this slot bumps GENERATION first, so an unchanged generation
+        // means the three loads weren't torn
+        fence(Ordering::Acquire);
+        if GENERATION.load(Ordering::Relaxed) == generation {
+            return Pair {
+                generation,
+                timestamp,
+                base,
+            };
+        }
+    }
+}
+
+/// Make `(timestamp, base)` the pair new ULIDs are drawn from
+fn publish(_writer: &mut Writer, timestamp: u64, base: u128) -> Pair {
+    let generation = GENERATION.load(Ordering::Relaxed) + 1;
+    let slot = &SLOTS[(generation % 2) as usize];
+
+    // Pairs with the reader's fence: seeing any store below implies seeing the
+    // previous generation bump, so the reader retries
+    fence(Ordering::Release);
+    slot.timestamp.store(timestamp, Ordering::Relaxed);
+    slot.base_high.store((base >> 64) as u64, Ordering::Relaxed);
+    slot.base_low.store(base as u64, Ordering::Relaxed);
+    GENERATION.store(generation, Ordering::Release);
+
+    Pair {
+        generation,
+        timestamp,
+        base,
+    }
+}
+
+/// Offsets claimed from `pair` so far, or None if `pair` is outdated
+fn claimed(pair: &Pair, counter: u64) -> Option<u64> {
+    let tag = counter >> OFFSET_BITS;
+    if tag == pair.tag() {
+        Some(counter & CLOSED)
+    } else if tag < pair.tag() {
+        // Nobody has claimed from this pair yet
+        Some(0)
+    } else {
+        None
+    }
+}
+
+enum Claim {
+    /// First random component and how many consecutive ones were claimed
+    Taken(u128, u64),
+    Stale,
+    Closed,
+    Overflow,
+}
+
+/// Claim up to `max` consecutive random components from `pair`
+fn try_claim(pair: &Pair, max: u64) -> Claim {
+    let mut counter = COUNTER.load(Ordering::Acquire);
+    loop {
+        let Some(offset) = claimed(pair, counter) else {
+            return Claim::Stale;
+        };
+        if offset == CLOSED {
+            return Claim::Closed;
+        }
+        let first = pair.base + offset as u128;
+        if first >= RANDOM_END {
+            return Claim::Overflow;
+        }
+
+        let room = (RANDOM_END - first).min((CLOSED - offset) as u128) as u64;
+        let count = max.min(room);
+        let next = (pair.tag() << OFFSET_BITS) | (offset + count);
+        match COUNTER.compare_exchange_weak(counter, next, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => return Claim::Taken(first, count),
+            Err(actual) => counter = actual,
+        }
+    }
+}
+
+/// Stop further claims from `pair`, returning how many were made; the writer then
+/// publishes a replacement
+fn close(pair: &Pair) -> u64 {
+    let mut counter = COUNTER.load(Ordering::Acquire);
+    loop {
+        let offset = claimed(pair, counter).expect("only the writer publishes");
+        let closed = (pair.tag() << OFFSET_BITS) | CLOSED;
+        match COUNTER.compare_exchange_weak(counter, closed, Ordering::AcqRel, Ordering::Acquire) {
+            Ok(_) => return offset,
+            Err(actual) => counter = actual,
+        }
+    }
+}
+
+/// A fresh random base: `fresh` if given, else the writer's RNG. None when an entropy
+/// source is set and nothing was drawn from it yet, as it can't be called under the lock.
+fn fresh_base(
+    writer: &mut Writer,
+    fresh: &mut Option<u128>,
+    draw: impl FnOnce(&mut StdRng) -> u128,
+) -> Option<u128> {
+    match fresh.take() {
+        Some(base) => Some(base),
+        None if entropy::source_set() => None,
+        None => Some(draw(&mut writer.rng)),
+    }
+}
+
+/// The newest pair, publishing the first one if nothing has been generated yet (None
+/// if that needs a value from the entropy source first)
+fn initialized(writer: &mut Writer, fresh: &mut Option<u128>) -> Option<Pair> {
+    let pair = current();
+    if pair.generation != 0 {
+        return Some(pair);
+    }
+
+    let timestamp = current_timestamp_ms();
+    let base = fresh_base(writer, fresh, |rng| {
+        entropy::mode_rng(rng).random::<u128>() & Ulid::bitmask(Ulid::RAND_BITS)
+    })?;
+    logging::emit(logging::INFO, || {
+        format!("initialized generator state at timestamp {}", timestamp)
+    });
+    Some(publish(writer, timestamp, base))
+}
+
+/// The timestamp to use for `pair`'s successors, or None if the clock is too far behind
+#[inline(always)]
+fn hold(pair: &Pair, now: u64) -> Option<u64> {
+    if now >= pair.timestamp {
+        return Some(now);
+    }
+
+    // A step back within the drift tolerance holds the last timestamp instead
+    let behind = pair.timestamp - now;
+    (behind <= clock::drift_tolerance_ms()).then(|| {
+        logging::emit(logging::DEBUG, || {
+            format!(
+                "clock moved backwards by {} ms within the drift tolerance, holding \
+                 timestamp {}",
+                behind, pair.timestamp
+            )
+        });
+        pair.timestamp
+    })
+}
+
+/// Publish a fresh random base for the new millisecond `timestamp` (None if that
+/// needs a value from the entropy source first)
+fn rollover(
+    writer: &mut Writer,
+    timestamp: u64,
+    words: Option<&clean::Matcher>,
+    fresh: &mut Option<u128>,
+) -> Option<Pair> {
+    let mut base = fresh_base(writer, fresh, rollover_random)?;
+    if let Some(words) = words {
+        let rng = &mut writer.rng;
+        base = words.pick(&encode_timestamp(timestamp), base, |_, _| {
+            Some(rollover_random(rng))
+        });
+    }
+    Some(publish(writer, timestamp, base))
+}
+
+/// The slow path: claim under the writer lock, starting a new millisecond or moving
+/// the base as needed. With `words`, exactly one ULID is claimed and it spells none of
+/// them (see `Matcher::pick`).
+///
+/// Ok(None) means a new base is due while an entropy source is set: the caller draws
+/// one into `fresh` with no lock held and tries again.
+fn claim_locked(
+    max: u64,
+    words: Option<&clean::Matcher>,
+    fresh: &mut Option<u128>,
+) -> Result<Option<(u64, u128, u64)>, GenerateError> {
+    let mut writer = writer();
+    let Some(mut pair) = initialized(&mut writer, fresh) else {
+        return Ok(None);
+    };
+    loop {
+        let now = current_timestamp_ms();
+        let Some(timestamp) = hold(&pair, now) else {
+            logging::emit(logging::WARNING, || {
+                format!(
+                    "clock moved backwards by {} ms (last {}, now {})",
+                    pair.timestamp - now,
+                    pair.timestamp,
+                    now
+                )
+            });
+            return Err(GenerateError::Clock(
+                "Clock moved backwards, cannot generate ULID".to_string(),
+            ));
+        };
+
+        if timestamp > pair.timestamp {
+            let Some(next) = rollover(&mut writer, timestamp, words, fresh) else {
+                return Ok(None);
+            };
+            pair = next;
+            continue;
+        }
+
+        let (first, count) = match try_claim(&pair, if words.is_some() { 1 } else { max }) {
+            Claim::Taken(first, count) => (first, count),
+            Claim::Stale => {
+                pair = current();
+                continue;
+            }
+            Claim::Closed => {
+                // Every offset of the base is used up; carry on right after them
+                let offset = close(&pair);
+                pair = publish(&mut writer, pair.timestamp, pair.base + offset as u128);
+                continue;
+            }
+            Claim::Overflow if carry_enabled() && pair.timestamp < MAX_TIMESTAMP => {
+                let carried = pair.timestamp + 1;
+                let Some(next) = rollover(&mut writer, carried, words, fresh) else {
+                    return Ok(None);
+                };
+                logging::emit(logging::WARNING, || {
+                    format!(
+                        "random component overflow at timestamp {}, carried into {}",
+                        pair.timestamp, carried
+                    )
+                });
+                // Until the wall clock catches up, later ULIDs keep the carried timestamp
+                clock::raise_floor(carried);
+                pair = next;
+                continue;
+            }
+            Claim::Overflow => {
+                logging::emit(logging::WARNING, || {
+                    format!("random component overflow at timestamp {}", pair.timestamp)
+                });
+                return Err(GenerateError::Overflow(
+                    "Random component overflow, too many ULIDs in same millisecond".to_string(),
+                ));
+            }
+        };
+
+        let Some(words) = words else {
+            return Ok(Some((pair.timestamp, first, count)));
+        };
+        let mut rng = entropy::mode_rng(&mut writer.rng);
+        let picked = words.pick(
+            &encode_timestamp(pair.timestamp),
+            first,
+            |rejected, low_bits| {
+                // Smallest step that changes the word's last character, with fresh low
+                // bits; jumping further would waste sequence space
+                let low_mask = Ulid::bitmask(low_bits as u8);
+                let next = ((rejected >> low_bits) + 1) << low_bits;
+                (next < RANDOM_END).then(|| next | (rng.random::<u128>() & low_mask))
+            },
+        );
+        if picked == first {
+            return Ok(Some((pair.timestamp, first, 1)));
+        }
+
+        // Move the base just past the pick, unless ULIDs claimed meanwhile passed it
+        let next = pair.base + close(&pair) as u128;
+        if picked < next {
+            pair = publish(&mut writer, pair.timestamp, next);
+            continue;
+        }
+        publish(&mut writer, pair.timestamp, picked + 1);
+        return Ok(Some((pair.timestamp, picked, 1)));
+    }
+}
+
+/// `claim_locked`, drawing from the entropy source whenever it asks for a new base.
+/// The source is called with no lock held, since it takes the GIL.
+fn claim_slow(max: u64, words: Option<&clean::Matcher>) -> Result<(u64, u128, u64), ClaimError> {
+    let mut fresh = None;
+    loop {
+        match claim_locked(max, words, &mut fresh) {
+            Ok(Some(claimed)) => return Ok(claimed),
+            Ok(None) => fresh = Some(entropy::draw().map_err(ClaimError::Source)?),
+            Err(error) => return Err(ClaimError::Failed(error)),
+        }
+    }
+}
+
+/// Claim up to `max` (at least 1) consecutive ULIDs, as (timestamp, first random
+/// component, count). Lock-free unless a new millisecond starts or clean-word
+/// filtering is on.
+#[inline(always)]
+pub(crate) fn claim(max: u64) -> Result<(u64, u128, u64), ClaimError> {
+    if let Some(words) = clean::active() {
+        return claim_slow(1, Some(&words));
+    }
+
+    loop {
+        let pair = current();
+        if pair.generation == 0 {
+            break;
+        }
+        match hold(&pair, current_timestamp_ms()) {
+            Some(timestamp) if timestamp == pair.timestamp => {}
+            _ => break,
+        }
+        match try_claim(&pair, max) {
+            Claim::Taken(first, count) => return Ok((pair.timestamp, first, count)),
+            Claim::Stale => continue,
+            Claim::Closed | Claim::Overflow => break,
+        }
+    }
+    claim_slow(max, None)
+}
+
+/// Next ULID as a raw value
+#[inline(always)]
+pub(crate) fn next_value() -> Result<u128, ClaimError> {
+    let (timestamp, random, _) = claim(1)?;
+    Ok(Ulid::from_parts(timestamp, random).0)
+}
+
+/// Next ULID as a string
+#[inline(always)]
+pub(crate) fn next_string(alphabet: &[u8; 32]) -> Result<String, ClaimError> {
+    let (timestamp, random, _) = claim(1)?;
+    Ok(buffer_to_string(encode_ascii(timestamp, random, alphabet)))
+}
+
+/// Next ULID as its 26 ASCII characters, for writing into caller-owned memory
+#[inline(always)]
+pub(crate) fn next_ascii(alphabet: &[u8; 32]) -> Result<[u8; 26], ClaimError> {
+    let (timestamp, random, _) = claim(1)?;
+    Ok(encode_ascii(timestamp, random, alphabet))
+}
+
+/// Next ULID, spelling none of the blocked words of `words`
+pub(crate) fn next_string_avoiding(
+    words: &clean::Matcher,
+    alphabet: &[u8; 32],
+) -> Result<String, ClaimError> {
+    let (timestamp, random, _) = claim_slow(1, Some(words))?;
+    Ok(buffer_to_string(encode_ascii(timestamp, random, alphabet)))
+}
+
+/// Make every later ULID sort after `ulid` (if given), then return the timestamp the
+/// next one will use at the earliest
+pub(crate) fn raise_past(ulid: Option<Ulid>) -> u64 {
+    let mut writer = writer();
+    let pair = current();
+    let Some(ulid) = ulid else {
+        return match pair.generation {
+            0 => current_timestamp_ms(),
+            _ => pair.timestamp,
+        };
+    };
+    let after = (ulid.timestamp_ms(), ulid.random() + 1);
+
+    // Nothing generated yet: `ulid` is the only bound, and the first ULID draws its
+    // own base if the clock is already past it
+    if pair.generation == 0 {
+        let now = current_timestamp_ms();
+        if after.0 < now {
+            return now;
+        }
+        return publish(&mut writer, after.0, after.1).timestamp;
+    }
+
+    let next = (pair.timestamp, pair.base + close(&pair) as u128);
+    let (timestamp, base) = next.max(after);
+    publish(&mut writer, timestamp, base).timestamp
+}
+
+/// Jump the random component of `pair` forward by a random amount drawn from the
+/// writer's RNG, so a clone that continues from the same pair ends up elsewhere
+fn jump(writer: &mut Writer, pair: &Pair) -> Pair {
+    let mut next = pair.base + close(pair) as u128;
+    let room = RANDOM_END.saturating_sub(next);
+    if room > 0 {
+        next += 1 + writer.rng.random::<u64>() as u128 % room;
+    }
+    publish(writer, pair.timestamp, next)
+}
+
+/// Replace the RNG with one freshly seeded from the OS and jump the random
+/// component forward, so a restored clone diverges within the current millisecond
+pub(crate) fn reseed() -> Result<(), String> {
+    let mut writer = writer();
+    writer.rng = StdRng::try_from_os_rng()
+        .map_err(|e| format!("Failed to read OS entropy for reseed: {}", e))?;
+
+    // Before the first ULID there is no sequence to move; it starts from a fresh base
+    let pair = current();
+    let timestamp = match pair.generation {
+        0 => current_timestamp_ms(),
+        _ => jump(&mut writer, &pair).timestamp,
+    };
+
+    logging::emit(logging::INFO, || {
+        format!(
+            "reseeded generator state from OS entropy at timestamp {}",
+            timestamp
+        )
+    });
+    Ok(())
+}
+
+thread_local! {
+    // The writer lock, held by the forking thread from just before a fork until just
+    // after it, so the child never inherits it locked by a thread that isn't there
+    static FORK_GUARD: RefCell<Option<MutexGuard<'static, Writer>>> =
+        const { RefCell::new(None) };
+}
+
+/// Fork hook, before: take the writer lock
+pub(crate) fn before_fork() {
+    let guard = writer();
+    FORK_GUARD.with(|held| *held.borrow_mut() = Some(guard));
+}
+
+/// Fork hook, after in the parent: release the writer lock untouched
+pub(crate) fn after_fork_in_parent() {
+    FORK_GUARD.with(|held| held.borrow_mut().take());
+}
+
+/// Fork hook, after in the child: redraw the RNG and jump the random component like
+/// `reseed()`, then release the writer lock. The parent and every other child keep
+/// going from the same pair, so without this they would issue the same ULIDs.
+pub(crate) fn after_fork_in_child() {
+    let Some(mut writer) = FORK_GUARD.with(|held| held.borrow_mut().take()) else {
+        return;
+    };
+    writer.rng = StdRng::try_from_os_rng().unwrap_or_else(|_| {
+        // Still diverges: the thread RNG was reseeded first, and rollovers mix in
+        // OS entropy of their own
+        StdRng::from_rng(&mut rand::rng())
+    });
+
+    let pair = current();
+    let timestamp = match pair.generation {
+        0 => current_timestamp_ms(),
+        _ => jump(&mut writer, &pair).timestamp,
+    };
+
+    logging::emit(logging::INFO, || {
+        format!(
+            "reseeded generator state in forked child at timestamp {}",
+            timestamp
+        )
+    });
+}
@@ -0,0 +1,32 @@
+use pyo3::prelude::*;
+
+/// Items processed between signal checks in bulk loops
+pub(crate) const CHECK_INTERVAL: usize = 1 << 16;
+
+/// Run pending Python signal handlers (Ctrl-C) from a loop that released the GIL.
+///
+/// Retakes the GIL briefly, so it must never be called with the generator state lock
+/// held: a thread holding the GIL may be waiting for that lock.
+pub(crate) fn check() -> PyResult<()> {
+    Python::with_gil(|py| py.check_signals())
+}
+
+/// `check()` once every `CHECK_INTERVAL` items, for loops running without the GIL
+#[inline(always)]
+pub(crate) fn check_every(index: usize) -> PyResult<()> {
+    if index > 0 && index.is_multiple_of(CHECK_INTERVAL) {
+        check()
+    } else {
+        Ok(())
+    }
+}
+
+/// `check_every` for loops that already hold the GIL
+#[inline(always)]
+pub(crate) fn check_every_with_gil(py: Python<'_>, index: usize) -> PyResult<()> {
+    if index > 0 && index.is_multiple_of(CHECK_INTERVAL) {
+        py.check_signals()
+    } else {
+        Ok(())
+    }
+}
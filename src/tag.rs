@@ -0,0 +1,173 @@
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyString};
+
+use crate::{Ulid, UlidArg};
+
+const MIN_KEY_LEN: usize = 16;
+const MAX_TAG_BITS: u32 = 32;
+
+// Domain separation for deriving the two SipHash key halves from the user key
+const DERIVE_K0: (u64, u64) = (0x7079_756c_6964_7461, 0x676b_6579_3030_3030);
+const DERIVE_K1: (u64, u64) = (0x7079_756c_6964_7461, 0x676b_6579_3030_3031);
+
+/// SipHash-2-4 (Aumasson & Bernstein), a keyed PRF with a fixed, portable output
+fn siphash24(k0: u64, k1: u64, message: &[u8]) -> u64 {
+    let mut v = [
+        k0 ^ 0x736f_6d65_7073_6575,
+        k1 ^ 0x646f_7261_6e64_6f6d,
+        k0 ^ 0x6c79_6765_6e65_7261,
+        k1 ^ 0x7465_6462_7974_6573,
+    ];
+    let round = |v: &mut [u64; 4]| {
+        v[0] = v[0].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(13) ^ v[0];
+        v[0] = v[0].rotate_left(32);
+        v[2] = v[2].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(16) ^ v[2];
+        v[0] = v[0].wrapping_add(v[3]);
+        v[3] = v[3].rotate_left(21) ^ v[0];
+        v[2] = v[2].wrapping_add(v[1]);
+        v[1] = v[1].rotate_left(17) ^ v[2];
+        v[2] = v[2].rotate_left(32);
+    };
+    let compress = |v: &mut [u64; 4], m: u64| {
+        v[3] ^= m;
+        round(v);
+        round(v);
+        v[0] ^= m;
+    };
+
+    let mut chunks = message.chunks_exact(8);
+    for chunk in &mut chunks {
+        compress(&mut v, u64::from_le_bytes(chunk.try_into().unwrap()));
+    }
+    let mut last = [0u8; 8];
+    last[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
+    last[7] = message.len() as u8;
+    compress(&mut v, u64::from_le_bytes(last));
+
+    v[2] ^= 0xFF;
+    for _ in 0..4 {
+        round(&mut v);
+    }
+    v[0] ^ v[1] ^ v[2] ^ v[3]
+}
+
+/// A tag key: at least 16 bytes, given as bytes or a str (UTF-8 encoded)
+pub(crate) struct TagKey(u64, u64);
+
+impl<'py> FromPyObject<'py> for TagKey {
+    fn extract_bound(obj: &Bound<'py, PyAny>) -> PyResult<Self> {
+        let string;
+        let bytes = if let Ok(b) = obj.downcast::<PyBytes>() {
+            b.as_bytes()
+        } else if let Ok(s) = obj.downcast::<PyString>() {
+            string = s.to_cow()?;
+            string.as_bytes()
+        } else {
+            return Err(PyTypeError::new_err(format!(
+                "key must be bytes or str, got {}",
+                obj.get_type().name()?
+            )));
+        };
+
+        if bytes.len() < MIN_KEY_LEN {
+            return Err(PyValueError::new_err(format!(
+                "key must be at least {} bytes, got {}",
+                MIN_KEY_LEN,
+                bytes.len()
+            )));
+        }
+        Ok(TagKey(
+            siphash24(DERIVE_K0.0, DERIVE_K0.1, bytes),
+            siphash24(DERIVE_K1.0, DERIVE_K1.1, bytes),
+        ))
+    }
+}
+
+impl TagKey {
+    /// Word `counter` of the keystream for `timestamp`
+    fn stream(&self, timestamp: u64, counter: u64) -> u64 {
+        let mut message = [0u8; 16];
+        message[..8].copy_from_slice(&timestamp.to_le_bytes());
+        message[8..].copy_from_slice(&counter.to_le_bytes());
+        siphash24(self.0, self.1, &message)
+    }
+
+    /// The random-component bit positions carrying the tag and the pad they're XORed with
+    fn layout(&self, timestamp: u64, tag_bits: u32) -> (Vec<u32>, u64) {
+        // Partial Fisher-Yates over the 80 positions; the modulo bias is below 2**-57
+        let mut positions: Vec<u32> = (0..Ulid::RAND_BITS as u32).collect();
+        for i in 0..tag_bits as usize {
+            let remaining = (positions.len() - i) as u64;
+            let j = i + (self.stream(timestamp, i as u64 + 1) % remaining) as usize;
+            positions.swap(i, j);
+        }
+        positions.truncate(tag_bits as usize);
+        let pad = self.stream(timestamp, 0) & ((1u64 << tag_bits) - 1);
+        (positions, pad)
+    }
+}
+
+fn check_tag_bits(tag_bits: u32) -> PyResult<()> {
+    if tag_bits == 0 || tag_bits > MAX_TAG_BITS {
+        return Err(PyValueError::new_err(format!(
+            "tag_bits must be in range 1..={}, got {}",
+            MAX_TAG_BITS, tag_bits
+        )));
+    }
+    Ok(())
+}
+
+/// Hide `tag` in the random component of a ULID, recoverable only with `key`.
+///
+/// A SipHash-2-4 PRF of the key and the ULID's timestamp picks `tag_bits` of the 80
+/// random bit positions and a pad; those bits are overwritten with `tag XOR pad`. The
+/// timestamp is untouched, so the result is a valid ULID that sorts into the same
+/// millisecond, though not necessarily in its original place within it.
+///
+/// Threat model: to anyone without the key the tagged bits are PRF output, so a tagged
+/// ULID looks like any other. The tag is not authenticated (extracting from an
+/// untagged ULID returns an arbitrary value) and anyone can destroy it by changing the
+/// random bits. Tagged IDs from the same millisecond with the same tag share those bits,
+/// which an observer with many such IDs could notice. Use a key of at least 16 random
+/// bytes and keep it secret: it reveals the tag of every ID ever embedded with it.
+#[pyfunction]
+#[pyo3(signature = (ulid_str, tag, key, tag_bits = 8))]
+pub fn ulid_embed_tag(ulid_str: UlidArg, tag: i64, key: TagKey, tag_bits: u32) -> PyResult<String> {
+    check_tag_bits(tag_bits)?;
+    if tag < 0 || tag >= 1i64 << tag_bits {
+        return Err(PyValueError::new_err(format!(
+            "tag must be in range 0 <= tag < 2**{}, got {}",
+            tag_bits, tag
+        )));
+    }
+
+    let ulid = ulid_str.0;
+    let (positions, pad) = key.layout(ulid.timestamp_ms(), tag_bits);
+    let hidden = tag as u64 ^ pad;
+    let mut value = ulid.0;
+    for (i, &position) in positions.iter().enumerate() {
+        value &= !(1u128 << position);
+        value |= (((hidden >> i) & 1) as u128) << position;
+    }
+    Ok(Ulid(value).to_string())
+}
+
+/// Recover the tag `ulid_embed_tag` hid in a ULID with the same `key` and `tag_bits`
+#[pyfunction]
+#[pyo3(signature = (ulid_str, key, tag_bits = 8))]
+pub fn ulid_extract_tag(ulid_str: UlidArg, key: TagKey, tag_bits: u32) -> PyResult<u64> {
+    check_tag_bits(tag_bits)?;
+
+    let ulid = ulid_str.0;
+    let (positions, pad) = key.layout(ulid.timestamp_ms(), tag_bits);
+    let hidden = positions
+        .iter()
+        .enumerate()
+        .fold(0u64, |acc, (i, &position)| {
+            acc | (((ulid.0 >> position) & 1) as u64) << i
+        });
+    Ok(hidden ^ pad)
+}
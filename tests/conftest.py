@@ -2,9 +2,22 @@
 Pytest configuration and fixtures for PyULID tests.
 """
 
+import os
+import sys
+
 import pytest
 import pyulid
 
+# PYULID_DECODE_CACHE=N runs the whole suite with the decode cache on; results must
+# not change
+if os.environ.get("PYULID_DECODE_CACHE"):
+    pyulid.configure(decode_cache=int(os.environ["PYULID_DECODE_CACHE"]))
+
+# `match` statements are a syntax error before Python 3.10
+collect_ignore = []
+if sys.version_info < (3, 10):
+    collect_ignore.append("test_pattern_matching.py")
+
 
 @pytest.fixture
 def sample_ulid():
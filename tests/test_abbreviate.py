@@ -0,0 +1,216 @@
+"""
+Abbreviation tests for PyULID.
+
+Tests ulid_abbreviate, ulid_expand and ulid_closest.
+"""
+
+import random
+
+import pytest
+import pyulid
+
+
+def _is_unique_prefix(prefix, ulids):
+    return sum(1 for u in set(ulids) if u.startswith(prefix)) == 1
+
+
+class TestAbbreviate:
+    """Test ulid_abbreviate."""
+
+    def test_aligned_and_unique(self):
+        """Test that prefixes are aligned with the input and unique."""
+        ulids = [pyulid.ulid() for _ in range(2000)]
+        prefixes = pyulid.ulid_abbreviate(ulids)
+
+        assert len(prefixes) == len(ulids)
+        for ulid_str, prefix in zip(ulids, prefixes):
+            assert ulid_str.startswith(prefix)
+            assert len(prefix) >= 6
+            assert _is_unique_prefix(prefix, ulids)
+
+    def test_shortest(self):
+        """Test that one character fewer would be ambiguous (above min_len)."""
+        ulids = [pyulid.ulid() for _ in range(500)]
+        prefixes = pyulid.ulid_abbreviate(ulids, min_len=1)
+
+        for prefix in prefixes:
+            assert _is_unique_prefix(prefix, ulids)
+            if len(prefix) > 1:
+                assert not _is_unique_prefix(prefix[:-1], ulids)
+
+    def test_known_values(self):
+        """Test prefixes for a hand-picked collection."""
+        ulids = [
+            "01ARZ3NDEKTSV4RRFFQ69G5FAV",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAW",
+            "01BX5ZZKBKACTAV9WEVGEMMVRZ",
+        ]
+
+        assert pyulid.ulid_abbreviate(ulids, min_len=2) == [
+            "01ARZ3NDEKTSV4RRFFQ69G5FAV",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAW",
+            "01B",
+        ]
+        assert pyulid.ulid_abbreviate(ulids[2:], min_len=4) == ["01BX"]
+
+    def test_duplicates_keep_full_length(self):
+        """Test that duplicated ULIDs keep all 26 characters."""
+        ulid_str = pyulid.ulid()
+        assert pyulid.ulid_abbreviate([ulid_str, ulid_str]) == [ulid_str, ulid_str]
+
+    def test_accepts_ulid_objects_and_lowercase(self):
+        """Test that prefixes come from the canonical form."""
+        obj = pyulid.ULID()
+        prefixes = pyulid.ulid_abbreviate([obj, str(obj).lower()], min_len=3)
+        assert prefixes == [str(obj), str(obj)]
+
+    def test_empty_and_single(self):
+        """Test empty input and a single ULID."""
+        assert pyulid.ulid_abbreviate([]) == []
+        ulid_str = pyulid.ulid()
+        assert pyulid.ulid_abbreviate([ulid_str]) == [ulid_str[:6]]
+
+    def test_invalid_arguments(self):
+        """Test min_len validation and invalid items."""
+        with pytest.raises(ValueError):
+            pyulid.ulid_abbreviate([pyulid.ulid()], min_len=0)
+        with pytest.raises(ValueError):
+            pyulid.ulid_abbreviate([pyulid.ulid()], min_len=27)
+        with pytest.raises(ValueError):
+            pyulid.ulid_abbreviate(["invalid"])
+
+
+class TestExpand:
+    """Test ulid_expand."""
+
+    def test_round_trip(self):
+        """Test that every abbreviation expands back to its ULID."""
+        ulids = [pyulid.ulid() for _ in range(1000)]
+        for ulid_str, prefix in zip(ulids, pyulid.ulid_abbreviate(ulids)):
+            assert pyulid.ulid_expand(prefix, ulids) == ulid_str
+            assert pyulid.ulid_expand(prefix.lower(), ulids) == ulid_str
+
+    def test_ambiguous(self):
+        """Test that an ambiguous prefix lists its candidates."""
+        ulids = [
+            "01ARZ3NDEKTSV4RRFFQ69G5FAV",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAW",
+            "01BX5ZZKBKACTAV9WEVGEMMVRZ",
+        ]
+
+        with pytest.raises(pyulid.AmbiguousPrefixError) as exc_info:
+            pyulid.ulid_expand("01ARZ", ulids)
+
+        assert exc_info.value.candidates == ulids[:2]
+        assert ulids[0] in str(exc_info.value)
+        assert ulids[1] in str(exc_info.value)
+        assert isinstance(exc_info.value, ValueError)
+
+    def test_duplicates_are_not_ambiguous(self):
+        """Test that repeated copies of one ULID resolve to it."""
+        ulid_str = pyulid.ulid()
+        assert pyulid.ulid_expand(ulid_str[:6], [ulid_str, ulid_str]) == ulid_str
+
+    def test_no_match(self):
+        """Test that an unknown prefix raises KeyError."""
+        with pytest.raises(KeyError):
+            pyulid.ulid_expand("ZZZZZZ", [pyulid.ulid()])
+        with pytest.raises(KeyError):
+            pyulid.ulid_expand("01ARZ", [])
+
+
+def _levenshtein(a, b):
+    prev = list(range(len(b) + 1))
+    for i, ca in enumerate(a, 1):
+        cur = [i]
+        for j, cb in enumerate(b, 1):
+            cur.append(min(prev[j - 1] + (ca != cb), prev[j] + 1, cur[j - 1] + 1))
+        prev = cur
+    return prev[-1]
+
+
+def _typo(ulid_str, edits):
+    chars = list(ulid_str)
+    for _ in range(edits):
+        op = random.choice(["sub", "ins", "del"])
+        pos = random.randrange(len(chars))
+        if op == "sub":
+            chars[pos] = random.choice("0123456789ABCDEFGHJKMNPQRSTVWXYZ")
+        elif op == "ins":
+            chars.insert(pos, random.choice("0123456789ABCDEFGHJKMNPQRSTVWXYZ"))
+        else:
+            del chars[pos]
+    return "".join(chars)
+
+
+class TestClosest:
+    """Test ulid_closest."""
+
+    def test_exact_and_typo(self):
+        """Test exact matches and single-character typos."""
+        ulids = [pyulid.ulid_from_int(random.getrandbits(128)) for _ in range(1000)]
+        target = ulids[123]
+
+        assert pyulid.ulid_closest(target, ulids) == (target, 0)
+        typo = target[:20] + ("0" if target[20] != "0" else "1") + target[21:]
+        assert pyulid.ulid_closest(typo, ulids) == (target, 1)
+        assert pyulid.ulid_closest(target[:-1], ulids) == (target, 1)
+        assert pyulid.ulid_closest(target + "X", ulids) == (target, 1)
+
+    def test_normalization(self):
+        """Test lowercase input and Crockford substitutions."""
+        ulids = ["01ARZ3NDEKTSV4RRFFQ69G5FAV", "01BX5ZZKBKACTAV9WEVGEMMVRZ"]
+
+        lower = "o1arz3ndektsv4rrffq69g5fav"
+        assert pyulid.ulid_closest(lower, ulids) == (ulids[0], 0)
+        substituted = "OLBX5ZZKBKACTAV9WEVGEMMVRZ"
+        assert pyulid.ulid_closest(substituted, ulids) == (ulids[1], 0)
+
+    def test_none_when_too_far(self):
+        """Test that None is returned beyond max_distance."""
+        ulids = ["01ARZ3NDEKTSV4RRFFQ69G5FAV"]
+
+        far = "01ARZ3NDEKTSV4RRFFQ69GXXXX"
+        assert pyulid.ulid_closest(far, ulids) is None
+        assert pyulid.ulid_closest(far, ulids, max_distance=4) == (ulids[0], 4)
+        assert pyulid.ulid_closest("anything", []) is None
+
+    def test_ties_sorted(self):
+        """Test that ties are returned as a sorted, de-duplicated list."""
+        ulids = [
+            "01ARZ3NDEKTSV4RRFFQ69G5FAW",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAX",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAW",
+        ]
+
+        result = pyulid.ulid_closest("01ARZ3NDEKTSV4RRFFQ69G5FAV", ulids)
+        assert result == (sorted(set(ulids)), 1)
+
+    def test_matches_reference_distance(self):
+        """Test the banded distance against a full Levenshtein computation."""
+        ulids = [pyulid.ulid_from_int(random.getrandbits(128)) for _ in range(300)]
+
+        for _ in range(200):
+            typo = _typo(random.choice(ulids), random.randint(0, 3))
+            distances = {u: _levenshtein(typo, u) for u in ulids}
+            best = min(distances.values())
+            expected = sorted(u for u, d in distances.items() if d == best)
+
+            for max_distance in range(4):
+                result = pyulid.ulid_closest(typo, ulids, max_distance=max_distance)
+                if best > max_distance:
+                    assert result is None
+                elif len(expected) == 1:
+                    assert result == (expected[0], best)
+                else:
+                    assert result == (expected, best)
+
+    def test_accepts_ulid_array(self):
+        """Test the UlidArray fast path."""
+        ulids = [pyulid.ulid() for _ in range(100)]
+        array = pyulid.UlidArray(ulids)
+        assert pyulid.ulid_closest(ulids[5], array) == (ulids[5], 0)
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
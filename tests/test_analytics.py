@@ -0,0 +1,222 @@
+"""
+Analytics helper tests for PyULID.
+
+Tests time-based grouping and sampling of ULID collections.
+"""
+
+import random
+
+import pytest
+import pyulid
+
+MINUTE = 60_000
+
+
+def _at(*timestamps):
+    return [pyulid.ulid_with_timestamp(ts) for ts in timestamps]
+
+
+class TestSessionize:
+    """Test ulid_sessionize."""
+
+    def test_splits_on_gaps(self):
+        """Test that gaps of at least gap_ms start a new session."""
+        base = 1672531200000
+        ulids = _at(
+            base,
+            base + 10 * MINUTE,
+            base + 39 * MINUTE,  # 29 minutes later: same session
+            base + 69 * MINUTE,  # exactly 30 minutes: new session
+            base + 200 * MINUTE,
+        )
+
+        sessions = pyulid.ulid_sessionize(ulids, 30 * MINUTE)
+        assert sessions == [ulids[:3], ulids[3:4], ulids[4:]]
+        assert pyulid.ulid_sessionize(ulids, 30 * MINUTE, indices=True) == [0, 3, 4]
+
+    def test_unsorted_input(self):
+        """Test that input is sorted before grouping."""
+        ulids = _at(0, 1, 5000, 5001)
+        shuffled = ulids[:]
+        random.shuffle(shuffled)
+
+        assert pyulid.ulid_sessionize(shuffled, 1000) == [ulids[:2], ulids[2:]]
+
+    def test_same_millisecond_stays_together(self):
+        """Test that monotonic ULIDs from one burst form one session."""
+        ulids = [pyulid.ulid() for _ in range(1000)]
+        assert pyulid.ulid_sessionize(ulids, 1) == [
+            ulids[start:end]
+            for start, end in _runs([pyulid.ulid_timestamp(u) for u in ulids])
+        ]
+        assert pyulid.ulid_sessionize(ulids, 60 * MINUTE) == [ulids]
+
+    def test_matches_reference(self):
+        """Test against a straightforward Python implementation."""
+        timestamps = sorted(random.randint(0, 10_000) for _ in range(500))
+        ulids = sorted(_at(*timestamps))
+
+        expected = [[ulids[0]]]
+        for prev, cur in zip(ulids, ulids[1:]):
+            gap = pyulid.ulid_timestamp(cur) - pyulid.ulid_timestamp(prev)
+            if gap >= 50:
+                expected.append([])
+            expected[-1].append(cur)
+
+        assert pyulid.ulid_sessionize(ulids, 50) == expected
+
+    def test_mixed_inputs(self):
+        """Test ULID objects, lowercase strings and UlidArray."""
+        ulids = _at(0, 10_000)
+        mixed = [pyulid.ULID(ulids[0]), ulids[1].lower()]
+
+        assert pyulid.ulid_sessionize(mixed, 1000) == [[ulids[0]], [ulids[1]]]
+        array = pyulid.UlidArray(ulids)
+        assert pyulid.ulid_sessionize(array, 1000, indices=True) == [0, 1]
+
+    def test_empty(self):
+        """Test that empty input returns an empty list."""
+        assert pyulid.ulid_sessionize([], 1000) == []
+        assert pyulid.ulid_sessionize([], 1000, indices=True) == []
+
+    def test_invalid(self):
+        """Test invalid elements and gap values."""
+        with pytest.raises(ValueError, match="index 1"):
+            pyulid.ulid_sessionize([pyulid.ulid(), "invalid"], 1000)
+        with pytest.raises(pyulid.UlidDecodeError, match="index 1.*0-7"):
+            pyulid.ulid_sessionize([pyulid.ulid(), "Z" * 26], 1000)
+        with pytest.raises(ValueError):
+            pyulid.ulid_sessionize([pyulid.ulid()], 0)
+        with pytest.raises(ValueError):
+            pyulid.ulid_sessionize([pyulid.ulid()], -5)
+
+
+class TestSample:
+    """Test ulid_sample."""
+
+    def test_uniform(self):
+        """Test that a uniform sample is a sorted subset of the input."""
+        ulids = [pyulid.ulid() for _ in range(1000)]
+        sample = pyulid.ulid_sample(ulids, 50)
+
+        assert len(sample) == 50
+        assert sample == sorted(sample)
+        assert set(sample) <= set(ulids)
+
+    def test_seed_reproducible(self):
+        """Test that the same seed gives the same sample."""
+        ulids = [pyulid.ulid() for _ in range(1000)]
+        assert pyulid.ulid_sample(ulids, 20, seed=7) == pyulid.ulid_sample(
+            ulids, 20, seed=7
+        )
+        assert pyulid.ulid_sample(ulids, 20, seed=7) != pyulid.ulid_sample(
+            ulids, 20, seed=8
+        )
+
+        kwargs = {"method": "stratified", "seed": 3, "bucket_ms": 100}
+        spread = _at(*range(0, 1000, 2))
+        assert pyulid.ulid_sample(spread, 30, **kwargs) == pyulid.ulid_sample(
+            spread, 30, **kwargs
+        )
+
+    def test_uniform_is_unbiased(self):
+        """Test that every element is picked at roughly the same rate."""
+        ulids = [pyulid.ulid() for _ in range(10)]
+        hits = dict.fromkeys(ulids, 0)
+        for seed in range(2000):
+            for u in pyulid.ulid_sample(ulids, 3, seed=seed):
+                hits[u] += 1
+
+        # Expected 600 each; allow a generous margin
+        assert all(450 < n < 750 for n in hits.values())
+
+    def test_k_larger_than_population(self):
+        """Test that k beyond the population returns everything."""
+        ulids = _at(5, 1, 3)
+        assert pyulid.ulid_sample(ulids, 10) == sorted(ulids)
+        assert pyulid.ulid_sample(
+            ulids, 10, method="stratified", bucket_ms=2
+        ) == sorted(ulids)
+        assert pyulid.ulid_sample(ulids, 0) == []
+        assert pyulid.ulid_sample([], 5) == []
+
+    def test_stratified_proportional(self):
+        """Test that buckets are represented in proportion to their size."""
+        ulids = _at(*([0] * 600 + [MINUTE] * 300 + [2 * MINUTE] * 100))
+        sample = pyulid.ulid_sample(
+            ulids, 100, method="stratified", bucket_ms=MINUTE, seed=1
+        )
+
+        counts = [0, 0, 0]
+        for u in sample:
+            counts[pyulid.ulid_timestamp(u) // MINUTE] += 1
+        assert counts == [60, 30, 10]
+
+    def test_stratified_per_bucket(self):
+        """Test that per_bucket takes evenly from every bucket."""
+        ulids = _at(*([0] * 600 + [MINUTE] * 300 + [2 * MINUTE] * 2))
+        sample = pyulid.ulid_sample(
+            ulids, 100, method="stratified", bucket_ms=MINUTE, per_bucket=10
+        )
+        counts = [0, 0, 0]
+        for u in sample:
+            counts[pyulid.ulid_timestamp(u) // MINUTE] += 1
+        assert counts == [10, 10, 2]
+
+        # When k is the tighter limit the buckets are levelled evenly
+        sample = pyulid.ulid_sample(
+            ulids, 9, method="stratified", bucket_ms=MINUTE, per_bucket=10
+        )
+        counts = [0, 0, 0]
+        for u in sample:
+            counts[pyulid.ulid_timestamp(u) // MINUTE] += 1
+        assert counts == [4, 3, 2]
+
+    def test_mixed_inputs(self):
+        """Test ULID objects and UlidArray input."""
+        ulids = [pyulid.ulid() for _ in range(100)]
+        array = pyulid.UlidArray(ulids)
+        objects = [pyulid.ULID(u) for u in ulids]
+
+        assert pyulid.ulid_sample(array, 10, seed=1) == pyulid.ulid_sample(
+            objects, 10, seed=1
+        )
+
+    def test_streams_iterators(self):
+        """Test that a one-shot iterator samples like the list it came from."""
+        ulids = [pyulid.ulid() for _ in range(10000)]
+        for kwargs in [{}, {"method": "stratified", "bucket_ms": 1}]:
+            stream = iter(ulids)
+            sample = pyulid.ulid_sample(stream, 10, seed=3, **kwargs)
+            assert sample == pyulid.ulid_sample(ulids, 10, seed=3, **kwargs)
+            assert next(stream, None) is None
+
+    def test_invalid(self):
+        """Test argument validation."""
+        ulids = [pyulid.ulid()]
+        with pytest.raises(ValueError, match="method"):
+            pyulid.ulid_sample(ulids, 1, method="systematic")
+        with pytest.raises(ValueError, match="bucket_ms"):
+            pyulid.ulid_sample(ulids, 1, method="stratified")
+        with pytest.raises(ValueError):
+            pyulid.ulid_sample(ulids, 1, method="stratified", bucket_ms=0)
+        with pytest.raises(ValueError):
+            pyulid.ulid_sample(ulids, 1, bucket_ms=1000)
+        with pytest.raises(ValueError, match="index 1"):
+            pyulid.ulid_sample([pyulid.ulid(), "invalid"], 1)
+        with pytest.raises(pyulid.UlidDecodeError, match="index 1.*0-7"):
+            pyulid.ulid_sample([pyulid.ulid(), "Z" * 26], 1)
+        with pytest.raises(OverflowError):
+            pyulid.ulid_sample(ulids, -1)
+
+
+def _runs(timestamps):
+    start = 0
+    for i in range(1, len(timestamps) + 1):
+        if i == len(timestamps) or timestamps[i] != timestamps[i - 1]:
+            yield start, i
+            start = i
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
@@ -0,0 +1,219 @@
+"""
+UlidArray container tests for PyULID.
+
+Tests construction, sequence behavior, serialization and the buffer protocol.
+"""
+
+import os
+import sys
+
+import pytest
+import pyulid
+
+
+class TestArrayConstruction:
+    """Test the ways to build a UlidArray."""
+
+    def test_batch_array(self):
+        """Test ulid_batch_array produces n valid, sorted, unique ULIDs."""
+        arr = pyulid.ulid_batch_array(1000)
+        values = arr.to_list()
+
+        assert len(arr) == 1000
+        assert all(pyulid.ulid_is_valid(u) for u in values)
+        assert values == sorted(values)
+        assert len(set(values)) == 1000
+
+    def test_batch_array_continues_global_sequence(self):
+        """Test that batches stay monotonic with ulid()."""
+        before = pyulid.ulid()
+        arr = pyulid.ulid_batch_array(10)
+        after = pyulid.ulid()
+        assert before < arr[0] < arr[-1] < after
+
+    def test_from_strings(self):
+        """Test construction from str, bytes and ULID objects."""
+        ulids = [pyulid.ulid() for _ in range(10)]
+        mixed = [ulids[0].lower(), ulids[1].encode(), pyulid.ULID(ulids[2])] + ulids[3:]
+
+        arr = pyulid.UlidArray(mixed)
+        assert arr.to_list() == ulids
+        assert len(pyulid.UlidArray()) == 0
+
+    def test_invalid_strings(self):
+        """Test that invalid entries raise with their index."""
+        with pytest.raises(ValueError, match="index 1"):
+            pyulid.UlidArray([pyulid.ulid(), "short"])
+        with pytest.raises(ValueError, match="index 2"):
+            pyulid.UlidArray([pyulid.ulid(), pyulid.ulid(), "I" * 26])
+        with pytest.raises(TypeError):
+            pyulid.UlidArray([pyulid.ulid(), 42])
+
+    def test_overflowing_strings(self):
+        """Test that values above 128 bits raise instead of wrapping."""
+        for leading in "8Zz":
+            with pytest.raises(pyulid.UlidDecodeError, match="index 1.*0-7"):
+                pyulid.UlidArray([pyulid.ulid(), leading + "Z" * 25])
+        with pytest.raises(pyulid.UlidDecodeError, match="index 0"):
+            pyulid.UlidArray([b"8" + b"0" * 25])
+        assert pyulid.UlidArray(["7" + "Z" * 25]).to_list() == ["7" + "Z" * 25]
+
+    def test_bytes_round_trip(self):
+        """Test to_bytes/from_bytes round trip with big-endian records."""
+        arr = pyulid.ulid_batch_array(100)
+        blob = arr.to_bytes()
+
+        assert len(blob) == 1600
+        assert blob[:16] == pyulid.ulid_to_int(arr[0]).to_bytes(16, "big")
+        assert pyulid.UlidArray.from_bytes(blob) == arr
+
+        with pytest.raises(ValueError):
+            pyulid.UlidArray.from_bytes(b"\x00" * 17)
+
+    def test_large_construction(self):
+        """Test building from a large list of strings."""
+        ulids = [pyulid.ulid() for _ in range(100000)]
+        arr = pyulid.UlidArray(ulids)
+        assert len(arr) == 100000
+        assert arr[-1] == ulids[-1]
+
+
+class TestArraySequence:
+    """Test sequence behavior."""
+
+    def test_indexing(self):
+        """Test positive and negative indices."""
+        ulids = [pyulid.ulid() for _ in range(5)]
+        arr = pyulid.UlidArray(ulids)
+
+        assert arr[0] == ulids[0]
+        assert arr[-1] == ulids[-1]
+        with pytest.raises(IndexError):
+            arr[5]
+        with pytest.raises(IndexError):
+            arr[-6]
+        with pytest.raises(TypeError):
+            arr["0"]
+
+    def test_slicing(self):
+        """Test that slices match list slicing and return UlidArray."""
+        ulids = [pyulid.ulid() for _ in range(20)]
+        arr = pyulid.UlidArray(ulids)
+
+        slices = [slice(2, 8), slice(None, None, -1), slice(1, None, 3), slice(50, 60)]
+        for sl in slices:
+            sliced = arr[sl]
+            assert isinstance(sliced, pyulid.UlidArray)
+            assert sliced.to_list() == ulids[sl]
+
+    def test_iteration_and_equality(self):
+        """Test iteration order and equality."""
+        ulids = [pyulid.ulid() for _ in range(10)]
+        arr = pyulid.UlidArray(ulids)
+
+        assert list(arr) == ulids
+        assert arr == pyulid.UlidArray(ulids)
+        assert arr != pyulid.UlidArray(ulids[:-1])
+        assert arr != ulids
+
+    def test_append_extend(self):
+        """Test append and extend."""
+        arr = pyulid.UlidArray()
+        first = pyulid.ulid()
+        arr.append(first)
+        arr.append(pyulid.ULID())
+        arr.extend([pyulid.ulid() for _ in range(3)])
+        arr.extend(pyulid.ulid_batch_array(2))
+
+        assert len(arr) == 7
+        assert arr[0] == first
+
+        with pytest.raises(ValueError):
+            arr.append("invalid")
+        assert len(arr) == 7
+
+    def test_memory_per_element(self):
+        """Test that each element costs exactly 16 bytes."""
+        # __sizeof__ directly: sys.getsizeof is unavailable on PyPy
+        empty = pyulid.UlidArray().__sizeof__()
+        for n in [1, 1000, 100000]:
+            assert pyulid.ulid_batch_array(n).__sizeof__() - empty == 16 * n
+
+
+def _has_buffer_protocol():
+    # Only forbid-unsafe builds can't export buffers
+    try:
+        memoryview(pyulid.UlidArray()).release()
+        return True
+    except TypeError:
+        return False
+
+
+class TestArrayBufferAvailability:
+    """Test which builds export buffers."""
+
+    def test_every_build_but_forbid_unsafe(self):
+        """Test that only the forbid-unsafe build lacks the buffer protocol."""
+        # CI sets PYULID_FORBID_UNSAFE for its forbid-unsafe run
+        expected = not os.environ.get("PYULID_FORBID_UNSAFE")
+        assert _has_buffer_protocol() == expected
+
+    def test_to_memoryview(self):
+        """Test that to_memoryview gives the native records in every build."""
+        arr = pyulid.ulid_batch_array(10)
+        view = arr.to_memoryview()
+
+        assert view.readonly
+        assert view.nbytes == 160
+        raw = view.tobytes()
+        for i in range(10):
+            record = int.from_bytes(raw[i * 16 : (i + 1) * 16], sys.byteorder)
+            assert record == pyulid.ulid_to_int(arr[i])
+        if _has_buffer_protocol():
+            assert raw == memoryview(arr).tobytes()
+        view.release()
+
+
+@pytest.mark.skipif(
+    not _has_buffer_protocol(), reason="no buffer protocol in this build"
+)
+class TestArrayBuffer:
+    """Test the buffer protocol."""
+
+    def test_memoryview(self):
+        """Test that the buffer exposes 16 native-endian bytes per record."""
+        arr = pyulid.ulid_batch_array(10)
+        view = memoryview(arr)
+
+        assert view.readonly
+        assert view.nbytes == 160
+        raw = view.tobytes()
+        for i in range(10):
+            record = int.from_bytes(raw[i * 16 : (i + 1) * 16], sys.byteorder)
+            assert record == pyulid.ulid_to_int(arr[i])
+        view.release()
+
+    def test_resize_blocked_while_exported(self):
+        """Test that append/extend fail while a buffer is held."""
+        arr = pyulid.ulid_batch_array(3)
+        view = memoryview(arr)
+
+        with pytest.raises(BufferError):
+            arr.append(pyulid.ulid())
+        with pytest.raises(BufferError):
+            arr.extend([pyulid.ulid()])
+
+        view.release()
+        arr.append(pyulid.ulid())
+        assert len(arr) == 4
+
+    def test_numpy_view(self):
+        """Test zero-copy access from NumPy."""
+        np = pytest.importorskip("numpy")
+        arr = pyulid.ulid_batch_array(10)
+        data = np.frombuffer(arr, dtype=np.uint8).reshape(-1, 16)
+        assert data.shape == (10, 16)
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
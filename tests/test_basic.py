@@ -6,7 +6,9 @@ Tests ULID generation, validation, and basic operations.
 
 import pytest
 import pyulid
-from datetime import datetime
+from datetime import datetime, timedelta, timezone
+import functools
+import random
 import time
 
 
@@ -26,6 +28,34 @@ class TestBasicGeneration:
         valid_chars = set("0123456789ABCDEFGHJKMNPQRSTVWXYZ")
         assert all(c in valid_chars for c in ulid_str)
 
+    def test_matches_canonical_encoding(self):
+        """Test that the generator's fast string path matches the shared encoder."""
+        for ulid_str in [pyulid.ulid() for _ in range(1000)]:
+            assert ulid_str == pyulid.ulid_from_int(pyulid.ulid_to_int(ulid_str))
+            assert len(ulid_str.encode()) == 26
+
+    def test_direct_str_paths_match_canonical_encoding(self):
+        """Test that every function building its str from the stack buffer agrees."""
+        dt = datetime(2023, 1, 1, tzinfo=timezone.utc)
+        generators = [
+            pyulid.ulid,
+            lambda: pyulid.ulid(lowercase=True).upper(),
+            lambda: pyulid.ulid_with_timestamp(1672531200123),
+            lambda: pyulid.ulid_with_timestamp(2**48 - 1),
+            lambda: pyulid.ulid_with_timestamp(0, lowercase=True).upper(),
+            lambda: pyulid.ulid_at(1672531200.5),
+            lambda: pyulid.ulid_from_datetime(dt),
+            lambda: pyulid.ulid_from_iso8601("2023-01-01T00:00:00Z"),
+            pyulid.ulid_non_monotonic,
+        ]
+        for generate in generators:
+            for _ in range(200):
+                ulid_str = generate()
+                assert type(ulid_str) is str
+                expected = pyulid.encode_base32(pyulid.ulid_to_int(ulid_str))
+                assert ulid_str == expected
+                assert ulid_str.isascii() and len(ulid_str) == 26
+
     def test_ulid_uniqueness(self):
         """Test that generated ULIDs are unique."""
         ulids = set()
@@ -47,6 +77,153 @@ class TestBasicGeneration:
             assert pyulid.ulid_is_valid(ulid_str)
 
 
+class TestLowercase:
+    """Test lowercase=True output."""
+
+    def test_ulid(self):
+        """Test that ulid(lowercase=True) is lowercase Crockford Base32."""
+        ulids = [pyulid.ulid(lowercase=True) for _ in range(1000)]
+        valid_chars = set("0123456789abcdefghjkmnpqrstvwxyz")
+        for ulid_str in ulids:
+            assert len(ulid_str) == 26
+            assert set(ulid_str) <= valid_chars
+            assert pyulid.ulid_is_valid(ulid_str)
+        # Still monotonic, and sharing the sequence with uppercase ULIDs
+        upper = pyulid.ulid()
+        assert ulids == sorted(ulids)
+        assert ulids[-1].upper() < upper
+
+    def test_ulid_with_timestamp(self):
+        """Test that the lowercase twin decodes identically."""
+        ulid_str = pyulid.ulid_with_timestamp(1672531200123, lowercase=True)
+        assert ulid_str == ulid_str.lower()
+        upper = ulid_str.upper()
+        assert pyulid.ulid_timestamp(ulid_str) == 1672531200123
+        assert pyulid.ulid_to_int(ulid_str) == pyulid.ulid_to_int(upper)
+        assert pyulid.ulid_random(ulid_str) == pyulid.ulid_random(upper)
+        assert pyulid.ulid_to_uuid(ulid_str) == pyulid.ulid_to_uuid(upper)
+        assert pyulid.ulid_with_timestamp(1672531200123).isupper()
+
+    def test_ulid_from_str(self):
+        """Test normalizing either case to lowercase or uppercase."""
+        upper = pyulid.ulid()
+        mixed = upper[:13].lower() + upper[13:]
+        assert pyulid.ulid_from_str(mixed) == upper
+        assert pyulid.ulid_from_str(mixed, lowercase=True) == upper.lower()
+        assert pyulid.ulid_from_str(upper.lower().encode(), lowercase=True) == (
+            upper.lower()
+        )
+        with pytest.raises(ValueError):
+            pyulid.ulid_from_str("invalid", lowercase=True)
+
+    def test_keyword_only(self):
+        """Test that lowercase must be given by keyword."""
+        with pytest.raises(TypeError):
+            pyulid.ulid(True)
+        with pytest.raises(TypeError):
+            pyulid.ulid_with_timestamp(0, True)
+
+
+class TestLenientParsing:
+    """Test ulid_from_str_lenient and Crockford's decoding aliases."""
+
+    def test_aliases_either_case(self):
+        """Test that o/O read as 0 and i/I, l/L as 1."""
+        canonical = "01ARZ3NDEKTSV4RRFFQ69G5FAV"
+        for typed in [
+            "O1ARZ3NDEKTSV4RRFFQ69G5FAV",
+            "o1ARZ3NDEKTSV4RRFFQ69G5FAV",
+            "0IARZ3NDEKTSV4RRFFQ69G5FAV",
+            "0iARZ3NDEKTSV4RRFFQ69G5FAV",
+            "0LARZ3NDEKTSV4RRFFQ69G5FAV",
+            "0lARZ3NDEKTSV4RRFFQ69G5FAV",
+            "oiarz3ndektsv4rrffq69g5fav",
+        ]:
+            assert pyulid.ulid_from_str_lenient(typed) == canonical
+
+    def test_hyphens_ignored(self):
+        """Test that hyphens anywhere are dropped before validation."""
+        canonical = "01ARZ3NDEKTSV4RRFFQ69G5FAV"
+        for typed in [
+            "01ARZ3NDEK-TSV4RRFFQ6-9G5FAV",
+            "-01ARZ-3NDEK--TSV4RRFFQ69G5FAV-",
+        ]:
+            assert pyulid.ulid_from_str_lenient(typed) == canonical
+
+    def test_canonical_input_unchanged(self):
+        """Test that already-valid ULIDs come back as ulid_from_str gives them."""
+        for _ in range(100):
+            ulid_str = pyulid.ulid()
+            assert pyulid.ulid_from_str_lenient(ulid_str.lower()) == ulid_str
+            assert pyulid.ulid_from_str_lenient(ulid_str.encode()) == ulid_str
+
+    def test_u_still_invalid(self):
+        """Test that U has no alias."""
+        for bad in ["01ARZ3NDEKTSV4RRFFQ69G5FAU", "01ARZ3NDEKTSV4RRFFQ69G5FAu"]:
+            with pytest.raises(ValueError, match="Invalid character 'U'"):
+                pyulid.ulid_from_str_lenient(bad)
+
+    def test_length_after_cleanup(self):
+        """Test that length is checked on the cleaned-up string."""
+        with pytest.raises(ValueError, match="got 25"):
+            pyulid.ulid_from_str_lenient("01ARZ3NDEKTSV4RRFFQ69G5F-A")
+        with pytest.raises(ValueError, match="got 27"):
+            pyulid.ulid_from_str_lenient("01ARZ3NDEKTSV4RRFFQ69G5FAVO")
+        with pytest.raises(ValueError, match="0-7"):
+            pyulid.ulid_from_str_lenient("8" + "O" * 25)
+
+    def test_strict_functions_unchanged(self):
+        """Test that the default parsers still reject the aliases."""
+        for bad in ["O1ARZ3NDEKTSV4RRFFQ69G5FAV", "01ARZ3NDEK-TSV4RRFFQ69G5FA"]:
+            assert not pyulid.ulid_is_valid(bad)
+            with pytest.raises(ValueError):
+                pyulid.ulid_from_str(bad)
+        with pytest.raises(ValueError):
+            pyulid.decode_base32("IL")
+
+
+
+class TestRelaxedParsing:
+    """Test ulid_from_str(strict=False) for pasted IDs."""
+
+    def test_whitespace_and_separators(self):
+        """Test that whitespace and group separators are dropped."""
+        canonical = "01ARZ3NDEKTSV4RRFFQ69G5FAV"
+        for pasted in [
+            " 01ARZ3NDEKTSV4RRFFQ69G5FAV \n",
+            "\t01arz3ndektsv4rrffq69g5fav",
+            "01ARZ3-NDEK-TSV4-RRFF-Q69G-5FAV",
+            "01ARZ3_NDEK_TSV4RRFFQ69G5FAV",
+            "  _01ARZ3NDEK-TSV4RRFFQ69G5FAV-\r\n",
+            b" 01ARZ3NDEK-TSV4RRFFQ69G5FAV\n",
+        ]:
+            assert pyulid.ulid_from_str(pasted, strict=False) == canonical
+
+    def test_lowercase_output(self):
+        """Test that lowercase=True still applies after cleaning."""
+        pasted = " 01ARZ3NDEK-TSV4RRFFQ69G5FAV\n"
+        result = pyulid.ulid_from_str(pasted, strict=False, lowercase=True)
+        assert result == "01arz3ndektsv4rrffq69g5fav"
+
+    def test_aliases_and_interior_whitespace_rejected(self):
+        """Test that only separators are dropped, not aliases or inner spaces."""
+        for bad, message in [
+            ("O1ARZ3NDEKTSV4RRFFQ69G5FAV", "Invalid character 'O' at position 0"),
+            ("01ARZ3NDEK TSV4RRFFQ69G5FA", "Invalid character ' ' at position 10"),
+            ("01ARZ3NDEK-TSV4RRFFQ69G5F", "got 24"),
+            ("81ARZ3NDEK-TSV4RRFFQ69G5FAV", "0-7"),
+        ]:
+            with pytest.raises(pyulid.UlidDecodeError, match=message):
+                pyulid.ulid_from_str(bad, strict=False)
+
+    def test_strict_by_default(self):
+        """Test that the default still rejects whitespace and separators."""
+        for bad in [" 01ARZ3NDEKTSV4RRFFQ69G5FAV", "01ARZ3NDEK-TSV4RRFFQ69G5FAV"]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_from_str(bad)
+            with pytest.raises(ValueError):
+                pyulid.ulid_from_str(bad, strict=True)
+
 class TestValidation:
     """Test ULID validation functionality."""
 
@@ -83,6 +260,9 @@ class TestValidation:
             "01ARZ3NDEKTSV4RRFFQ69G5FA@",  # special char
             # Non-string types would cause errors, but we test string validation
             "IIIIIIIIIIIIIIIIIIIIIIIIII",  # Invalid chars (I not allowed)
+            # Leading character above 7 (value beyond 128 bits)
+            "8" + "0" * 25,
+            "ZZZZZZZZZZZZZZZZZZZZZZZZZZ",
         ]
 
         for invalid_ulid in invalid_ulids:
@@ -99,6 +279,273 @@ class TestValidation:
         lowercase_ulid = ulid_str.lower()
         assert pyulid.ulid_is_valid(lowercase_ulid) is True
 
+    def test_leading_character_boundary(self):
+        """Test that only 0-7 may lead, since anything above needs 130 bits."""
+        for first in "01234567":
+            assert pyulid.ulid_is_valid(first + "Z" * 25) is True
+        for first in "89ABCDEFGHJKMNPQRSTVWXYZabz":
+            assert pyulid.ulid_is_valid(first + "0" * 25) is False
+        assert pyulid.ulid_is_valid("Z" * 26) is False
+
+    def test_leading_character_case_insensitive_rest(self):
+        """Test that the other 25 characters stay case-insensitive."""
+        assert pyulid.ulid_is_valid("7" + "z" * 25) is True
+        assert pyulid.ulid_from_str("7" + "z" * 25) == "7" + "Z" * 25
+
+    def test_from_str_rejects_out_of_range(self):
+        """Test that ulid_from_str and the other parsers reject the same inputs."""
+        assert pyulid.ulid_from_str("7" + "Z" * 25) == "7" + "Z" * 25
+        for bad in ["8" + "0" * 25, "Z" * 26, "z" + "0" * 25]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_from_str(bad)
+            with pytest.raises(ValueError, match="0-7"):
+                pyulid.ulid_to_int(bad)
+            with pytest.raises(ValueError):
+                pyulid.ULID(bad)
+
+
+BYTE_INPUT_FUNCTIONS = [
+    "ulid_timestamp",
+    "ulid_random",
+    "ulid_is_valid",
+    "ulid_to_uuid",
+    "ulid_from_str",
+]
+
+
+class TestBytesInput:
+    """Test that the parsing functions take ASCII bytes and bytearray too."""
+
+    @pytest.mark.parametrize("name", BYTE_INPUT_FUNCTIONS)
+    @pytest.mark.parametrize("convert", [bytes, bytearray])
+    def test_same_result_as_str(self, name, convert):
+        """Test that byte input gives exactly the str result, in either case."""
+        func = getattr(pyulid, name)
+        for ulid_str in [pyulid.ulid(), "0" * 26, "7" + "Z" * 25]:
+            for text in [ulid_str, ulid_str.lower()]:
+                assert func(convert(text.encode("ascii"))) == func(text)
+
+    @pytest.mark.parametrize("name", BYTE_INPUT_FUNCTIONS)
+    @pytest.mark.parametrize("convert", [bytes, bytearray])
+    def test_invalid_bytes(self, name, convert):
+        """Test that bad byte input fails like bad str input."""
+        func = getattr(pyulid, name)
+        for bad in ["01ARZ3NDEKTSV4RRFFQ69G5FA", "01ARZ3NDEKTSV4RRFFQ69G5FAU"]:
+            if name == "ulid_is_valid":
+                assert func(convert(bad.encode())) is False
+                continue
+            with pytest.raises(ValueError) as excinfo:
+                func(convert(bad.encode()))
+            assert str(excinfo.value) == pyulid.ulid_validate(bad)["message"]
+
+    @pytest.mark.parametrize("name", BYTE_INPUT_FUNCTIONS)
+    def test_non_ascii_bytes(self, name):
+        """Test that non-ASCII bytes raise ValueError, never UnicodeDecodeError."""
+        func = getattr(pyulid, name)
+        for raw in [b"\xff" * 26, b"0" * 24 + "\u00e9".encode(), b"0" * 25 + b"\x80"]:
+            if name == "ulid_is_valid":
+                assert func(raw) is False
+                continue
+            with pytest.raises(ValueError, match="expected ASCII") as excinfo:
+                func(raw)
+            assert not isinstance(excinfo.value, UnicodeDecodeError)
+
+    @pytest.mark.parametrize("name", BYTE_INPUT_FUNCTIONS)
+    def test_other_types_rejected(self, name):
+        """Test that other types still raise TypeError."""
+        func = getattr(pyulid, name)
+        for value in [123, None, memoryview(b"0" * 26)]:
+            with pytest.raises(TypeError):
+                func(value)
+
+    def test_validate_false(self):
+        """Test that the unvalidated fast path takes bytes too."""
+        ulid_str = pyulid.ulid()
+        raw = ulid_str.encode()
+        assert pyulid.ulid_timestamp(raw, validate=False) == pyulid.ulid_timestamp(
+            ulid_str
+        )
+        assert pyulid.ulid_to_uuid(raw, validate=False) == pyulid.ulid_to_uuid(ulid_str)
+
+
+class TestValidateDiagnostics:
+    """Test ulid_validate's structured reasons and the shared messages."""
+
+    def test_valid(self):
+        """Test that valid ULIDs, in either case, give None."""
+        ulid_str = pyulid.ulid()
+        assert pyulid.ulid_validate(ulid_str) is None
+        assert pyulid.ulid_validate(ulid_str.lower()) is None
+
+    def test_length(self):
+        """Test that the actual length is reported."""
+        report = pyulid.ulid_validate("01ARZ3NDEKTSV4RRFFQ69G5FA")
+        assert report["reason"] == "length"
+        assert report["length"] == 25
+        assert report["message"] == "ULID must be exactly 26 characters, got 25"
+        assert pyulid.ulid_validate("")["length"] == 0
+
+    def test_character(self):
+        """Test that the first bad character and its index are reported."""
+        report = pyulid.ulid_validate("01ARZ3NDEKTSV4RRFFQ69G5FAU")
+        assert report["reason"] == "character"
+        assert (report["character"], report["index"]) == ("U", 25)
+        assert "'U' at position 25" in report["message"]
+        assert pyulid.ulid_validate("01ARZ3NDEKTSV4RRFF!69G5FAI")["index"] == 18
+
+    def test_overflow(self):
+        """Test that a leading character above 7 is reported."""
+        report = pyulid.ulid_validate("8" + "0" * 25)
+        assert report["reason"] == "overflow"
+        assert report["character"] == "8"
+        assert "0-7" in report["message"]
+
+    def test_checks_in_order(self):
+        """Test that length comes before characters, and characters before range."""
+        assert pyulid.ulid_validate("8!")["reason"] == "length"
+        assert pyulid.ulid_validate("8" + "0" * 24 + "!")["reason"] == "character"
+
+    def test_non_ascii(self):
+        """Test that emoji and other non-ASCII input is measured in characters."""
+        emoji = "01ARZ3NDEKTSV4RRFFQ69G5FA\U0001f600"
+        report = pyulid.ulid_validate(emoji)
+        assert report["reason"] == "character"
+        assert (report["character"], report["index"]) == ("\U0001f600", 25)
+        assert pyulid.ulid_validate("\u00e9" * 13)["length"] == 13
+        assert pyulid.ulid_validate("0" * 24 + "\u00e9")["length"] == 25
+
+    def test_control_character_escaped(self):
+        """Test that control characters are escaped in the message."""
+        report = pyulid.ulid_validate("0" * 25 + "\n")
+        assert report["character"] == "\n"
+        assert "'\\n'" in report["message"]
+
+    def test_parsers_raise_same_message(self):
+        """Test that parsing functions raise the message ulid_validate reports."""
+        bad_inputs = [
+            "01ARZ3NDEKTSV4RRFFQ69G5FA",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAU",
+            "8" + "0" * 25,
+            "01ARZ3NDEKTSV4RRFFQ69G5FA\U0001f600",
+        ]
+        parsers = [
+            pyulid.ulid_from_str,
+            pyulid.ulid_timestamp,
+            pyulid.ulid_to_int,
+            pyulid.Ulid.from_string,
+        ]
+        for bad in bad_inputs:
+            message = pyulid.ulid_validate(bad)["message"]
+            for parse in parsers:
+                with pytest.raises(ValueError) as excinfo:
+                    parse(bad)
+                assert str(excinfo.value) == message
+            assert pyulid.ulid_is_valid(bad) is False
+
+    def test_wrong_type(self):
+        """Test that non-strings raise TypeError."""
+        with pytest.raises(TypeError):
+            pyulid.ulid_validate(123)
+
+
+class TestPatternAndSchema:
+    """Test ulid_pattern and the jsonschema format checker."""
+
+    VALID = [
+        "01ARZ3NDEKTSV4RRFFQ69G5FAV",
+        "0" * 26,
+        "7" + "Z" * 25,
+    ]
+    INVALID = [
+        "8" + "0" * 25,  # Leading character above 7 overflows 128 bits
+        "Z" * 26,
+        "01ARZ3NDEKTSV4RRFFQ69G5FA",  # Too short
+        "01ARZ3NDEKTSV4RRFFQ69G5FAVX",  # Too long
+        "01ARZ3NDEKTSV4RRFFQ69G5FAI",  # I is excluded
+        "01ARZ3NDEKTSV4RRFFQ69G5FAL",  # L is excluded
+        "01ARZ3NDEKTSV4RRFFQ69G5FAO",  # O is excluded
+        "01ARZ3NDEKTSV4RRFFQ69G5FAU",  # U is excluded
+        "",
+    ]
+
+    def test_pattern_matches_valid(self):
+        """Test that the anchored pattern accepts valid ULIDs."""
+        import re
+
+        pattern = re.compile(pyulid.ulid_pattern())
+        for value in self.VALID + [pyulid.ulid() for _ in range(100)]:
+            assert pattern.fullmatch(value)
+            assert pattern.fullmatch(value.lower())
+
+    def test_pattern_rejects_invalid(self):
+        """Test that the anchored pattern rejects invalid strings."""
+        import re
+
+        pattern = re.compile(pyulid.ulid_pattern())
+        for value in self.INVALID:
+            assert not pattern.match(value)
+
+    def test_pattern_agrees_with_alphabet(self):
+        """Test every ASCII character in every position against the decoder."""
+        import re
+
+        pattern = re.compile(pyulid.ulid_pattern())
+        base = "0" * 26
+        for pos in (0, 1, 25):
+            for code in range(128):
+                candidate = base[:pos] + chr(code) + base[pos + 1 :]
+                decodes = pyulid.ulid_is_valid(candidate) and candidate[0] <= "7"
+                assert bool(pattern.fullmatch(candidate)) == decodes
+
+    def test_pattern_options(self):
+        """Test unanchored and case-sensitive variants."""
+        import re
+
+        ulid_str = pyulid.ulid()
+        assert re.search(pyulid.ulid_pattern(anchored=False), f"id={ulid_str};")
+        assert not re.search(pyulid.ulid_pattern(), f"id={ulid_str};")
+
+        strict = re.compile(pyulid.ulid_pattern(case_insensitive=False))
+        assert strict.fullmatch(ulid_str)
+        assert not strict.fullmatch(ulid_str.lower())
+
+    def test_jsonschema_format(self):
+        """Test validating a schema that uses the ulid format."""
+        jsonschema = pytest.importorskip("jsonschema")
+
+        schema = {
+            "type": "object",
+            "properties": {"id": {"type": "string", "format": "ulid"}},
+        }
+        checker = pyulid.register_jsonschema_format()
+
+        for value in self.VALID:
+            jsonschema.validate({"id": value}, schema, format_checker=checker)
+        for value in self.INVALID:
+            with pytest.raises(jsonschema.ValidationError):
+                jsonschema.validate({"id": value}, schema, format_checker=checker)
+
+    def test_jsonschema_pattern(self):
+        """Test using ulid_pattern directly as a schema pattern."""
+        jsonschema = pytest.importorskip("jsonschema")
+
+        schema = {"type": "string", "pattern": pyulid.ulid_pattern()}
+        for value in self.VALID:
+            jsonschema.validate(value, schema)
+        for value in self.INVALID:
+            with pytest.raises(jsonschema.ValidationError):
+                jsonschema.validate(value, schema)
+
+    def test_register_on_existing_checker(self):
+        """Test registering on a caller-supplied FormatChecker."""
+        jsonschema = pytest.importorskip("jsonschema")
+
+        checker = jsonschema.FormatChecker(formats=[])
+        assert pyulid.register_jsonschema_format(checker) is checker
+        assert checker.conforms(pyulid.ulid(), "ulid")
+        assert not checker.conforms("Z" * 26, "ulid")
+        assert checker.conforms(42, "ulid")  # Non-strings are not constrained
+
 
 class TestTimestampExtraction:
     """Test timestamp extraction from ULIDs."""
@@ -135,6 +582,528 @@ class TestTimestampExtraction:
         assert timestamp2 > timestamp1
 
 
+
+class TestBulkParsing:
+    """Test ulid_is_valid_many and ulid_timestamp_many."""
+
+    def test_is_valid_many_matches_single(self):
+        """Test that each result agrees with ulid_is_valid, in order."""
+        values = [
+            pyulid.ulid(),
+            "01ARZ3NDEKTSV4RRFFQ69G5FA",
+            pyulid.ulid().lower(),
+            "01ARZ3NDEKTSV4RRFFQ69G5FAU",
+            "81ARZ3NDEKTSV4RRFFQ69G5FAV",
+            pyulid.ulid().encode(),
+            "",
+        ]
+        expected = [pyulid.ulid_is_valid(v) for v in values]
+        assert pyulid.ulid_is_valid_many(values) == expected
+        assert pyulid.ulid_is_valid_many(tuple(values)) == expected
+        assert expected == [True, False, True, False, False, True, False]
+
+    def test_is_valid_many_other_types(self):
+        """Test that entries other than str and bytes are invalid, not errors."""
+        assert pyulid.ulid_is_valid_many([None, 42, pyulid.ulid()]) == [
+            False,
+            False,
+            True,
+        ]
+        assert pyulid.ulid_is_valid_many([]) == []
+
+    def test_timestamp_many(self):
+        """Test that timestamps come back in input order."""
+        timestamps = [1672531200000, 0, 2**48 - 1, 1000]
+        ulids = [pyulid.ulid_with_timestamp(ts) for ts in timestamps]
+        assert pyulid.ulid_timestamp_many(ulids) == timestamps
+        assert pyulid.ulid_timestamp_many(iter(u.encode() for u in ulids)) == timestamps
+
+    def test_timestamp_many_raise(self):
+        """Test that the default policy names the first invalid index."""
+        values = [pyulid.ulid(), "bad", "worse"]
+        with pytest.raises(pyulid.UlidDecodeError, match="index 1: 'bad'"):
+            pyulid.ulid_timestamp_many(values)
+        with pytest.raises(TypeError, match="index 0"):
+            pyulid.ulid_timestamp_many([None])
+
+    def test_timestamp_many_skip_and_none(self):
+        """Test that skip drops invalid entries and none keeps their slot."""
+        good = pyulid.ulid_with_timestamp(1000)
+        values = ["bad", good, None, "81ARZ3NDEKTSV4RRFFQ69G5FAV"]
+        assert pyulid.ulid_timestamp_many(values, errors="skip") == [1000]
+        assert pyulid.ulid_timestamp_many(values, errors="none") == [
+            None,
+            1000,
+            None,
+            None,
+        ]
+
+    def test_timestamp_many_bad_policy(self):
+        """Test that an unknown policy raises ValueError."""
+        with pytest.raises(ValueError, match="'raise', 'skip' or 'none'"):
+            pyulid.ulid_timestamp_many([], errors="coerce")
+
+class TestSecondsTimestamps:
+    """Test float-seconds timestamp helpers."""
+
+    def test_timestamp_seconds(self):
+        """Test that seconds are the millisecond timestamp over 1000."""
+        ulid_str = pyulid.ulid_with_timestamp(1672531200123)
+        assert pyulid.ulid_timestamp_seconds(ulid_str) == 1672531200.123
+        assert pyulid.ulid_timestamp_seconds(pyulid.Ulid.from_string(ulid_str)) == (
+            1672531200.123
+        )
+
+    def test_round_trip_with_time(self):
+        """Test that ulid_at(time.time()) reads back within 1ms."""
+        for _ in range(1000):
+            now = time.time()
+            ulid_str = pyulid.ulid_at(now)
+            assert abs(pyulid.ulid_timestamp_seconds(ulid_str) - now) <= 0.001
+
+    def test_round_half_even(self):
+        """Test that exact half milliseconds round to the even neighbour."""
+        assert pyulid.ulid_timestamp(pyulid.ulid_at(3.0625)) == 3062
+        assert pyulid.ulid_timestamp(pyulid.ulid_at(3.1875)) == 3188
+        assert pyulid.ulid_timestamp(pyulid.ulid_at(1.0004)) == 1000
+        assert pyulid.ulid_timestamp(pyulid.ulid_at(1.0006)) == 1001
+
+    def test_accepts_int_seconds(self):
+        """Test that whole seconds may be given as an int."""
+        assert pyulid.ulid_timestamp(pyulid.ulid_at(1672531200)) == 1672531200000
+
+    def test_range(self):
+        """Test the 48-bit range, negatives and non-finite values."""
+        limit = (2**48 - 1) / 1000
+        assert pyulid.ulid_timestamp(pyulid.ulid_at(limit)) == 2**48 - 1
+        assert pyulid.ulid_timestamp(pyulid.ulid_at(0.0)) == 0
+        for bad in [-0.001, -1.0, 2**48 / 1000, float("inf"), float("nan")]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_at(bad)
+        with pytest.raises(TypeError):
+            pyulid.ulid_at("1.5")
+
+    def test_ulid_class_float(self):
+        """Test that ULID.with_timestamp rounds float seconds the same way."""
+        assert pyulid.ULID.with_timestamp(3.1875).timestamp == 3188
+
+
+class TestDatetimeExtraction:
+    """Test ulid_datetime."""
+
+    @staticmethod
+    def _epoch_ms(dt):
+        epoch = datetime(1970, 1, 1, tzinfo=timezone.utc)
+        return (dt - epoch) // timedelta(milliseconds=1)
+
+    def test_aware_utc(self):
+        """Test that the result is an aware UTC datetime."""
+        dt = pyulid.ulid_datetime(pyulid.ulid_with_timestamp(1672531200123))
+        assert dt == datetime(2023, 1, 1, 0, 0, 0, 123000, tzinfo=timezone.utc)
+        assert dt.utcoffset() == timedelta(0)
+
+    def test_agrees_with_ulid_timestamp(self):
+        """Test millisecond agreement across the representable range."""
+        import random
+
+        last = self._epoch_ms(datetime.max.replace(tzinfo=timezone.utc))
+        timestamps = [0, 1, 999, int(time.time() * 1000), last - 1, last // 1000 * 1000]
+        timestamps += [random.randrange(last) for _ in range(500)]
+        for ts in timestamps:
+            ulid_str = pyulid.ulid_with_timestamp(ts)
+            dt = pyulid.ulid_datetime(ulid_str)
+            assert self._epoch_ms(dt) == pyulid.ulid_timestamp(ulid_str) == ts
+
+    def test_beyond_datetime_max(self):
+        """Test that timestamps near the 48-bit maximum raise OverflowError."""
+        last = self._epoch_ms(datetime.max.replace(tzinfo=timezone.utc))
+        for ts in [2**48 - 1, last + 1]:
+            with pytest.raises(OverflowError):
+                pyulid.ulid_datetime(pyulid.ulid_with_timestamp(ts))
+
+    def test_invalid(self):
+        """Test that invalid strings raise ValueError like the other parsers."""
+        for bad in ["", "01ARZ3NDEKTSV4RRFFQ69G5FA", "01ARZ3NDEKTSV4RRFFQ69G5FAU"]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_datetime(bad)
+
+
+class TestParse:
+    """Test ulid_parse."""
+
+    def test_components_agree(self):
+        """Test that every component matches its single-purpose function."""
+        for ulid_str in [pyulid.ulid(), "0" * 26, "7" + "Z" * 25]:
+            parts = pyulid.ulid_parse(ulid_str.lower())
+            assert set(parts) == {
+                "timestamp_ms",
+                "datetime",
+                "random",
+                "bytes",
+                "canonical",
+            }
+            assert parts["timestamp_ms"] == pyulid.ulid_timestamp(ulid_str)
+            assert parts["random"] == pyulid.ulid_random(ulid_str)
+            assert parts["bytes"] == pyulid.ulid_to_bytes(ulid_str)
+            assert parts["canonical"] == ulid_str.upper()
+
+    def test_datetime(self):
+        """Test the datetime component, and None past datetime.max."""
+        parts = pyulid.ulid_parse(pyulid.ulid_with_timestamp(1672531200123))
+        assert parts["datetime"] == datetime(
+            2023, 1, 1, 0, 0, 0, 123000, tzinfo=timezone.utc
+        )
+        last = pyulid.ulid_with_timestamp(2**48 - 1)
+        assert pyulid.ulid_parse(last)["datetime"] is None
+
+    def test_invalid_names_the_failure(self):
+        """Test that invalid input raises one ValueError saying what failed."""
+        cases = {
+            "01ARZ3NDEKTSV4RRFFQ69G5FA": "26 characters",
+            "01ARZ3NDEKTSV4RRFFQ69G5FAU": "Invalid character 'U'",
+            "8" + "0" * 25: "don't fit in 128 bits",
+        }
+        for bad, message in cases.items():
+            with pytest.raises(ValueError, match=message):
+                pyulid.ulid_parse(bad)
+            with pytest.raises(ValueError, match=message):
+                pyulid.ulid_timestamp(bad)
+
+
+class TestFromDatetime:
+    """Test ulid_from_datetime."""
+
+    def test_aware_datetime(self):
+        """Test that the timestamp is the datetime's epoch milliseconds."""
+        dt = datetime(2023, 1, 1, 12, 30, 45, 123000, tzinfo=timezone.utc)
+        ulid_str = pyulid.ulid_from_datetime(dt)
+        assert pyulid.ulid_is_valid(ulid_str)
+        assert pyulid.ulid_timestamp(ulid_str) == 1672576245123
+
+    def test_other_timezone(self):
+        """Test that non-UTC offsets are converted, not ignored."""
+        utc = datetime(2023, 1, 1, 12, 0, tzinfo=timezone.utc)
+        plus_two = utc.astimezone(timezone(timedelta(hours=2)))
+        assert pyulid.ulid_timestamp(
+            pyulid.ulid_from_datetime(plus_two)
+        ) == pyulid.ulid_timestamp(pyulid.ulid_from_datetime(utc))
+
+    def test_sub_millisecond_floored(self):
+        """Test that microseconds never round up into the next millisecond."""
+        dt = datetime(2023, 1, 1, tzinfo=timezone.utc) + timedelta(microseconds=999)
+        assert pyulid.ulid_timestamp(pyulid.ulid_from_datetime(dt)) == 1672531200000
+
+    def test_naive_rejected(self):
+        """Test that naive datetimes raise ValueError naming the problem."""
+        with pytest.raises(ValueError, match="naive"):
+            pyulid.ulid_from_datetime(datetime(2023, 1, 1))
+
+    def test_range(self):
+        """Test that pre-epoch dates raise and the latest datetime still fits."""
+        with pytest.raises(ValueError):
+            pyulid.ulid_from_datetime(
+                datetime(1969, 12, 31, 23, 59, 59, tzinfo=timezone.utc)
+            )
+        epoch = datetime(1970, 1, 1, tzinfo=timezone.utc)
+        assert pyulid.ulid_timestamp(pyulid.ulid_from_datetime(epoch)) == 0
+        last = datetime.max.replace(tzinfo=timezone.utc)
+        assert pyulid.ulid_timestamp(pyulid.ulid_from_datetime(last)) < 2**48
+
+    def test_wrong_type(self):
+        """Test that ints and strings are rejected."""
+        for value in [1672531200000, "2023-01-01"]:
+            with pytest.raises(TypeError):
+                pyulid.ulid_from_datetime(value)
+
+
+def iso_ms(ts):
+    return pyulid.ulid_timestamp(pyulid.ulid_from_iso8601(ts))
+
+
+class TestFromIso8601:
+    """Test ulid_from_iso8601."""
+
+    def test_utc(self):
+        """Test a Z timestamp with milliseconds."""
+        assert iso_ms("2023-11-05T14:48:00.123Z") == 1699195680123
+        assert iso_ms("2023-11-05t14:48:00.123z") == 1699195680123
+        assert iso_ms("2023-11-05 14:48:00.123Z") == 1699195680123
+
+    def test_offsets(self):
+        """Test that numeric offsets are converted to UTC."""
+        expected = iso_ms("2023-11-05T12:48:00Z")
+        for ts in [
+            "2023-11-05T14:48:00+02:00",
+            "2023-11-05T14:48:00+0200",
+            "2023-11-05T14:48:00+02",
+            "2023-11-05T07:18:00-05:30",
+            "2023-11-05T12:48:00+00:00",
+            "2023-11-05T12:48:00-00:00",
+            "2023-11-06T00:48:00+12:00",  # Crosses midnight into the previous day
+        ]:
+            assert iso_ms(ts) == expected, ts
+
+    def test_missing_fraction_and_seconds(self):
+        """Test that seconds and fractions are optional."""
+        assert iso_ms("2023-11-05T14:48:00Z") == 1699195680000
+        assert iso_ms("2023-11-05T14:48Z") == 1699195680000
+
+    def test_fraction_precision(self):
+        """Test short fractions and truncation of sub-millisecond digits."""
+        assert iso_ms("2023-11-05T14:48:00.1Z") == 1699195680100
+        assert iso_ms("2023-11-05T14:48:00.12Z") == 1699195680120
+        assert iso_ms("2023-11-05T14:48:00,5Z") == 1699195680500
+        assert iso_ms("2023-11-05T14:48:00.123999Z") == 1699195680123
+        assert iso_ms("2023-11-05T14:48:00.999999999Z") == 1699195680999
+
+    def test_matches_datetime(self):
+        """Test agreement with datetime.fromisoformat across many dates."""
+        rng = random.Random(1234)
+        for _ in range(1000):
+            ms = rng.randrange(0, 253402300800000)
+            offset = timedelta(minutes=rng.randrange(-23 * 60, 24 * 60))
+            dt = datetime.fromtimestamp(ms / 1000, timezone(offset))
+            dt = dt.replace(microsecond=ms % 1000 * 1000)
+            ts = dt.isoformat(timespec="microseconds")
+            assert iso_ms(ts) == pyulid.ulid_timestamp(pyulid.ulid_from_datetime(dt))
+
+    def test_leap_day(self):
+        """Test February 29 in leap years only."""
+        assert iso_ms("2024-02-29T00:00:00Z") == 1709164800000
+        assert iso_ms("2000-02-29T00:00:00Z") == 951782400000
+        for ts in ["2023-02-29T00:00:00Z", "1900-02-29T00:00:00Z"]:
+            with pytest.raises(ValueError, match="day out of range"):
+                pyulid.ulid_from_iso8601(ts)
+
+    def test_range(self):
+        """Test the epoch itself and rejection of earlier times."""
+        assert iso_ms("1970-01-01T00:00:00Z") == 0
+        assert iso_ms("1970-01-01T01:00:00+01:00") == 0
+        for ts in ["1969-12-31T23:59:59.999Z", "1970-01-01T00:30:00+01:00"]:
+            with pytest.raises(ValueError, match="before 1970"):
+                pyulid.ulid_from_iso8601(ts)
+        assert iso_ms("9999-12-31T23:59:59.999Z") < 2**48
+
+    def test_ambiguous_rejected(self):
+        """Test that strings without a time or an offset are rejected."""
+        with pytest.raises(ValueError, match="ambiguous"):
+            pyulid.ulid_from_iso8601("2023-11-05T14:48:00")
+        with pytest.raises(ValueError, match="ambiguous"):
+            pyulid.ulid_from_iso8601("2023-11-05")
+
+    def test_invalid(self):
+        """Test that malformed timestamps raise ValueError."""
+        invalid = [
+            "",
+            "2023-11-05T14:48:00.Z",  # No fraction digits
+            "2023-13-05T14:48:00Z",
+            "2023-11-31T14:48:00Z",
+            "2023-11-05T24:00:00Z",
+            "2023-11-05T14:60:00Z",
+            "2023-11-05T14:48:60Z",  # Leap second
+            "2023-11-05T14:48:00+24:00",
+            "2023-11-05T14:48:00+2",
+            "2023-11-05T14:48:00ZZ",
+            "2023-11-05T14:48:00 Z",
+            "20231105T144800Z",  # Basic format
+            "23-11-05T14:48:00Z",
+            "2023-11-05X14:48:00Z",
+            "２０23-11-05T14:48:00Z",
+        ]
+        for ts in invalid:
+            with pytest.raises(ValueError, match="ISO 8601"):
+                pyulid.ulid_from_iso8601(ts)
+
+    def test_fresh_random(self):
+        """Test that each call draws a fresh random component."""
+        ts = "2023-11-05T14:48:00.123Z"
+        assert pyulid.ulid_from_iso8601(ts) != pyulid.ulid_from_iso8601(ts)
+
+
+class TestTimestampDelta:
+    """Test ulid_delta time differences."""
+
+    def test_delta_milliseconds(self):
+        """Test delta in signed milliseconds."""
+        older = pyulid.ulid_with_timestamp(1672531200000)
+        newer = pyulid.ulid_with_timestamp(1672531201500)
+
+        assert pyulid.ulid_delta(newer, older) == 1500
+        assert isinstance(pyulid.ulid_delta(newer, older), int)
+
+    def test_negative_means_a_is_older(self):
+        """Test that a negative delta means the first argument is older."""
+        older = pyulid.ulid_with_timestamp(1672531200000)
+        newer = pyulid.ulid_with_timestamp(1672531201500)
+
+        assert pyulid.ulid_delta(older, newer) == -1500
+
+    def test_delta_seconds(self):
+        """Test delta as float seconds."""
+        older = pyulid.ulid_with_timestamp(1672531200000)
+        newer = pyulid.ulid_with_timestamp(1672531201500)
+
+        result = pyulid.ulid_delta(newer, older, unit="s")
+        assert isinstance(result, float)
+        assert result == 1.5
+
+    def test_random_component_ignored(self):
+        """Test that ULIDs in the same millisecond have zero delta."""
+        a = pyulid.ulid_with_timestamp(1672531200000)
+        b = pyulid.ulid_with_timestamp(1672531200000)
+
+        assert a != b
+        assert pyulid.ulid_delta(a, b) == 0
+
+    def test_accepts_ulid_objects(self):
+        """Test that ULID objects and strings can be mixed."""
+        older = pyulid.ULID.with_timestamp(1672531200000)
+        newer = pyulid.ULID.with_timestamp(1672531200250)
+
+        assert pyulid.ulid_delta(newer, older) == 250
+        assert pyulid.ulid_delta(str(newer), older) == 250
+
+    def test_invalid_inputs(self):
+        """Test that both inputs are validated."""
+        valid = pyulid.ulid()
+
+        with pytest.raises(ValueError):
+            pyulid.ulid_delta(valid, "INVALID")
+        with pytest.raises(ValueError):
+            pyulid.ulid_delta("INVALID", valid)
+        with pytest.raises(ValueError):
+            pyulid.ulid_delta(valid, valid, unit="minutes")
+        with pytest.raises(TypeError):
+            pyulid.ulid_delta(valid, 12345)
+
+
+
+class TestDiffAndCompare:
+    """Test ulid_diff_ms and ulid_compare."""
+
+    def test_diff_a_older(self):
+        """Test that the difference is negative when a is older than b."""
+        older = pyulid.ulid_with_timestamp(1672531200000)
+        newer = pyulid.ulid_with_timestamp(1672531201500)
+
+        assert pyulid.ulid_diff_ms(older, newer) == -1500
+        assert pyulid.ulid_diff_ms(newer, older) == 1500
+        assert pyulid.ulid_compare(older, newer) == -1
+        assert pyulid.ulid_compare(newer, older) == 1
+
+    def test_equal_timestamps_random_differs(self):
+        """Test a zero difference while the random component still orders them."""
+        low = pyulid.ulid_from_parts(1672531200000, 1)
+        high = pyulid.ulid_from_parts(1672531200000, 2)
+
+        assert pyulid.ulid_diff_ms(low, high) == 0
+        assert pyulid.ulid_compare(low, high) == -1
+        assert pyulid.ulid_compare(high, low) == 1
+        assert pyulid.ulid_compare(low, low.lower()) == 0
+
+    def test_compare_as_sort_key(self):
+        """Test that ulid_compare sorts like the strings do."""
+        ulids = [pyulid.ulid_from_int(random.getrandbits(128)) for _ in range(200)]
+        ulids.append(pyulid.ULID.with_timestamp(0))
+        ordered = sorted(ulids, key=functools.cmp_to_key(pyulid.ulid_compare))
+        assert [str(u) for u in ordered] == sorted(str(u) for u in ulids)
+
+    def test_invalid_argument_named(self):
+        """Test that each argument is validated and named in the error."""
+        valid = pyulid.ulid()
+        for func in [pyulid.ulid_diff_ms, pyulid.ulid_compare]:
+            with pytest.raises(pyulid.UlidDecodeError, match="argument 'a': "):
+                func("INVALID", valid)
+            with pytest.raises(pyulid.UlidDecodeError, match="argument 'b': .*'U'"):
+                func(valid, "01ARZ3NDEKTSV4RRFFQ69G5FAU")
+            with pytest.raises(TypeError, match="argument 'b': "):
+                func(valid, 12345)
+
+class TestTimestampLimits:
+    """Test the 48-bit timestamp limit of the user-supplied timestamp entry points."""
+
+    ENTRY_POINTS = [
+        pyulid.ulid_with_timestamp,
+        pyulid.ulid_with_timestamp_monotonic,
+        pyulid.ulid_min_for_timestamp,
+        pyulid.ULID.with_timestamp,
+    ]
+
+    def test_last_millisecond(self):
+        """Test that 2**48 - 1 is accepted and round-trips."""
+        for generate in self.ENTRY_POINTS:
+            assert pyulid.ulid_timestamp(str(generate(2**48 - 1))) == 2**48 - 1
+
+    def test_one_past_the_limit(self):
+        """Test that 2**48 raises ValueError naming the latest date."""
+        for generate in self.ENTRY_POINTS:
+            with pytest.raises(ValueError, match="up to 10889-08-02T05:31:50.655Z"):
+                generate(2**48)
+
+    def test_negative(self):
+        """Test that -1 raises ValueError rather than wrapping."""
+        for generate in self.ENTRY_POINTS:
+            with pytest.raises(ValueError, match="got -1"):
+                generate(-1)
+
+    def test_float_seconds_message(self):
+        """Test that ulid_at reports the limit the same way."""
+        with pytest.raises(ValueError, match="10889-08-02.*seconds"):
+            pyulid.ulid_at(2**48 / 1000)
+
+
+class TestAge:
+    """Test ulid_age_ms and ulid_is_expired against the clock."""
+
+    def test_age_of_past_ulid(self):
+        """Test the age of a ULID stamped at a known past time."""
+        known = pyulid.ulid_with_timestamp(1672531200000)
+        low = int(time.time() * 1000) - 1672531200000
+        age = pyulid.ulid_age_ms(known)
+        high = int(time.time() * 1000) - 1672531200000
+
+        assert isinstance(age, int)
+        assert low - 1 <= age <= high + 1
+
+    def test_frozen_clock(self):
+        """Test that the frozen clock used by generation applies as well."""
+        pyulid._freeze_time(1672531205000)
+        try:
+            known = pyulid.ulid_with_timestamp(1672531200000)
+            assert pyulid.ulid_age_ms(known) == 5000
+            future = pyulid.ulid_with_timestamp(1672531207500)
+            assert pyulid.ulid_age_ms(future) == -2500
+
+            assert pyulid.ulid_is_expired(known, 4999)
+            assert pyulid.ulid_is_expired(known, 5000)
+            assert not pyulid.ulid_is_expired(known, 5001)
+            assert not pyulid.ulid_is_expired(future, 0)
+        finally:
+            pyulid._unfreeze_time()
+
+    def test_expiry(self):
+        """Test ulid_is_expired on old and fresh ULIDs."""
+        old = pyulid.ulid_with_timestamp(int(time.time() * 1000) - 60_000)
+        assert pyulid.ulid_is_expired(old, 30_000)
+        assert not pyulid.ulid_is_expired(old, 3_600_000)
+        assert not pyulid.ulid_is_expired(pyulid.ulid(), 60_000)
+        assert not pyulid.ulid_is_expired(old, 2**64 - 1)
+
+    def test_accepts_ulid_objects(self):
+        """Test that ULID objects work like strings."""
+        known = pyulid.ULID.with_timestamp(1672531200000)
+        assert abs(pyulid.ulid_age_ms(known) - pyulid.ulid_age_ms(str(known))) < 1000
+
+    def test_invalid_inputs(self):
+        """Test the standard validation and a negative TTL."""
+        with pytest.raises(ValueError):
+            pyulid.ulid_age_ms("INVALID")
+        with pytest.raises(ValueError):
+            pyulid.ulid_is_expired("01ARZ3NDEKTSV4RRFFQ69G5FAU", 1000)
+        with pytest.raises(OverflowError):
+            pyulid.ulid_is_expired(pyulid.ulid(), -1)
+
+
 class TestRandomExtraction:
     """Test random component extraction from ULIDs."""
 
@@ -164,6 +1133,138 @@ class TestRandomExtraction:
         assert len(random_components) >= 95
 
 
+class TestComponentReplacement:
+    """Test ulid_set_timestamp and ulid_set_random."""
+
+    def test_set_timestamp_round_trip(self):
+        """Test that writing back the same timestamp yields the canonical form."""
+        for _ in range(100):
+            ulid_str = pyulid.ulid()
+            ts = pyulid.ulid_timestamp(ulid_str)
+            assert pyulid.ulid_set_timestamp(ulid_str, ts) == ulid_str
+            assert pyulid.ulid_set_timestamp(ulid_str.lower(), ts) == ulid_str
+
+    def test_set_random_round_trip(self):
+        """Test that writing back the same random part yields the canonical form."""
+        for _ in range(100):
+            ulid_str = pyulid.ulid()
+            rnd = pyulid.ulid_random(ulid_str)
+            assert pyulid.ulid_set_random(ulid_str, rnd) == ulid_str
+            assert pyulid.ulid_set_random(ulid_str.lower(), rnd) == ulid_str
+            assert pyulid.ulid_set_random(ulid_str, rnd.to_bytes(10, "big")) == ulid_str
+
+    def test_set_timestamp_keeps_random(self):
+        """Test that only the timestamp changes."""
+        ulid_str = pyulid.ulid()
+        updated = pyulid.ulid_set_timestamp(ulid_str, 1672531200000)
+
+        assert pyulid.ulid_timestamp(updated) == 1672531200000
+        assert pyulid.ulid_random(updated) == pyulid.ulid_random(ulid_str)
+        assert updated[10:] == ulid_str[10:]
+
+    def test_set_random_keeps_timestamp(self):
+        """Test that only the random component changes."""
+        ulid_str = pyulid.ulid()
+        updated = pyulid.ulid_set_random(ulid_str, 12345)
+
+        assert pyulid.ulid_random(updated) == 12345
+        assert updated[:10] == ulid_str[:10]
+
+    def test_set_timestamp_datetime(self):
+        """Test aware datetimes are converted exactly and naive ones rejected."""
+        from datetime import timezone, timedelta
+
+        ulid_str = pyulid.ulid()
+        aware = datetime(2023, 1, 1, 0, 0, 0, 123999, tzinfo=timezone.utc)
+        updated = pyulid.ulid_set_timestamp(ulid_str, aware)
+        assert pyulid.ulid_timestamp(updated) == 1672531200123
+
+        shifted = aware.astimezone(timezone(timedelta(hours=7)))
+        assert pyulid.ulid_set_timestamp(ulid_str, shifted) == updated
+
+        with pytest.raises(ValueError):
+            pyulid.ulid_set_timestamp(ulid_str, datetime(2023, 1, 1))
+
+    def test_accepts_ulid_objects(self):
+        """Test that ULID objects are accepted as the first argument."""
+        obj = pyulid.ULID()
+        assert pyulid.ulid_set_random(obj, obj.random) == str(obj)
+
+    def test_invalid_inputs(self):
+        """Test validation of both arguments."""
+        ulid_str = pyulid.ulid()
+
+        for bad_ts in [-1, 2**48, 2**200]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_set_timestamp(ulid_str, bad_ts)
+        for bad_random in [-1, 2**80, b"\x00" * 9, b"\x00" * 11]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_set_random(ulid_str, bad_random)
+
+        with pytest.raises(TypeError):
+            pyulid.ulid_set_timestamp(ulid_str, "1672531200000")
+        with pytest.raises(TypeError):
+            pyulid.ulid_set_random(ulid_str, 1.5)
+        with pytest.raises(ValueError):
+            pyulid.ulid_set_timestamp("invalid", 0)
+        with pytest.raises(ValueError):
+            pyulid.ulid_set_random("invalid", 0)
+
+    def test_extremes(self):
+        """Test the boundary values of both components."""
+        ulid_str = pyulid.ulid()
+        assert pyulid.ulid_set_timestamp(ulid_str, 2**48 - 1)[:10] == "7ZZZZZZZZZ"
+        assert pyulid.ulid_set_random(ulid_str, 2**80 - 1)[10:] == "Z" * 16
+        assert pyulid.ulid_set_random(ulid_str, 0)[10:] == "0" * 16
+
+
+class TestFromParts:
+    """Test ulid_from_parts."""
+
+    @pytest.mark.parametrize("timestamp", [0, 1, 1672531200123, 2**48 - 1])
+    @pytest.mark.parametrize("rand", [0, 1, 2**79, 2**80 - 1])
+    def test_round_trip(self, timestamp, rand):
+        """Test that the components read back exactly, extremes included."""
+        ulid_str = pyulid.ulid_from_parts(timestamp, rand)
+        assert pyulid.ulid_is_valid(ulid_str)
+        assert pyulid.ulid_timestamp(ulid_str) == timestamp
+        assert pyulid.ulid_random(ulid_str) == rand
+
+    def test_extremes(self):
+        """Test the all-zero and all-ones ULIDs."""
+        assert pyulid.ulid_from_parts(0, 0) == "0" * 26
+        assert pyulid.ulid_from_parts(2**48 - 1, 2**80 - 1) == "7" + "Z" * 25
+
+    def test_matches_generated(self):
+        """Test that a generated ULID is rebuilt from its own components."""
+        for _ in range(100):
+            ulid_str = pyulid.ulid()
+            timestamp = pyulid.ulid_timestamp(ulid_str)
+            rand = pyulid.ulid_random(ulid_str)
+            assert pyulid.ulid_from_parts(timestamp, rand) == ulid_str
+            rand_bytes = pyulid.ulid_random_bytes(ulid_str)
+            assert pyulid.ulid_from_parts(timestamp, rand_bytes) == ulid_str
+
+    def test_datetime_timestamp(self):
+        """Test that an aware datetime works as the timestamp."""
+        dt = datetime(2023, 1, 1, 0, 0, 0, 123000, tzinfo=timezone.utc)
+        ulid_str = pyulid.ulid_from_parts(dt, 0)
+        assert ulid_str == pyulid.ulid_from_parts(1672531200123, 0)
+
+    def test_out_of_range_raises(self):
+        """Test that out-of-range components raise instead of being masked."""
+        for bad_ts in [-1, 2**48, 2**64]:
+            with pytest.raises(ValueError, match="2\\*\\*48"):
+                pyulid.ulid_from_parts(bad_ts, 0)
+        for bad_random in [-1, 2**80, 2**128]:
+            with pytest.raises(ValueError, match="2\\*\\*80"):
+                pyulid.ulid_from_parts(0, bad_random)
+        with pytest.raises(TypeError):
+            pyulid.ulid_from_parts("0", 0)
+        with pytest.raises(TypeError):
+            pyulid.ulid_from_parts(0, 0.5)
+
+
 class TestULIDClass:
     """Test the ULID class wrapper."""
 
@@ -220,5 +1321,228 @@ class TestULIDClass:
         assert ulid1 != str(ulid2)
 
 
+class TestTimedeltaArithmetic:
+    """Test ULID +/- timedelta."""
+
+    def test_add_and_subtract(self):
+        """Test shifting the timestamp while keeping the random part."""
+        from datetime import timedelta
+
+        obj = pyulid.ULID.with_timestamp(1672531200000)
+        later = obj + timedelta(days=1)
+        earlier = obj - timedelta(seconds=1, milliseconds=500)
+
+        assert later.timestamp == 1672531200000 + 86_400_000
+        assert earlier.timestamp == 1672531200000 - 1500
+        assert later.random == obj.random == earlier.random
+        assert str(later)[10:] == str(obj)[10:]
+        assert isinstance(later, pyulid.ULID)
+
+    def test_radd_and_negative(self):
+        """Test both operand orders and negative timedeltas."""
+        from datetime import timedelta
+
+        obj = pyulid.ULID.with_timestamp(10_000)
+        assert timedelta(milliseconds=5) + obj == obj + timedelta(milliseconds=5)
+        assert obj + timedelta(milliseconds=-5) == obj - timedelta(milliseconds=5)
+        assert (obj + timedelta(0)) == obj
+
+    def test_sub_millisecond_truncation(self):
+        """Test that sub-millisecond parts truncate toward zero."""
+        from datetime import timedelta
+
+        obj = pyulid.ULID.with_timestamp(10_000)
+        assert (obj + timedelta(microseconds=999)).timestamp == 10_000
+        assert (obj + timedelta(microseconds=1999)).timestamp == 10_001
+        assert (obj + timedelta(microseconds=-999)).timestamp == 10_000
+        assert (obj + timedelta(microseconds=-1999)).timestamp == 9_999
+        assert (obj - timedelta(microseconds=1999)).timestamp == 9_999
+
+    def test_overflow(self):
+        """Test that leaving the 48-bit range raises OverflowError."""
+        from datetime import timedelta
+
+        low = pyulid.ULID.with_timestamp(0)
+        high = pyulid.ULID.with_timestamp(2**48 - 1)
+
+        with pytest.raises(OverflowError):
+            low - timedelta(milliseconds=1)
+        with pytest.raises(OverflowError):
+            high + timedelta(milliseconds=1)
+        assert (high - timedelta(milliseconds=1)).timestamp == 2**48 - 2
+
+    def test_other_types_not_supported(self):
+        """Test that non-timedelta operands give TypeError via NotImplemented."""
+        obj = pyulid.ULID()
+        for other in [1, 1.5, "x", obj]:
+            with pytest.raises(TypeError):
+                obj + other
+            with pytest.raises(TypeError):
+                obj - other
+        assert obj.__add__(1) is NotImplemented
+        assert obj.__sub__(obj) is NotImplemented
+
+
+class TestDatetimeComparison:
+    """Test ordering ULID objects against datetimes."""
+
+    def test_ordering_both_directions(self):
+        """Test ULID vs datetime ordering in both operand orders."""
+        from datetime import datetime, timezone
+
+        obj = pyulid.ULID.with_timestamp(1672531200000)
+        before = datetime(2022, 12, 31, tzinfo=timezone.utc)
+        after = datetime(2023, 1, 2, tzinfo=timezone.utc)
+
+        assert obj > before and obj >= before
+        assert obj < after and obj <= after
+        assert before < obj and before <= obj
+        assert after > obj and after >= obj
+        assert not obj < before
+        assert not after < obj
+
+    def test_same_millisecond_boundary(self):
+        """Test a datetime inside the ULID's millisecond is neither before nor after."""
+        from datetime import datetime, timedelta, timezone
+
+        ms = 1672531200123
+        obj = pyulid.ULID.with_timestamp(ms)
+        start = datetime(1970, 1, 1, tzinfo=timezone.utc) + timedelta(milliseconds=ms)
+
+        for dt in [start, start + timedelta(microseconds=999)]:
+            assert not obj < dt
+            assert not obj > dt
+            assert obj <= dt and obj >= dt
+            assert dt <= obj and dt >= obj
+            assert not dt < obj and not dt > obj
+
+        next_ms = start + timedelta(milliseconds=1)
+        assert obj < next_ms and next_ms > obj
+        prev_us = start - timedelta(microseconds=1)
+        assert obj > prev_us and prev_us < obj
+
+    def test_other_timezones(self):
+        """Test that non-UTC aware datetimes compare by instant."""
+        from datetime import datetime, timedelta, timezone
+
+        obj = pyulid.ULID.with_timestamp(1672531200000)
+        plus_seven = timezone(timedelta(hours=7))
+        same_instant = datetime(2023, 1, 1, 7, 0, tzinfo=plus_seven)
+
+        assert obj <= same_instant and obj >= same_instant
+        assert obj < same_instant + timedelta(milliseconds=1)
+
+    def test_naive_datetime_raises(self):
+        """Test that naive datetimes raise TypeError in both orders."""
+        from datetime import datetime
+
+        obj = pyulid.ULID()
+        naive = datetime(2023, 1, 1)
+
+        with pytest.raises(TypeError):
+            obj < naive
+        with pytest.raises(TypeError):
+            naive >= obj
+
+    def test_equality_always_false(self):
+        """Test that equality against a datetime is always False."""
+        from datetime import datetime, timedelta, timezone
+
+        obj = pyulid.ULID.with_timestamp(1672531200000)
+        same = datetime(2023, 1, 1, tzinfo=timezone.utc)
+
+        assert obj <= same and obj >= same
+        assert not obj == same
+        assert not same == obj
+        assert obj != same
+        assert same != obj
+        assert obj != same + timedelta(days=1)
+
+
+class TestInterning:
+    """Test ULID.intern and pyulid.intern."""
+
+    def test_equal_values_are_identical(self):
+        """Test that interned equal ULIDs are the same object."""
+        ulid_str = pyulid.ulid()
+        a = pyulid.ULID(ulid_str).intern()
+        b = pyulid.ULID(ulid_str).intern()
+        c = pyulid.intern(ulid_str.lower())
+        d = pyulid.intern(pyulid.ULID(ulid_str))
+
+        assert a is b is c is d
+        assert str(a) == ulid_str
+
+    def test_first_instance_becomes_canonical(self):
+        """Test that intern returns self when nothing is cached."""
+        obj = pyulid.ULID()
+        assert obj.intern() is obj
+        assert pyulid.intern(str(obj)) is obj
+
+    def test_distinct_values_are_distinct(self):
+        """Test that different ULIDs intern to different objects."""
+        a = pyulid.intern(pyulid.ulid())
+        b = pyulid.intern(pyulid.ulid())
+        assert a is not b
+        assert a != b
+
+    def test_unused_entries_are_collected(self):
+        """Test that the cache holds entries weakly."""
+        import gc
+        import weakref
+
+        ulid_str = pyulid.ulid()
+        obj = pyulid.intern(ulid_str)
+        ref = weakref.ref(obj)
+        del obj
+        gc.collect()
+
+        assert ref() is None
+        assert pyulid.ulid_to_int(ulid_str) not in pyulid._intern_cache
+
+    def test_cache_does_not_grow_unbounded(self):
+        """Test that interning many transient values leaves the cache small."""
+        import gc
+
+        for _ in range(10000):
+            pyulid.intern(pyulid.ulid())
+        gc.collect()
+
+        assert len(pyulid._intern_cache) < 100
+
+    def test_deduplicates_repeats(self):
+        """Test that repeated foreign keys share one object."""
+        keys = [pyulid.ulid() for _ in range(10)]
+        interned = [pyulid.intern(keys[i % 10]) for i in range(1000)]
+
+        assert len({id(obj) for obj in interned}) == 10
+
+    def test_thread_safety(self):
+        """Test that concurrent interning yields a single canonical object."""
+        import threading
+
+        ulid_str = pyulid.ulid()
+        results = []
+        lock = threading.Lock()
+
+        def worker():
+            local = [pyulid.intern(ulid_str) for _ in range(1000)]
+            with lock:
+                results.extend(local)
+
+        threads = [threading.Thread(target=worker) for _ in range(8)]
+        for t in threads:
+            t.start()
+        for t in threads:
+            t.join()
+
+        assert len({id(obj) for obj in results}) == 1
+
+    def test_invalid_input(self):
+        """Test that invalid strings raise."""
+        with pytest.raises(ValueError):
+            pyulid.intern("invalid")
+
+
 if __name__ == "__main__":
     pytest.main([__file__])
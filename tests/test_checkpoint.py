@@ -0,0 +1,171 @@
+"""
+Checkpoint tests for PyULID.
+
+Tests the persisted high-water mark of UlidCheckpoint, including concurrent writers.
+"""
+
+import os
+import pathlib
+import subprocess
+import sys
+import tempfile
+import threading
+
+import pytest
+import pyulid
+
+# Each writer advances through its own random ULIDs, checking the mark never drops
+WRITER = """
+import random, sys
+import pyulid
+
+checkpoint = pyulid.UlidCheckpoint(sys.argv[1])
+best = None
+for _ in range(200):
+    u = pyulid.ulid_from_int(random.getrandbits(127))
+    checkpoint.advance(u)
+    best = u if best is None else max(best, u)
+    assert checkpoint.get() >= best
+print(best)
+"""
+
+
+@pytest.fixture
+def path():
+    with tempfile.TemporaryDirectory() as directory:
+        yield os.path.join(directory, "state.ulid")
+
+
+class TestCheckpoint:
+    """Test UlidCheckpoint."""
+
+    def test_empty(self, path):
+        """Test that a missing file reads as None."""
+        assert pyulid.UlidCheckpoint(path).get() is None
+
+    def test_advance_only_forward(self, path):
+        """Test that only greater values are stored."""
+        checkpoint = pyulid.UlidCheckpoint(path)
+        a, b = pyulid.ulid(), pyulid.ulid()
+
+        assert checkpoint.advance(b) is True
+        assert checkpoint.get() == b
+        assert checkpoint.advance(a) is False
+        assert checkpoint.advance(b) is False
+        assert checkpoint.get() == b
+
+        c = pyulid.ulid()
+        assert checkpoint.advance(pyulid.ULID(c)) is True
+        assert checkpoint.get() == c
+
+    def test_persists(self, path):
+        """Test that the mark survives a new instance and is stored canonically."""
+        u = pyulid.ulid()
+        pyulid.UlidCheckpoint(path).advance(u.lower())
+
+        assert pyulid.UlidCheckpoint(path).get() == u
+        with open(path) as f:
+            assert f.read() == u + "\n"
+
+    def test_reset(self, path):
+        """Test that reset forgets the mark and tolerates a missing file."""
+        checkpoint = pyulid.UlidCheckpoint(path)
+        checkpoint.advance(pyulid.ulid())
+        checkpoint.reset()
+        assert checkpoint.get() is None
+        checkpoint.reset()
+
+        u = pyulid.ulid_from_int(0)
+        assert checkpoint.advance(u) is True
+        assert checkpoint.get() == u
+
+    def test_no_temp_files_left(self, path):
+        """Test that writes leave only the checkpoint and its lock file."""
+        checkpoint = pyulid.UlidCheckpoint(path)
+        for _ in range(20):
+            checkpoint.advance(pyulid.ulid())
+
+        names = sorted(os.listdir(os.path.dirname(path)))
+        assert names == ["state.ulid", "state.ulid.lock"]
+
+    @pytest.mark.parametrize(
+        "contents",
+        [b"", b"\n", b"not-a-ulid\n", b"01ARZ3NDEKTSV4RRFFQ69G5FA\n", b"\xff\xfe"],
+    )
+    def test_corrupted(self, path, contents):
+        """Test that corrupted contents raise instead of reading as MIN."""
+        with open(path, "wb") as f:
+            f.write(contents)
+        checkpoint = pyulid.UlidCheckpoint(path)
+
+        with pytest.raises(ValueError, match="corrupted checkpoint"):
+            checkpoint.get()
+        with pytest.raises(ValueError, match="corrupted checkpoint"):
+            checkpoint.advance(pyulid.ulid())
+        with open(path, "rb") as f:
+            assert f.read() == contents
+
+        checkpoint.reset()
+        assert checkpoint.get() is None
+
+    def test_invalid_ulid(self, path):
+        """Test that advance validates its argument."""
+        with pytest.raises(ValueError):
+            pyulid.UlidCheckpoint(path).advance("invalid")
+        assert pyulid.UlidCheckpoint(path).get() is None
+
+    def test_io_errors(self, path):
+        """Test that filesystem errors surface as OSError."""
+        missing_dir = os.path.join(path, "nested", "state.ulid")
+        with pytest.raises(OSError):
+            pyulid.UlidCheckpoint(missing_dir).advance(pyulid.ulid())
+
+    def test_pathlike(self, path):
+        """Test os.PathLike paths and the repr."""
+        checkpoint = pyulid.UlidCheckpoint(pathlib.Path(path))
+        checkpoint.advance(pyulid.ulid())
+        assert pyulid.UlidCheckpoint(path).get() == checkpoint.get()
+        assert repr(checkpoint) == f"UlidCheckpoint({path!r})"
+
+    def test_concurrent_threads(self, path):
+        """Test that concurrent advances from threads keep the maximum."""
+        values = [pyulid.ulid() for _ in range(400)]
+        checkpoint = pyulid.UlidCheckpoint(path)
+
+        def worker(chunk):
+            for u in chunk:
+                checkpoint.advance(u)
+
+        threads = [
+            threading.Thread(target=worker, args=(values[i::4],)) for i in range(4)
+        ]
+        for t in threads:
+            t.start()
+        for t in threads:
+            t.join()
+        assert checkpoint.get() == max(values)
+
+    def test_concurrent_processes(self, path):
+        """Test that writers in separate processes never move the mark backwards."""
+        env = dict(os.environ, PYTHONPATH=os.pathsep.join(sys.path))
+        writers = [
+            subprocess.Popen(
+                [sys.executable, "-c", WRITER, path],
+                env=env,
+                stdout=subprocess.PIPE,
+                stderr=subprocess.PIPE,
+                text=True,
+            )
+            for _ in range(4)
+        ]
+
+        bests = []
+        for writer in writers:
+            out, err = writer.communicate(timeout=60)
+            assert writer.returncode == 0, err
+            bests.append(out.strip())
+        assert pyulid.UlidCheckpoint(path).get() == max(bests)
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
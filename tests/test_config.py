@@ -0,0 +1,430 @@
+"""
+Configuration tests for PyULID.
+
+Tests configure()/get_config() and the options they control.
+"""
+
+import os
+import sys
+import threading
+import time
+
+import pytest
+import pyulid
+
+
+@pytest.fixture
+def restore_config():
+    """Fixture restoring the configuration after the test."""
+    saved = pyulid.get_config()
+    yield
+    pyulid.configure(**saved)
+
+
+def _burst(duration_s):
+    """Generate ULIDs for at least `duration_s` seconds of wall time."""
+    ulids = []
+    deadline = time.perf_counter() + duration_s
+    while time.perf_counter() < deadline:
+        ulids.extend(pyulid.ulid() for _ in range(100))
+    return ulids
+
+
+class TestConfigure:
+    """Test configure and get_config."""
+
+    def test_defaults(self):
+        """Test the default configuration."""
+        config = pyulid.get_config()
+        assert config == {
+            "hybrid_clock": sys.platform == "win32",
+            "decode_cache": int(os.environ.get("PYULID_DECODE_CACHE", 0)),
+            "clean_words": False,
+            "clock_drift_tolerance_ms": 0,
+            "overflow": "raise",
+        }
+
+    def test_round_trip(self, restore_config):
+        """Test that configure() changes are visible in get_config()."""
+        pyulid.configure(hybrid_clock=True)
+        assert pyulid.get_config()["hybrid_clock"] is True
+        pyulid.configure(hybrid_clock=False)
+        assert pyulid.get_config()["hybrid_clock"] is False
+
+    def test_omitted_options_unchanged(self, restore_config):
+        """Test that options left out keep their value."""
+        pyulid.configure(hybrid_clock=True)
+        pyulid.configure()
+        assert pyulid.get_config()["hybrid_clock"] is True
+
+    def test_keyword_only(self):
+        """Test that options must be passed by keyword."""
+        with pytest.raises(TypeError):
+            pyulid.configure(True)
+        with pytest.raises(TypeError):
+            pyulid.configure(unknown_option=1)
+
+
+@pytest.fixture
+def corpus():
+    """Fixture providing valid, lowercase and invalid ULID strings."""
+    ulids = [pyulid.ulid() for _ in range(20)]
+    return (
+        ulids
+        + [u.lower() for u in ulids]
+        + ["", "invalid", "01ARZ3NDEKTSV4RRFFQ69G5FA", "01ARZ3NDEKTSV4RRFFQ69G5FAU"]
+        + ["8ZZZZZZZZZZZZZZZZZZZZZZZZZ", "01ARZ3NDEKTSV4RRFFQ69G5FAVX"]
+    )
+
+
+def _outcomes(values):
+    """Result or exception (type and message) of every decoding entry point."""
+    outcomes = []
+    for value in values:
+        for func in (
+            pyulid.ulid_timestamp,
+            pyulid.ulid_to_uuid,
+            pyulid.ulid_to_int,
+            pyulid.ulid_from_str,
+            lambda v: str(pyulid.ULID.from_str(v)),
+        ):
+            try:
+                outcomes.append(func(value))
+            except Exception as e:
+                outcomes.append((type(e), str(e)))
+    return outcomes
+
+
+class TestDecodeCache:
+    """Test the opt-in decode cache."""
+
+    def test_identical_results(self, restore_config, corpus):
+        """Test that results and errors are the same with the cache on or off."""
+        pyulid.configure(decode_cache=0)
+        expected = _outcomes(corpus)
+
+        for capacity in (1, 4, 4096):
+            pyulid.configure(decode_cache=capacity)
+            # Twice, so the second pass is served from the cache where possible
+            assert _outcomes(corpus) == expected
+            assert _outcomes(corpus) == expected
+
+    def test_stats(self, restore_config):
+        """Test hit and miss counting."""
+        pyulid.configure(decode_cache=16)
+        assert pyulid.stats()["decode_cache"] == {
+            "hits": 0,
+            "misses": 0,
+            "size": 0,
+            "capacity": 16,
+        }
+
+        u = pyulid.ulid()
+        pyulid.ulid_timestamp(u)
+        pyulid.ulid_to_uuid(u)
+        pyulid.ULID.from_str(u)
+        with pytest.raises(ValueError):
+            pyulid.ulid_timestamp("invalid!invalid!invalid!ab")
+
+        stats = pyulid.stats()["decode_cache"]
+        assert stats["hits"] == 2
+        assert stats["misses"] == 2
+        assert stats["size"] == 1
+
+    def test_disabled(self, restore_config):
+        """Test that a disabled cache stores and counts nothing."""
+        pyulid.configure(decode_cache=0)
+        pyulid.ulid_timestamp(pyulid.ulid())
+        assert pyulid.stats()["decode_cache"] == {
+            "hits": 0,
+            "misses": 0,
+            "size": 0,
+            "capacity": 0,
+        }
+        assert pyulid.get_config()["decode_cache"] == 0
+
+    def test_lru_eviction(self, restore_config):
+        """Test that the least recently used entry is evicted first."""
+        pyulid.configure(decode_cache=2)
+        a, b, c = pyulid.ulid(), pyulid.ulid(), pyulid.ulid()
+        for u in (a, b, a, c):
+            pyulid.ulid_timestamp(u)
+        assert pyulid.stats()["decode_cache"]["size"] == 2
+
+        pyulid.ulid_timestamp(a)
+        assert pyulid.stats()["decode_cache"]["hits"] == 2
+        pyulid.ulid_timestamp(b)
+        assert pyulid.stats()["decode_cache"]["hits"] == 2
+
+    def test_configure_invalidates(self, restore_config):
+        """Test that any configure() call empties the cache."""
+        pyulid.configure(decode_cache=16)
+        pyulid.ulid_timestamp(pyulid.ulid())
+        pyulid.configure(hybrid_clock=pyulid.get_config()["hybrid_clock"])
+
+        stats = pyulid.stats()["decode_cache"]
+        assert stats["size"] == 0
+        assert stats["capacity"] == 16
+        assert pyulid.get_config()["decode_cache"] == 16
+
+    def test_concurrent(self, restore_config):
+        """Test that threads sharing a small cache always get correct values."""
+        pyulid.configure(decode_cache=8)
+        ulids = [pyulid.ulid_with_timestamp(1672531200000 + i) for i in range(32)]
+        errors = []
+
+        def worker():
+            for _ in range(200):
+                for i, u in enumerate(ulids):
+                    if pyulid.ulid_timestamp(u) != 1672531200000 + i:
+                        errors.append(u)
+
+        threads = [threading.Thread(target=worker) for _ in range(4)]
+        for t in threads:
+            t.start()
+        for t in threads:
+            t.join()
+        assert errors == []
+
+
+def _absent_words(candidates):
+    """Words from `candidates` that can't occur in the current timestamp characters."""
+    prefix = pyulid.ulid()[:10]
+    return [w for w in candidates if not set(w) & set(prefix)]
+
+
+def _filter_words(words):
+    """Turn filtering on from a fresh millisecond.
+
+    IDs issued earlier in the current millisecond may have left no clean values above
+    them, and ordering rules out going back.
+    """
+    pyulid.configure(clean_words=words)
+    start = time.time_ns() // 1_000_000
+    while time.time_ns() // 1_000_000 <= start:
+        time.sleep(0.001)
+
+
+class TestClockDrift:
+    """Test clock_drift_tolerance_ms, with clock steps injected through UlidGenerator."""
+
+    T0 = 1672531200000
+
+    def _stepped_back(self, ms):
+        gen = pyulid.UlidGenerator(1, self.T0)
+        first = gen.generate()
+        gen.set_timestamp(self.T0 - ms)
+        return gen, first
+
+    def test_default_raises(self):
+        """Test that any backwards step raises by default."""
+        gen, _ = self._stepped_back(1)
+        with pytest.raises(RuntimeError, match="backwards"):
+            gen.generate()
+
+    def test_within_tolerance(self, restore_config):
+        """Test that a small step back holds the last timestamp and keeps counting."""
+        pyulid.configure(clock_drift_tolerance_ms=5)
+        assert pyulid.get_config()["clock_drift_tolerance_ms"] == 5
+        gen, first = self._stepped_back(5)
+
+        held = [gen.generate() for _ in range(3)]
+        assert [pyulid.ulid_timestamp(u) for u in held] == [self.T0] * 3
+        assert [pyulid.ulid_random(u) for u in held] == [
+            pyulid.ulid_random(first) + i for i in (1, 2, 3)
+        ]
+
+        # Once the clock catches up it takes over again
+        gen.set_timestamp(self.T0 + 1)
+        assert pyulid.ulid_timestamp(gen.generate()) == self.T0 + 1
+
+    def test_beyond_tolerance(self, restore_config):
+        """Test that larger steps still raise."""
+        pyulid.configure(clock_drift_tolerance_ms=5)
+        gen, _ = self._stepped_back(6)
+        with pytest.raises(RuntimeError, match="backwards"):
+            gen.generate()
+
+    def test_unset_option_unchanged(self, restore_config):
+        """Test that configure() without the option keeps the tolerance."""
+        pyulid.configure(clock_drift_tolerance_ms=10)
+        pyulid.configure(hybrid_clock=pyulid.get_config()["hybrid_clock"])
+        assert pyulid.get_config()["clock_drift_tolerance_ms"] == 10
+        with pytest.raises(OverflowError):
+            pyulid.configure(clock_drift_tolerance_ms=-1)
+
+
+def _pin_near_overflow(room):
+    """Pin generation to a future millisecond with `room` random values left."""
+    floor = int(time.time() * 1000) + 300
+    near_max = pyulid.ulid_set_random(
+        pyulid.ulid_with_timestamp(floor), 2**80 - 1 - room
+    )
+    pyulid.set_minimum_timestamp(pyulid.ULID(near_max))
+    return floor
+
+
+def _wait_past(timestamp):
+    """Sleep until the wall clock is past `timestamp` (ms), lifting the floor."""
+    while time.time() * 1000 <= timestamp + 1:
+        time.sleep(0.005)
+
+
+class TestOverflowPolicy:
+    """Test overflow="raise" / "carry", exhausting the random component via a floor."""
+
+    def test_round_trip(self, restore_config):
+        """Test that the option round-trips and rejects unknown policies."""
+        pyulid.configure(overflow="carry")
+        assert pyulid.get_config()["overflow"] == "carry"
+        pyulid.configure(overflow="raise")
+        assert pyulid.get_config()["overflow"] == "raise"
+        with pytest.raises(ValueError, match="'raise' or 'carry'"):
+            pyulid.configure(overflow="wrap")
+        assert pyulid.get_config()["overflow"] == "raise"
+
+    def test_raise(self, restore_config):
+        """Test that the default policy raises once the random component runs out."""
+        floor = _pin_near_overflow(2)
+        try:
+            pyulid.ulid()
+            pyulid.ulid()
+            with pytest.raises(RuntimeError, match="overflow"):
+                pyulid.ulid()
+        finally:
+            _wait_past(floor)
+
+    def test_carry(self, restore_config):
+        """Test that carry moves on to the next millisecond and stays monotonic."""
+        pyulid.configure(overflow="carry")
+        floor = _pin_near_overflow(2)
+        try:
+            ulids = [pyulid.ulid() for _ in range(5)]
+            timestamps = [pyulid.ulid_timestamp(u) for u in ulids]
+            assert timestamps == [floor] * 2 + [floor + 1] * 3
+            assert pyulid.ulid_random(ulids[1]) == 2**80 - 1
+            assert ulids == sorted(ulids) and len(set(ulids)) == 5
+            # Still held at the carried millisecond while the clock is behind it
+            assert pyulid.ulid_timestamp(pyulid.ulid()) == floor + 1
+        finally:
+            _wait_past(floor + 1)
+
+    def test_carry_in_batches(self, restore_config):
+        """Test that batches carry instead of failing part way."""
+        pyulid.configure(overflow="carry")
+        floor = _pin_near_overflow(4)
+        try:
+            batch = pyulid.ulid_batch(100)
+            timestamps = [pyulid.ulid_timestamp(u) for u in batch]
+            assert timestamps == [floor] * 4 + [floor + 1] * 96
+            assert batch == sorted(batch) and len(set(batch)) == 100
+        finally:
+            _wait_past(floor + 1)
+
+
+class TestCleanWords:
+    """Test the blocked-word filter for generated ULIDs."""
+
+    def test_round_trip(self, restore_config):
+        """Test that the option round-trips through get_config()."""
+        pyulid.configure(clean_words=True)
+        assert pyulid.get_config()["clean_words"] is True
+        pyulid.configure(clean_words=["boob", "zz"])
+        assert pyulid.get_config()["clean_words"] == ["boob", "zz"]
+        pyulid.configure(clean_words=False)
+        assert pyulid.get_config()["clean_words"] is False
+
+    def test_custom_words_filtered(self, restore_config):
+        """Test that generated ULIDs avoid the words while staying ordered."""
+        words = _absent_words(["ZZ", "YY", "XX", "WW", "TT", "SS"])
+        assert words
+
+        unfiltered = [pyulid.ulid() for _ in range(3000)]
+        assert any(w in u for u in unfiltered for w in words)
+
+        _filter_words(words)
+        ulids = [pyulid.ulid() for _ in range(3000)]
+        assert not [u for u in ulids if any(w in u for w in words)]
+        assert ulids == sorted(ulids)
+        assert len(set(ulids)) == len(ulids)
+
+    def test_all_generators_filtered(self, restore_config):
+        """Test batch and fixed-timestamp generation."""
+        words = _absent_words(["ZZ", "YY", "XX", "WW", "TT", "SS"])
+        _filter_words(words)
+
+        batch = pyulid.ulid_batch_array(3000).to_list()
+        fixed = [pyulid.ulid_with_timestamp(0) for _ in range(3000)]
+        for ulids in (batch, fixed):
+            assert not [u for u in ulids if any(w in u for w in words)]
+        assert batch == sorted(batch)
+
+    def test_lookalikes_and_case(self, restore_config):
+        """Test that words match case-insensitively with Crockford look-alikes."""
+        # Lowercase "uu" and "ii" are read the way "VV" and "11" are written; timestamp 0
+        # encodes as all zeros, so neither can sit in the unfixable timestamp part
+        pyulid.configure(clean_words=["uu", "ii"])
+
+        ulids = [pyulid.ulid_with_timestamp(0) for _ in range(3000)]
+        assert not [u for u in ulids if "VV" in u or "11" in u]
+
+    def test_ulid_clean(self, restore_config):
+        """Test the one-shot form with the built-in and the configured list."""
+        pyulid.configure(clean_words=False)
+        ulids = [pyulid.ulid_clean() for _ in range(5000)]
+        assert not [u for u in ulids if "ASS" in u or "SEX" in u or "CVNT" in u]
+        assert ulids == sorted(ulids)
+        assert pyulid.get_config()["clean_words"] is False
+
+        words = _absent_words(["ZZ", "YY", "XX"])
+        _filter_words(words)
+        ulids = [pyulid.ulid_clean() for _ in range(3000)]
+        assert not [u for u in ulids if any(w in u for w in words)]
+
+    def test_word_in_timestamp(self, restore_config):
+        """Test that a word inside the timestamp is returned rather than looping."""
+        prefix = pyulid.ulid()[:3]
+        pyulid.configure(clean_words=[prefix])
+        assert pyulid.ulid().startswith(prefix)
+
+    def test_invalid(self, restore_config):
+        """Test that invalid word lists raise and leave the option unchanged."""
+        pyulid.configure(clean_words=True)
+        with pytest.raises(ValueError):
+            pyulid.configure(clean_words=["f-k"])
+        with pytest.raises(ValueError):
+            pyulid.configure(clean_words=[""])
+        with pytest.raises(TypeError):
+            pyulid.configure(clean_words="boob")
+        with pytest.raises(TypeError):
+            pyulid.configure(clean_words=1)
+        assert pyulid.get_config()["clean_words"] is True
+
+
+class TestHybridClock:
+    """Test the interpolating clock source."""
+
+    @pytest.mark.parametrize("hybrid", [True, False])
+    def test_burst_spans_milliseconds(self, restore_config, hybrid):
+        """Test that a 50 ms burst sees several distinct milliseconds."""
+        pyulid.configure(hybrid_clock=hybrid)
+        ulids = _burst(0.05)
+
+        timestamps = {pyulid.ulid_timestamp(u) for u in ulids}
+        assert len(timestamps) > 1
+        assert ulids == sorted(ulids)
+        assert len(set(ulids)) == len(ulids)
+
+    def test_tracks_wall_clock(self, restore_config):
+        """Test that the hybrid clock stays close to the system clock."""
+        pyulid.configure(hybrid_clock=True)
+        for _ in range(100):
+            before = time.time_ns() // 1_000_000
+            timestamp = pyulid.ulid_timestamp(pyulid.ulid())
+            after = time.time_ns() // 1_000_000
+            # Interpolation may run ahead of a coarse system clock by under one tick
+            assert before <= timestamp <= after + 100
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
@@ -0,0 +1,124 @@
+"""
+Stream conversion tests for PyULID.
+
+Tests convert_stream over files, paths and line-ending variations.
+"""
+
+import io
+import os
+import tempfile
+import uuid
+
+import pytest
+import pyulid
+
+
+def _convert(data, **kwargs):
+    """Run convert_stream over in-memory bytes, returning (output, report)."""
+    dst = io.BytesIO()
+    report = pyulid.convert_stream(io.BytesIO(data), dst, **kwargs)
+    return dst.getvalue(), report
+
+
+class TestConvertStream:
+    """Test convert_stream."""
+
+    def test_dashed_and_bare(self):
+        """Test that both UUID spellings convert like uuid_to_ulid."""
+        uuids = [str(uuid.uuid4()) for _ in range(10)]
+        bare = [u.replace("-", "").upper() for u in uuids[1::2]]
+        lines = [line for pair in zip(uuids[::2], bare) for line in pair]
+        out, report = _convert("\n".join(lines).encode() + b"\n")
+
+        assert out.decode().splitlines() == [pyulid.uuid_to_ulid(u) for u in uuids]
+        assert report == {"converted": 10, "failed": 0, "failures": []}
+
+    @pytest.mark.parametrize("ending", [b"\n", b"\r\n"])
+    @pytest.mark.parametrize("trailing", [True, False])
+    def test_line_endings(self, ending, trailing):
+        """Test LF and CRLF endings, with and without a trailing newline."""
+        uuids = [str(uuid.uuid4()) for _ in range(3)]
+        data = ending.join(u.encode() for u in uuids) + (ending if trailing else b"")
+        out, report = _convert(data)
+
+        assert out == "".join(pyulid.uuid_to_ulid(u) + "\n" for u in uuids).encode()
+        assert report["converted"] == 3
+        assert report["failed"] == 0
+
+    def test_failures_keep_alignment(self):
+        """Test that failed lines leave empty output lines and are reported."""
+        good = str(uuid.uuid4())
+        data = f"{good}\nnot-a-uuid\n\n  {good}  \n{'f' * 33}\n".encode()
+        out, report = _convert(data)
+
+        ulid_str = pyulid.uuid_to_ulid(good)
+        assert out.decode().split("\n") == [ulid_str, "", "", ulid_str, "", ""]
+        assert report["converted"] == 2
+        assert report["failed"] == 3
+        assert report["failures"] == [
+            {"line": 2, "value": "not-a-uuid", "error": "not a UUID"},
+            {"line": 3, "value": "", "error": "empty line"},
+            {"line": 5, "value": "f" * 33, "error": "not a UUID"},
+        ]
+
+    def test_failure_sample_bounded(self):
+        """Test that only max_failures failures are kept, but all are counted."""
+        out, report = _convert(b"bad\n" * 500, max_failures=5)
+        assert report["failed"] == 500
+        assert [f["line"] for f in report["failures"]] == [1, 2, 3, 4, 5]
+        assert out == b"\n" * 500
+
+    def test_overlong_line(self):
+        """Test that a huge line fails without being buffered whole."""
+        good = str(uuid.uuid4())
+        data = b"0" * 3_000_000 + b"\n" + good.encode() + b"\n"
+        out, report = _convert(data)
+
+        assert out == b"\n" + pyulid.uuid_to_ulid(good).encode() + b"\n"
+        assert report["failures"][0]["line"] == 1
+        assert report["failures"][0]["error"] == "line too long"
+        assert len(report["failures"][0]["value"]) == 64
+
+    def test_pair_output(self):
+        """Test the uuid,ulid mapping format."""
+        good = str(uuid.uuid4())
+        out, report = _convert(f"{good}\nbad\n".encode(), output="pair")
+        assert out == f"{good},{pyulid.uuid_to_ulid(good)}\nbad,\n".encode()
+        assert report["failed"] == 1
+
+    def test_empty_input(self):
+        """Test that an empty source produces no output."""
+        assert _convert(b"") == (b"", {"converted": 0, "failed": 0, "failures": []})
+
+    def test_paths_and_chunk_boundaries(self):
+        """Test path arguments over an input spanning many read chunks."""
+        uuids = [str(uuid.uuid4()) for _ in range(100_000)]
+        with tempfile.TemporaryDirectory() as directory:
+            src = os.path.join(directory, "in.txt")
+            dst = os.path.join(directory, "out.txt")
+            with open(src, "w") as f:
+                f.write("\n".join(uuids))
+
+            report = pyulid.convert_stream(src, dst)
+            with open(dst) as f:
+                converted = f.read().splitlines()
+
+        assert report["converted"] == 100_000
+        assert converted[0] == pyulid.uuid_to_ulid(uuids[0])
+        assert converted[-1] == pyulid.uuid_to_ulid(uuids[-1])
+        assert converted == [pyulid.uuid_to_ulid(u) for u in uuids]
+
+    def test_invalid_arguments(self):
+        """Test argument validation."""
+        with pytest.raises(ValueError):
+            _convert(b"", input_format="csv")
+        with pytest.raises(ValueError):
+            _convert(b"", output="uuid")
+        with pytest.raises(TypeError, match="binary mode"):
+            pyulid.convert_stream(io.StringIO("x\n"), io.BytesIO())
+        with pytest.raises(OSError):
+            pyulid.convert_stream("/nonexistent/uuids.txt", io.BytesIO())
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
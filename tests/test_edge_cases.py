@@ -4,6 +4,14 @@ Edge case and error handling tests for PyULID.
 Tests boundary conditions, error scenarios, and edge cases.
 """
 
+import _thread
+import itertools
+import random
+import signal
+import threading
+import time
+import uuid
+
 import pytest
 import pyulid
 
@@ -112,23 +120,14 @@ class TestErrorHandling:
                 pyulid.ULID(invalid_ulid)
 
     def test_large_timestamp_overflow(self):
-        """Test handling of timestamps larger than 48-bit limit."""
-        # Timestamp larger than 48-bit (should be truncated/handled gracefully)
-        huge_timestamp = 2**50  # Larger than 48-bit limit
-
-        # Should not crash - may truncate or handle gracefully
-        ulid_str = pyulid.ulid_with_timestamp(huge_timestamp)
-        assert pyulid.ulid_is_valid(ulid_str)
-
-        # Extracted timestamp should be within valid range
-        extracted = pyulid.ulid_timestamp(ulid_str)
-        max_48_bit = (2**48) - 1
-        assert extracted <= max_48_bit
+        """Test that timestamps beyond the 48-bit limit raise instead of wrapping."""
+        for huge_timestamp in [2**48, 2**50, 2**64, 2**200]:
+            with pytest.raises(ValueError, match="10889-08-02"):
+                pyulid.ulid_with_timestamp(huge_timestamp)
 
     def test_negative_timestamp(self):
-        """Test handling of negative timestamps."""
-        # Negative timestamps should be handled gracefully
-        with pytest.raises((ValueError, OverflowError)):
+        """Test that negative timestamps raise ValueError."""
+        with pytest.raises(ValueError, match=r"0 <= ms < 2\*\*48"):
             pyulid.ulid_with_timestamp(-1)
 
 
@@ -164,6 +163,34 @@ class TestUUIDConversion:
             with pytest.raises(ValueError):
                 pyulid.uuid_to_ulid(invalid_uuid)
 
+    def test_uuid_object_roundtrip(self):
+        """Test ULID to uuid.UUID and back conversion."""
+        original_ulid = pyulid.ulid()
+
+        uuid_obj = pyulid.ulid_to_uuid_obj(original_ulid)
+        assert isinstance(uuid_obj, uuid.UUID)
+        assert str(uuid_obj) == pyulid.ulid_to_uuid(original_ulid)
+        assert uuid_obj.int == pyulid.ulid_to_int(original_ulid)
+
+        assert pyulid.uuid_to_ulid(uuid_obj) == original_ulid
+
+    def test_nil_uuid_object(self):
+        """Test the nil UUID converts to the zero ULID and back."""
+        assert pyulid.uuid_to_ulid(uuid.UUID(int=0)) == "0" * 26
+        assert pyulid.ulid_to_uuid_obj("0" * 26) == uuid.UUID(int=0)
+
+    def test_mixed_case_uuid(self):
+        """Test mixed-case hex strings convert like uuid.UUID objects."""
+        value = uuid.UUID("0123ABCD-89ab-CDEF-0123-456789AbCdEf")
+        text = "0123ABCD-89ab-CDEF-0123-456789AbCdEf"
+        assert pyulid.uuid_to_ulid(text) == pyulid.uuid_to_ulid(value)
+
+    def test_uuid_to_ulid_rejects_other_types(self):
+        """Test uuid_to_ulid only accepts strings and uuid.UUID objects."""
+        for value in [0, b"\x00" * 16, None]:
+            with pytest.raises(TypeError):
+                pyulid.uuid_to_ulid(value)
+
     def test_invalid_ulid_to_uuid(self):
         """Test invalid ULID to UUID conversion."""
         invalid_ulids = [
@@ -177,6 +204,94 @@ class TestUUIDConversion:
                 pyulid.ulid_to_uuid(invalid_ulid)
 
 
+class TestUUIDv7Conversion:
+    """Test ULID to UUIDv7 conversion."""
+
+    def test_valid_uuid7(self):
+        """Test the result is an RFC 9562 version 7 UUID."""
+        value = uuid.UUID(pyulid.ulid_to_uuid7(pyulid.ulid()))
+        assert value.version == 7
+        assert value.variant == uuid.RFC_4122
+
+    def test_timestamp_preserved(self):
+        """Test the timestamp survives the conversion in both directions."""
+        original = pyulid.ulid()
+        uuid7 = pyulid.ulid_to_uuid7(original)
+        assert int(uuid7.replace("-", "")[:12], 16) == pyulid.ulid_timestamp(original)
+        converted = pyulid.uuid7_to_ulid(uuid7)
+        assert pyulid.ulid_timestamp(converted) == pyulid.ulid_timestamp(original)
+
+    def test_roundtrip_drops_low_random_bits(self):
+        """Test a round trip keeps all but the lowest 6 random bits."""
+        original = pyulid.ulid()
+        converted = pyulid.uuid7_to_ulid(pyulid.ulid_to_uuid7(original))
+        assert pyulid.ulid_to_int(converted) == pyulid.ulid_to_int(original) & ~0x3F
+        assert pyulid.ulid_to_uuid7(converted) == pyulid.ulid_to_uuid7(original)
+
+    def test_ordering_preserved(self):
+        """Test UUIDv7 strings sort like the ULIDs they came from."""
+        ulids = sorted(pyulid.ulid_with_timestamp(ts) for ts in range(0, 10**6, 9973))
+        assert [pyulid.ulid_to_uuid7(u) for u in ulids] == sorted(
+            pyulid.ulid_to_uuid7(u) for u in ulids
+        )
+
+    def test_accepts_uuid_objects(self):
+        """Test uuid7_to_ulid takes uuid.UUID objects like uuid_to_ulid."""
+        uuid7 = pyulid.ulid_to_uuid7(pyulid.ulid())
+        assert pyulid.uuid7_to_ulid(uuid.UUID(uuid7)) == pyulid.uuid7_to_ulid(uuid7)
+
+    def test_rejects_other_versions(self):
+        """Test uuid7_to_ulid rejects UUIDs whose version isn't 7."""
+        with pytest.raises(ValueError, match="version 4"):
+            pyulid.uuid7_to_ulid(uuid.uuid4())
+        with pytest.raises(ValueError, match="version"):
+            pyulid.uuid7_to_ulid(pyulid.ulid_to_uuid("0" * 26))
+
+
+
+class TestUuidTimestamp:
+    """Test uuid_timestamp on UUID forms of ULIDs and on UUIDv7s."""
+
+    def test_round_trip_through_ulid_to_uuid(self):
+        """Test exact agreement with the timestamp the ULID was made with."""
+        for ts in [0, 1, 1672531200000, 2**48 - 1] + list(range(0, 10**9, 999983)):
+            ulid_str = pyulid.ulid_with_timestamp(ts)
+            assert pyulid.uuid_timestamp(pyulid.ulid_to_uuid(ulid_str)) == ts
+
+    def test_uuid7(self):
+        """Test that real UUIDv7 values give their unix_ts_ms field."""
+        for ts in [0, 1672531200000, 2**48 - 1]:
+            uuid7 = pyulid.ulid_to_uuid7(pyulid.ulid_with_timestamp(ts))
+            assert pyulid.uuid_timestamp(uuid7, require_v7=True) == ts
+
+    def test_input_forms(self):
+        """Test hyphenated, bare-hex and uuid.UUID inputs of either case."""
+        ulid_str = pyulid.ulid_with_timestamp(1672531200000)
+        hyphenated = pyulid.ulid_to_uuid(ulid_str)
+        for form in [
+            hyphenated,
+            hyphenated.replace("-", ""),
+            hyphenated.upper(),
+            uuid.UUID(hyphenated),
+        ]:
+            assert pyulid.uuid_timestamp(form) == 1672531200000
+
+    def test_require_v7(self):
+        """Test that require_v7 rejects v4 UUIDs, which are random up front."""
+        v4 = uuid.uuid4()
+        assert 0 <= pyulid.uuid_timestamp(v4) < 2**48
+        with pytest.raises(ValueError, match="version 4"):
+            pyulid.uuid_timestamp(v4, require_v7=True)
+
+    def test_invalid_input(self):
+        """Test that malformed UUIDs raise."""
+        with pytest.raises(ValueError, match="32 hex characters"):
+            pyulid.uuid_timestamp("1234")
+        with pytest.raises(ValueError, match="hex"):
+            pyulid.uuid_timestamp("g" * 32)
+        with pytest.raises(TypeError):
+            pyulid.uuid_timestamp(12345)
+
 class TestFromStrFunction:
     """Test ulid_from_str function edge cases."""
 
@@ -266,6 +381,588 @@ class TestBase32EdgeCases:
                 pyulid.decode_base32(invalid_str)
 
 
+class TestUnvalidatedFastPath:
+    """Test the validate=False fast path on conversion functions."""
+
+    FUNCTIONS = ["ulid_timestamp", "ulid_random", "ulid_to_uuid", "ulid_to_int"]
+
+    def test_identical_results_on_valid_input(self):
+        """Test that both paths agree on valid input, any case."""
+        samples = [pyulid.ulid() for _ in range(200)] + ["0" * 26, "7" + "Z" * 25]
+        for name in self.FUNCTIONS:
+            func = getattr(pyulid, name)
+            for ulid_str in samples:
+                expected = func(ulid_str)
+                assert func(ulid_str, validate=False) == expected
+                assert func(ulid_str.lower(), validate=False) == expected
+
+    def test_default_still_validates(self):
+        """Test that validation remains on unless disabled."""
+        for name in self.FUNCTIONS:
+            func = getattr(pyulid, name)
+            with pytest.raises(ValueError):
+                func("invalid")
+            with pytest.raises(ValueError):
+                func("invalid", validate=True)
+
+    def test_garbage_is_memory_safe(self):
+        """Test that unvalidated garbage returns something instead of crashing."""
+        garbage = ["", "!", "I" * 26, "x" * 1000, "\x00" * 26, "\u00e9" * 13]
+        garbage.append("🎉" * 7)
+        for name in self.FUNCTIONS:
+            func = getattr(pyulid, name)
+            for value in garbage:
+                func(value, validate=False)
+
+    def test_type_errors_unchanged(self):
+        """Test that both paths reject other types with the usual message."""
+        for name in self.FUNCTIONS:
+            func = getattr(pyulid, name)
+            for validate in (True, False):
+                with pytest.raises(TypeError, match="argument 'ulid_str': "):
+                    func(123, validate=validate)
+
+    def test_validate_is_keyword_only(self):
+        """Test that validate can't be passed positionally."""
+        with pytest.raises(TypeError):
+            pyulid.ulid_timestamp(pyulid.ulid(), False)
+
+
+class TestSelfCheck:
+    """Test the startup self_check."""
+
+    CHECKS = {"entropy", "clock", "roundtrip", "monotonic"}
+
+    def test_report_structure(self):
+        """Test that every check is reported and passes on a healthy host."""
+        report = pyulid.self_check()
+
+        assert set(report) == self.CHECKS
+        for result in report.values():
+            assert result["passed"] is True
+            assert isinstance(result["detail"], str) and result["detail"]
+
+    def test_no_raise_mode(self):
+        """Test that raise_on_failure=False returns the same report shape."""
+        report = pyulid.self_check(raise_on_failure=False)
+        assert set(report) == self.CHECKS
+        assert all(result["passed"] for result in report.values())
+
+    def test_only_consumes_two_ids(self):
+        """Test that the shared state advances exactly as two ulid() calls would."""
+        for _ in range(100):
+            before = pyulid.ulid()
+            pyulid.self_check()
+            after = pyulid.ulid()
+
+            assert after > before
+            if after[:10] == before[:10]:
+                diff = pyulid.ulid_random(after) - pyulid.ulid_random(before)
+                assert diff == 3
+                return
+        pytest.skip("clock advanced during every attempt")
+
+
+class TestEntropyCheck:
+    """Test the statistical ulid_entropy_check."""
+
+    CHECKS = {"bit_frequency", "serial_correlation", "byte_chi_square"}
+
+    @staticmethod
+    def _spread(randoms):
+        # One ULID per millisecond so every random component counts as fresh
+        return [
+            pyulid.ulid_set_random(pyulid.ulid_with_timestamp(1_000_000 + i), r)
+            for i, r in enumerate(randoms)
+        ]
+
+    def test_default_passes_quickly(self):
+        """Test the default run on a healthy host."""
+        start = time.perf_counter()
+        report = pyulid.ulid_entropy_check()
+        assert time.perf_counter() - start < 1.0
+
+        assert report["samples"] == 100_000
+        assert report["passed"] is True
+        assert report["threshold"] == 5.0
+        for name in self.CHECKS:
+            assert report[name]["passed"] is True
+            assert 0 <= report[name]["z"] < 5.0
+        assert 0 <= report["bit_frequency"]["worst_bit"] < 80
+        assert report["byte_chi_square"]["df"] == 255
+        assert abs(report["serial_correlation"]["coefficient"]) < 0.02
+
+    def test_existing_ulids(self):
+        """Test that a supplied list of well-spread ULIDs passes."""
+        ulids = self._spread(random.getrandbits(80) for _ in range(5000))
+        report = pyulid.ulid_entropy_check(ulids)
+        assert report["samples"] == 5000
+        assert report["passed"] is True
+
+    def test_only_first_per_millisecond(self):
+        """Test that monotonic increments within a millisecond are skipped."""
+        ulids = self._spread(random.getrandbits(80) for _ in range(2000))
+        bursts = [u for first in ulids for u in (first, pyulid.ulid_next(first))]
+        assert pyulid.ulid_entropy_check(bursts)["samples"] == 2000
+
+        with pytest.raises(ValueError, match="at least 1000"):
+            pyulid.ulid_entropy_check([pyulid.ulid() for _ in range(5000)][:999])
+
+    def test_detects_constant_source(self):
+        """Test that a stuck source fails every check without raising."""
+        report = pyulid.ulid_entropy_check(self._spread([12345] * 2000))
+        assert report["passed"] is False
+        assert not any(report[name]["passed"] for name in self.CHECKS)
+
+    def test_detects_counter(self):
+        """Test that a slowly counting source fails the serial correlation."""
+        ulids = self._spread(
+            (i << 60) | random.getrandbits(60) for i in range(2000)
+        )
+        report = pyulid.ulid_entropy_check(ulids)
+        assert report["serial_correlation"]["passed"] is False
+        assert report["passed"] is False
+
+    def test_raise_on_failure(self):
+        """Test the opt-in exception naming the failed checks."""
+        ulids = self._spread([0] * 2000)
+        with pytest.raises(RuntimeError, match="bit_frequency"):
+            pyulid.ulid_entropy_check(ulids, raise_on_failure=True)
+
+        report = pyulid.ulid_entropy_check(10_000, raise_on_failure=True)
+        assert report["passed"] is True
+
+    def test_invalid(self):
+        """Test argument validation."""
+        with pytest.raises(ValueError):
+            pyulid.ulid_entropy_check(999)
+        with pytest.raises(ValueError, match="index 1"):
+            pyulid.ulid_entropy_check([pyulid.ulid(), "invalid"])
+        with pytest.raises(pyulid.UlidDecodeError, match="index 1.*0-7"):
+            pyulid.ulid_entropy_check([pyulid.ulid(), "Z" * 26])
+
+
+class TestReseed:
+    """Test reseed() for snapshot/clone safety."""
+
+    def test_preserves_monotonic_order(self):
+        """Test that reseeding never breaks ordering."""
+        previous = pyulid.ulid()
+        for _ in range(1000):
+            pyulid.reseed()
+            current = pyulid.ulid()
+            assert current > previous
+            previous = current
+
+    def test_jumps_within_same_millisecond(self):
+        """Test that the next ULID in the same millisecond is not just last + 1."""
+        for _ in range(100):
+            before = pyulid.ulid()
+            pyulid.reseed()
+            after = pyulid.ulid()
+
+            if after[:10] == before[:10]:
+                diff = pyulid.ulid_random(after) - pyulid.ulid_random(before)
+                assert diff > 1
+                return
+        pytest.skip("clock advanced during every attempt")
+
+    def test_changes_subsequent_randomness(self):
+        """Test that values after reseed() are fresh and distinct."""
+        before = {pyulid.ulid_random(pyulid.ulid()) for _ in range(100)}
+        pyulid.reseed()
+        after = {pyulid.ulid_random(pyulid.ulid()) for _ in range(100)}
+
+        assert not before & after
+        assert len(after) == 100
+
+    def test_returns_none(self):
+        """Test the call signature."""
+        assert pyulid.reseed() is None
+
+
+class _CountingSource:
+    """Entropy source handing out 1, 2, 3, ... and counting its calls."""
+
+    def __init__(self):
+        self.calls = 0
+
+    def __call__(self):
+        self.calls += 1
+        return self.calls
+
+
+@pytest.fixture
+def frozen_source_clock():
+    """Fixture freezing the clock ahead of real time and resetting the source after."""
+    start = int(time.time() * 1000) + 200
+    pyulid._freeze_time(start)
+    try:
+        yield start
+    finally:
+        pyulid.set_entropy_source(None)
+        pyulid._unfreeze_time()
+        while int(time.time() * 1000) <= start + 10:
+            time.sleep(0.001)
+
+
+class TestEntropySource:
+    """Test set_entropy_source() for caller-supplied randomness."""
+
+    def test_once_per_millisecond(self, frozen_source_clock):
+        """Test ulid() draws on each new millisecond and increments within one."""
+        source = _CountingSource()
+        pyulid.set_entropy_source(source)
+
+        step = frozen_source_clock
+        ulids = []
+        for i in range(3):
+            pyulid._freeze_time(step + i)
+            ulids.extend(pyulid.ulid() for _ in range(3))
+
+        assert source.calls == 3
+        assert [pyulid.ulid_random(u) for u in ulids] == [1, 2, 3, 2, 3, 4, 3, 4, 5]
+        assert ulids == sorted(ulids)
+
+    def test_bytes_return(self, frozen_source_clock):
+        """Test that 10 bytes are used as the big-endian random component."""
+        pyulid.set_entropy_source(lambda: bytes(range(1, 11)))
+        pyulid._freeze_time(frozen_source_clock + 1)
+        assert pyulid.ulid_random_bytes(pyulid.ulid()) == bytes(range(1, 11))
+        assert pyulid.ulid_random(pyulid.ulid_with_timestamp(1000)) == int.from_bytes(
+            bytes(range(1, 11)), "big"
+        )
+
+    def test_batch_draws_without_gil(self, frozen_source_clock):
+        """Test the batch functions, which generate with the GIL released."""
+        source = _CountingSource()
+        pyulid.set_entropy_source(source)
+        pyulid._freeze_time(frozen_source_clock + 1)
+
+        batch = pyulid.ulid_batch(5)
+        assert source.calls == 1
+        assert [pyulid.ulid_random(u) for u in batch] == [1, 2, 3, 4, 5]
+
+    def test_with_timestamp_draws_every_call(self):
+        """Test that ulid_with_timestamp() and its variants draw once per ULID."""
+        source = _CountingSource()
+        pyulid.set_entropy_source(source)
+        try:
+            assert pyulid.ulid_random(pyulid.ulid_with_timestamp(1000)) == 1
+            assert pyulid.ulid_random(pyulid.ulid_with_timestamp(1000)) == 2
+            assert pyulid.ulid_random(pyulid.ulid_non_monotonic()) == 3
+            assert pyulid.ulid_random(pyulid.ulid_at(1.0)) == 4
+        finally:
+            pyulid.set_entropy_source(None)
+        assert source.calls == 4
+
+    def test_none_restores_builtin(self):
+        """Test that None goes back to the built-in RNG."""
+        source = _CountingSource()
+        pyulid.set_entropy_source(source)
+        pyulid.set_entropy_source(None)
+        randoms = {pyulid.ulid_random(pyulid.ulid_with_timestamp(1000)) for _ in range(10)}
+        assert source.calls == 0
+        assert len(randoms) == 10
+
+    def test_exception_propagates(self, frozen_source_clock):
+        """Test that an exception from the source comes out of the generating call."""
+
+        def failing():
+            raise OSError("hardware RNG unavailable")
+
+        pyulid.set_entropy_source(failing)
+        pyulid._freeze_time(frozen_source_clock + 1)
+        for generate in (pyulid.ulid, pyulid.ulid_bytes, lambda: pyulid.ulid_batch(3)):
+            with pytest.raises(OSError, match="hardware RNG unavailable"):
+                generate()
+        with pytest.raises(OSError, match="hardware RNG unavailable"):
+            pyulid.ulid_with_timestamp(1000)
+
+        pyulid.set_entropy_source(lambda: 7)
+        assert pyulid.ulid_random(pyulid.ulid()) == 7
+
+    def test_invalid_returns(self, frozen_source_clock):
+        """Test wrong-sized and wrong-typed returns raise instead of panicking."""
+        pyulid._freeze_time(frozen_source_clock + 1)
+        for value in [b"short", bytes(11), 2**80, -1]:
+            pyulid.set_entropy_source(lambda value=value: value)
+            with pytest.raises(ValueError, match="entropy source returned"):
+                pyulid.ulid()
+        for value in [None, "0123456789", 1.5]:
+            pyulid.set_entropy_source(lambda value=value: value)
+            with pytest.raises(TypeError, match="entropy source returned"):
+                pyulid.ulid_with_timestamp(1000)
+
+    def test_reentrant_source(self, frozen_source_clock):
+        """Test a source that generates ULIDs itself raises rather than recursing."""
+        pyulid.set_entropy_source(lambda: pyulid.ulid_random(pyulid.ulid()))
+        pyulid._freeze_time(frozen_source_clock + 1)
+        with pytest.raises(RuntimeError, match="can't generate ULIDs itself"):
+            pyulid.ulid()
+
+    def test_not_callable(self):
+        """Test that a non-callable source is rejected."""
+        with pytest.raises(TypeError, match="must be callable"):
+            pyulid.set_entropy_source(b"0123456789")
+
+    def test_excludes_clean_words(self):
+        """Test the source and clean-word filtering can't be combined."""
+        pyulid.configure(clean_words=True)
+        try:
+            with pytest.raises(ValueError, match="clean_words"):
+                pyulid.set_entropy_source(lambda: 1)
+        finally:
+            pyulid.configure(clean_words=False)
+
+        pyulid.set_entropy_source(lambda: 1)
+        try:
+            with pytest.raises(ValueError, match="set_entropy_source"):
+                pyulid.configure(clean_words=["ABC"])
+            with pytest.raises(ValueError, match="set_entropy_source"):
+                pyulid.ulid_clean()
+            pyulid.configure(clean_words=False)
+        finally:
+            pyulid.set_entropy_source(None)
+        assert pyulid.get_config()["clean_words"] is False
+
+
+
+@pytest.fixture
+def restore_rng_mode():
+    """Fixture putting the RNG mode back to "fast" after the test."""
+    yield
+    pyulid.set_rng_mode("fast")
+
+
+class TestRngMode:
+    """Test set_rng_mode() and get_rng_mode()."""
+
+    def test_default_and_round_trip(self, restore_rng_mode):
+        """Test that the mode defaults to fast and reads back what was set."""
+        assert pyulid.get_rng_mode() == "fast"
+        pyulid.set_rng_mode("os")
+        assert pyulid.get_rng_mode() == "os"
+        pyulid.set_rng_mode("fast")
+        assert pyulid.get_rng_mode() == "fast"
+
+    def test_unknown_mode(self):
+        """Test that an unknown mode raises and leaves the mode alone."""
+        with pytest.raises(ValueError, match="'fast' or 'os', got 'secure'"):
+            pyulid.set_rng_mode("secure")
+        assert pyulid.get_rng_mode() == "fast"
+
+    @pytest.mark.parametrize("mode", ["fast", "os"])
+    def test_valid_and_monotonic(self, mode, restore_rng_mode):
+        """Test that generation stays valid and ordered in both modes."""
+        pyulid.set_rng_mode(mode)
+        ulids = [pyulid.ulid() for _ in range(2000)] + pyulid.ulid_batch(2000)
+        assert ulids == sorted(ulids)
+        assert len(set(ulids)) == len(ulids)
+        assert all(pyulid.ulid_is_valid(u) for u in ulids)
+
+        gen = pyulid.UlidGenerator()
+        own = [gen.generate() for _ in range(500)]
+        assert own == sorted(own)
+        others = [pyulid.ulid_with_timestamp(1672531200000) for _ in range(500)]
+        assert len(set(others)) == len(others)
+        assert all(pyulid.ulid_is_valid(u) for u in own + others)
+
+    def test_seeded_generator_unaffected(self, restore_rng_mode):
+        """Test that seeded generators stay reproducible in os mode."""
+        pyulid.set_rng_mode("os")
+        gen = pyulid.UlidGenerator(0, 1672531200000)
+        assert gen.generate() == "01GNNA1J00D5XVPAHZPB6JRVW0"
+
+    def test_switch_while_generating(self, restore_rng_mode):
+        """Test that switching modes mid-generation keeps the sequence ordered."""
+        ulids = []
+        stop = threading.Event()
+
+        def generate():
+            while not stop.is_set():
+                ulids.extend(pyulid.ulid_batch(50))
+
+        worker = threading.Thread(target=generate)
+        worker.start()
+        try:
+            for mode in ["os", "fast"] * 50:
+                pyulid.set_rng_mode(mode)
+                time.sleep(0.0005)
+        finally:
+            stop.set()
+            worker.join()
+
+        assert ulids == sorted(ulids)
+        assert len(set(ulids)) == len(ulids)
+
+
+@pytest.mark.skipif(
+    not hasattr(pyulid, "_inject_panic"),
+    reason="needs a build with the test-hooks feature",
+)
+class TestPanicConversion:
+    """Test that internal panics surface as regular exceptions."""
+
+    @pytest.mark.parametrize(
+        "name, call",
+        [
+            ("ulid", lambda: pyulid.ulid()),
+            ("ulid_with_timestamp", lambda: pyulid.ulid_with_timestamp(0)),
+            ("ulid_batch_array", lambda: pyulid.ulid_batch_array(10)),
+            ("reseed", lambda: pyulid.reseed()),
+            ("self_check", lambda: pyulid.self_check()),
+            (
+                "KeyedUlidGenerator.generate",
+                lambda: pyulid.KeyedUlidGenerator().generate("k"),
+            ),
+            ("ulid_sibling", lambda: pyulid.ulid_sibling(pyulid.ulid())),
+        ],
+    )
+    def test_injected_panic(self, name, call):
+        """Test that a panic becomes UlidInternalError naming the function."""
+        pyulid._inject_panic(name)
+
+        with pytest.raises(pyulid.UlidInternalError, match="injected failure") as exc:
+            call()
+        assert f"{name}()" in str(exc.value)
+
+        # The hook is one-shot and the generator keeps working afterwards
+        call()
+        assert pyulid.ulid_is_valid(pyulid.ulid())
+
+    def test_exception_hierarchy(self):
+        """Test that the converted error is catchable as UlidError and RuntimeError."""
+        assert issubclass(pyulid.UlidInternalError, pyulid.UlidError)
+        assert issubclass(pyulid.UlidInternalError, RuntimeError)
+        assert pyulid.UlidInternalError.__module__ == "pyulid"
+
+        for base in [pyulid.UlidError, RuntimeError]:
+            pyulid._inject_panic("ulid")
+            with pytest.raises(base):
+                pyulid.ulid()
+
+    def test_only_target_function_fails(self):
+        """Test that other functions are unaffected while the hook is armed."""
+        pyulid._inject_panic("reseed")
+        assert pyulid.ulid_is_valid(pyulid.ulid())
+        with pytest.raises(pyulid.UlidInternalError):
+            pyulid.reseed()
+
+
+class TestErrorClasses:
+    """Test the specific exception classes and their legacy builtin bases."""
+
+    @pytest.mark.parametrize(
+        "cls, builtin",
+        [
+            (pyulid.UlidDecodeError, ValueError),
+            (pyulid.UlidOverflowError, RuntimeError),
+            (pyulid.UlidClockError, RuntimeError),
+        ],
+    )
+    def test_hierarchy(self, cls, builtin):
+        """Test that each class derives from both UlidError and its builtin."""
+        assert issubclass(cls, pyulid.UlidError)
+        assert issubclass(cls, builtin)
+        assert cls.__module__ == "pyulid"
+
+    @pytest.mark.parametrize(
+        "call",
+        [
+            lambda: pyulid.ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FA"),
+            lambda: pyulid.ulid_timestamp("01ARZ3NDEKTSV4RRFFQ69G5FAI"),
+            lambda: pyulid.ulid_timestamp("81ARZ3NDEKTSV4RRFFQ69G5FAV"),
+            lambda: pyulid.decode_base32("0U"),
+            lambda: pyulid.ulid_from_hex("zz" * 16),
+            lambda: pyulid.ulid_from_bytes(b"short"),
+            lambda: pyulid.ULID("not a ulid"),
+            lambda: pyulid.UlidArray(["01ARZ3NDEKTSV4RRFFQ69G5FA"]),
+        ],
+    )
+    def test_decode_errors(self, call):
+        """Test that decode failures raise UlidDecodeError, still a ValueError."""
+        for expected in [pyulid.UlidDecodeError, pyulid.UlidError, ValueError]:
+            with pytest.raises(expected):
+                call()
+
+    def test_argument_errors_stay_plain(self):
+        """Test that out-of-range arguments are not reported as decode errors."""
+        with pytest.raises(ValueError) as exc:
+            pyulid.ulid_with_timestamp(2**48)
+        assert not isinstance(exc.value, pyulid.UlidError)
+
+    def test_clock_error(self, frozen_source_clock):
+        """Test that a clock step backwards raises UlidClockError."""
+        pyulid.ulid()
+        pyulid._freeze_time(frozen_source_clock - 1)
+        for expected in [pyulid.UlidClockError, pyulid.UlidError, RuntimeError]:
+            with pytest.raises(expected, match="backwards"):
+                pyulid.ulid()
+        pyulid._freeze_time(frozen_source_clock)
+
+    def test_overflow_error(self):
+        """Test that an exhausted millisecond raises UlidOverflowError."""
+        timestamp = 1262304000300
+        last = pyulid.ulid_from_int((timestamp << 80) | (2**80 - 1))
+        pyulid._resume_timestamp_sequence(last)
+
+        for expected in [pyulid.UlidOverflowError, pyulid.UlidError, RuntimeError]:
+            with pytest.raises(expected, match="overflow"):
+                pyulid.ulid_with_timestamp_monotonic(timestamp)
+
+
+@pytest.fixture
+def sigalrm_interrupt():
+    """Fixture delivering a real signal that raises KeyboardInterrupt, like Ctrl-C."""
+    if not hasattr(signal, "setitimer"):
+        pytest.skip("signal.setitimer is unavailable")
+    previous = signal.signal(signal.SIGALRM, signal.default_int_handler)
+    yield lambda delay: signal.setitimer(signal.ITIMER_REAL, delay)
+    signal.setitimer(signal.ITIMER_REAL, 0)
+    signal.signal(signal.SIGALRM, previous)
+
+
+class TestInterrupts:
+    """Test that long bulk calls stop promptly on Ctrl-C."""
+
+    def test_batch_interrupted(self):
+        """Test interrupting a GIL-released batch from a timer thread."""
+        timer = threading.Timer(0.05, _thread.interrupt_main)
+        start = time.perf_counter()
+        timer.start()
+        with pytest.raises(KeyboardInterrupt):
+            pyulid.ulid_batch_array(50_000_000)
+        elapsed = time.perf_counter() - start
+        timer.join()
+
+        assert elapsed < 2.0
+        # The state lock was released, so other threads can still generate
+        results = []
+        worker = threading.Thread(target=lambda: results.append(pyulid.ulid()))
+        worker.start()
+        worker.join(timeout=5)
+        assert len(results) == 1
+        assert results[0] < pyulid.ulid()
+
+    def test_entropy_check_interrupted(self, sigalrm_interrupt):
+        """Test interrupting sample generation of ulid_entropy_check."""
+        sigalrm_interrupt(0.05)
+        start = time.perf_counter()
+        with pytest.raises(KeyboardInterrupt):
+            pyulid.ulid_entropy_check(100_000_000)
+        assert time.perf_counter() - start < 2.0
+
+    def test_collection_interrupted(self, sigalrm_interrupt):
+        """Test interrupting input collection, which runs with the GIL held."""
+        endless = itertools.repeat(pyulid.ulid())
+        sigalrm_interrupt(0.05)
+        start = time.perf_counter()
+        with pytest.raises(KeyboardInterrupt):
+            pyulid.UlidSet(endless)
+        assert time.perf_counter() - start < 2.0
+
+
 if __name__ == "__main__":
     pytest.main([__file__])
 
@@ -6,6 +6,7 @@ Tests Base32 encoding/decoding, format compliance, and related functionality.
 
 import pytest
 import pyulid
+import random
 import re
 import time
 
@@ -137,6 +138,56 @@ class TestBase32Decoding:
         assert result == 0
 
 
+NON_ASCII = ["Ä", "é", "€", "\U0001f600"]
+
+
+class TestNonAsciiMessages:
+    """Test that non-ASCII input is reported as whole characters."""
+
+    @pytest.mark.parametrize("c", NON_ASCII)
+    @pytest.mark.parametrize("position", [0, 12, 24, 25])
+    def test_decode_base32(self, c, position):
+        """Test that decode_base32 names the real character and its position."""
+        chars = list("01ARZ3NDEKTSV4RRFFQ69G5FAV")
+        chars[position] = c
+        with pytest.raises(ValueError) as excinfo:
+            pyulid.decode_base32("".join(chars))
+        assert str(excinfo.value) == (
+            f"Invalid character '{c}' at position {position} in Base32 string"
+        )
+
+    @pytest.mark.parametrize("c", NON_ASCII)
+    @pytest.mark.parametrize("position", [0, 12, 24, 25])
+    def test_ulid_parsers(self, c, position):
+        """Test that a 26-character ULID with a multi-byte character reports it."""
+        chars = list("01ARZ3NDEKTSV4RRFFQ69G5FAV")
+        chars[position] = c
+        expected = f"Invalid character '{c}' at position {position} in ULID"
+        for parse in [pyulid.ulid_timestamp, pyulid.ulid_random, pyulid.ulid_to_uuid]:
+            with pytest.raises(ValueError) as excinfo:
+                parse("".join(chars))
+            assert str(excinfo.value) == expected
+
+    def test_length_counts_characters(self):
+        """Test that length errors count characters, not UTF-8 bytes."""
+        with pytest.raises(ValueError, match="got 25"):
+            pyulid.ulid_timestamp("é" * 25)
+        with pytest.raises(ValueError, match="got 9"):
+            pyulid.decode_timestamp_base32("€" * 9)
+        with pytest.raises(ValueError, match="'€' at position 9"):
+            pyulid.decode_timestamp_base32("01ARZ3NDE€")
+
+    def test_suffix_key(self):
+        """Test that ulid_parse_suffix reports the character in the right part."""
+        key = pyulid.ulid_with_suffix(pyulid.ulid(), 5)
+        with pytest.raises(ValueError, match="got 29"):
+            pyulid.ulid_parse_suffix(key + "é")
+        with pytest.raises(ValueError, match="'é' at position 3 in ULID"):
+            pyulid.ulid_parse_suffix(key[:3] + "é" + key[4:])
+        with pytest.raises(ValueError, match="'é' at position 1 in Base32"):
+            pyulid.ulid_parse_suffix(key[:27] + "é")
+
+
 class TestEncodingRoundtrip:
     """Test encoding/decoding roundtrip operations."""
     
@@ -347,6 +398,713 @@ class TestSpecialEncodingCases:
             decoded = pyulid.decode_base32(encoded)
             assert decoded == value, f"Bit precision lost for {bin(value)}"
 
+    def test_full_width_values(self):
+        """Test that values needing all 26 characters keep their top bits."""
+        assert pyulid.encode_base32(2**128 - 1) == "7" + "Z" * 25
+        assert pyulid.encode_base32(2**125) == "1" + "0" * 25
+        for value in [2**128 - 1, 2**125, 2**125 - 1, 2**127]:
+            assert pyulid.decode_base32(pyulid.encode_base32(value)) == value
+
+    def test_top_of_range_sweep(self):
+        """Test round trips for random values at or above 2**125."""
+        rng = random.Random(125)
+        for _ in range(1000):
+            value = rng.randrange(2**125, 2**128)
+            encoded = pyulid.encode_base32(value)
+            assert encoded[0] != "0"
+            assert pyulid.decode_base32(encoded) == value
+            raw = value.to_bytes(16, "big")
+            assert str(pyulid.Ulid.from_bytes(raw)) == encoded
+
+    def test_uuid_with_high_bits(self):
+        """Test that UUIDs with high leading bits convert without losing them."""
+        for uuid_str in [
+            "ffffffff-ffff-ffff-ffff-ffffffffffff",
+            "e0000000-0000-0000-0000-000000000000",
+            "20000000-0000-0000-0000-000000000001",
+        ]:
+            value = int(uuid_str.replace("-", ""), 16)
+            ulid_str = pyulid.uuid_to_ulid(uuid_str)
+            assert ulid_str == pyulid.encode_base32(value)
+            assert pyulid.ulid_to_uuid(ulid_str) == uuid_str
+
+
+class TestIntegerConversion:
+    """Test ulid_to_int / ulid_from_int conversions."""
+
+    def test_roundtrip_extremes(self):
+        """Test roundtrip at the edges of the 128-bit range."""
+        for value in [0, 1, 2**80 - 1, 2**125, 2**127, 2**128 - 1]:
+            ulid_str = pyulid.ulid_from_int(value)
+            assert len(ulid_str) == 26
+            assert pyulid.ulid_to_int(ulid_str) == value
+
+    def test_roundtrip_random_values(self):
+        """Test roundtrip for random 128-bit values."""
+        import random
+
+        for _ in range(1000):
+            value = random.getrandbits(128)
+            assert pyulid.ulid_to_int(pyulid.ulid_from_int(value)) == value
+
+    def test_matches_base32_functions(self):
+        """Test that integer conversions agree with encode/decode_base32."""
+        for _ in range(100):
+            ulid_str = pyulid.ulid()
+            value = pyulid.ulid_to_int(ulid_str)
+            assert value == pyulid.decode_base32(ulid_str)
+            assert pyulid.ulid_from_int(value) == pyulid.encode_base32(value)
+
+    def test_known_values(self):
+        """Test known integer/string pairs."""
+        assert pyulid.ulid_from_int(0) == "0" * 26
+        assert pyulid.ulid_from_int(2**128 - 1) == "7" + "Z" * 25
+        assert pyulid.ulid_to_int("0" * 25 + "1") == 1
+
+    def test_lowercase_input(self):
+        """Test that ulid_to_int is case insensitive."""
+        ulid_str = pyulid.ulid()
+        assert pyulid.ulid_to_int(ulid_str.lower()) == pyulid.ulid_to_int(ulid_str)
+
+    def test_from_int_out_of_range(self):
+        """Test that values outside 0 <= i < 2**128 are rejected."""
+        for value in [-1, 2**128, 2**200]:
+            with pytest.raises(OverflowError):
+                pyulid.ulid_from_int(value)
+
+    def test_from_int_wrong_type(self):
+        """Test that non-integers are rejected."""
+        with pytest.raises(TypeError):
+            pyulid.ulid_from_int("123")
+
+    def test_to_int_invalid(self):
+        """Test that ulid_to_int validates like the other parsers."""
+        invalid_ulids = [
+            "",
+            "01ARZ3NDEKTSV4RRFFQ69G5FA",  # Too short
+            "01ARZ3NDEKTSV4RRFFQ69G5FAVX",  # Too long
+            "01ARZ3NDEKTSV4RRFFQ69G5FAI",  # Invalid char 'I'
+        ]
+
+        for invalid_ulid in invalid_ulids:
+            with pytest.raises(ValueError):
+                pyulid.ulid_to_int(invalid_ulid)
+
+
+class TestBytesConversion:
+    """Test ulid_to_bytes / ulid_from_bytes conversions."""
+
+    def test_roundtrip_extremes(self):
+        """Test lossless roundtrip including all-zero and all-ones values."""
+        for value in [0, 1, 2**80 - 1, 2**127, 2**128 - 1]:
+            raw = pyulid.ulid_to_bytes(pyulid.ulid_from_int(value))
+            assert raw == value.to_bytes(16, "big")
+            assert pyulid.ulid_from_bytes(raw) == pyulid.ulid_from_int(value)
+
+    def test_roundtrip_random_values(self):
+        """Test roundtrip for random 128-bit values."""
+        for _ in range(1000):
+            raw = random.getrandbits(128).to_bytes(16, "big")
+            assert pyulid.ulid_to_bytes(pyulid.ulid_from_bytes(raw)) == raw
+
+    def test_byte_order_matches_string_order(self):
+        """Test that big-endian bytes sort like the strings."""
+        ulids = [pyulid.ulid_from_int(random.getrandbits(128)) for _ in range(200)]
+        assert sorted(ulids, key=pyulid.ulid_to_bytes) == sorted(ulids)
+
+    def test_accepts_ulid_objects(self):
+        """Test that ulid_to_bytes takes ULID objects and lowercase strings."""
+        ulid_str = pyulid.ulid()
+        raw = pyulid.ulid_to_bytes(ulid_str)
+        assert pyulid.ulid_to_bytes(ulid_str.lower()) == raw
+        assert pyulid.ulid_to_bytes(pyulid.ULID(ulid_str)) == raw
+        assert pyulid.ulid_to_bytes(pyulid.Ulid.from_string(ulid_str)) == raw
+
+    def test_from_bytes_wrong_length(self):
+        """Test that anything but exactly 16 bytes is rejected."""
+        for raw in [b"", b"\x00" * 15, b"\x00" * 17, b"\xff" * 32]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_from_bytes(raw)
+
+    def test_to_bytes_invalid(self):
+        """Test that ulid_to_bytes validates its input."""
+        with pytest.raises(ValueError):
+            pyulid.ulid_to_bytes("01ARZ3NDEKTSV4RRFFQ69G5FAI")
+        with pytest.raises(ValueError):
+            pyulid.ulid_to_bytes("01ARZ3NDEKTSV4RRFFQ69G5FAI", validate=True)
+
+    def test_to_bytes_unvalidated(self):
+        """Test that validate=False agrees on valid input and skips the checks."""
+        ulid_str = pyulid.ulid()
+        raw = pyulid.ulid_to_bytes(ulid_str)
+        assert pyulid.ulid_to_bytes(ulid_str, validate=False) == raw
+        assert pyulid.ulid_to_bytes(ulid_str.lower(), validate=False) == raw
+        assert pyulid.ulid_to_bytes(pyulid.ULID(ulid_str), validate=False) == raw
+        assert len(pyulid.ulid_to_bytes("I" * 26, validate=False)) == 16
+        with pytest.raises(TypeError, match="argument 'ulid': "):
+            pyulid.ulid_to_bytes(123, validate=False)
+
+
+class TestComponentBytes:
+    """Test ulid_timestamp_bytes / ulid_random_bytes."""
+
+    def test_concatenation_is_to_bytes(self):
+        """Test that timestamp bytes + random bytes == ulid_to_bytes."""
+        for _ in range(1000):
+            ulid_str = pyulid.ulid_from_int(random.getrandbits(128))
+            timestamp = pyulid.ulid_timestamp_bytes(ulid_str)
+            rand = pyulid.ulid_random_bytes(ulid_str)
+            assert len(timestamp) == 6 and len(rand) == 10
+            assert timestamp + rand == pyulid.ulid_to_bytes(ulid_str)
+
+    def test_match_int_components(self):
+        """Test agreement with ulid_timestamp and ulid_random."""
+        ulid_str = pyulid.ulid()
+        timestamp = pyulid.ulid_timestamp(ulid_str)
+        assert pyulid.ulid_timestamp_bytes(ulid_str) == timestamp.to_bytes(6, "big")
+        rand = pyulid.ulid_random(ulid_str)
+        assert pyulid.ulid_random_bytes(ulid_str) == rand.to_bytes(10, "big")
+
+    def test_leading_zeros_kept(self):
+        """Test that small values still give full-width bytes."""
+        assert pyulid.ulid_timestamp_bytes("0" * 26) == bytes(6)
+        assert pyulid.ulid_random_bytes("0" * 25 + "1") == bytes(9) + b"\x01"
+        assert pyulid.ulid_random_bytes("7" + "Z" * 25) == b"\xff" * 10
+
+    def test_accepts_ulid_objects(self):
+        """Test that ULID objects and lowercase strings are accepted."""
+        ulid_str = pyulid.ulid()
+        for value in [ulid_str.lower(), pyulid.ULID(ulid_str)]:
+            assert pyulid.ulid_random_bytes(value) == (
+                pyulid.ulid_random_bytes(ulid_str)
+            )
+            assert pyulid.ulid_timestamp_bytes(value) == (
+                pyulid.ulid_timestamp_bytes(ulid_str)
+            )
+
+    def test_invalid(self):
+        """Test that both validate their input like ulid_to_bytes."""
+        for bad in ["01ARZ3NDEKTSV4RRFFQ69G5FAI", "8" + "0" * 25, ""]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_random_bytes(bad)
+            with pytest.raises(ValueError):
+                pyulid.ulid_timestamp_bytes(bad)
+
+
+class TestHexConversion:
+    """Test ulid_to_hex / ulid_from_hex conversions."""
+
+    def test_roundtrip_extremes(self):
+        """Test zero-padded roundtrip including all-zero and all-ones values."""
+        for value in [0, 1, 2**80 - 1, 2**127, 2**128 - 1]:
+            hex_str = pyulid.ulid_to_hex(pyulid.ulid_from_int(value))
+            assert hex_str == f"{value:032x}"
+            assert pyulid.ulid_from_hex(hex_str) == pyulid.ulid_from_int(value)
+
+    def test_roundtrip_random_values(self):
+        """Test roundtrip for random 128-bit values."""
+        for _ in range(1000):
+            hex_str = f"{random.getrandbits(128):032x}"
+            assert pyulid.ulid_to_hex(pyulid.ulid_from_hex(hex_str)) == hex_str
+
+    def test_matches_uuid_digits(self):
+        """Test that the hex digits are the UUID form without dashes."""
+        ulid_str = pyulid.ulid()
+        assert pyulid.ulid_to_hex(ulid_str) == pyulid.ulid_to_uuid(ulid_str).replace(
+            "-", ""
+        )
+
+    def test_from_hex_either_case(self):
+        """Test that upper and mixed case hex decode like lowercase."""
+        hex_str = pyulid.ulid_to_hex(pyulid.ulid())
+        expected = pyulid.ulid_from_hex(hex_str)
+        assert pyulid.ulid_from_hex(hex_str.upper()) == expected
+        mixed = "".join(c.upper() if i % 2 else c for i, c in enumerate(hex_str))
+        assert pyulid.ulid_from_hex(mixed) == expected
+
+    def test_from_hex_wrong_length(self):
+        """Test that anything but exactly 32 characters is rejected with the length."""
+        for hex_str in ["", "0" * 31, "0" * 33, "0" * 26]:
+            with pytest.raises(ValueError, match=f"got {len(hex_str)}"):
+                pyulid.ulid_from_hex(hex_str)
+
+    def test_from_hex_invalid_characters(self):
+        """Test that non-hex characters are rejected with the offending character."""
+        cases = {
+            "g" + "0" * 31: "'g' at position 0",
+            "0" * 31 + "-": "'-' at position 31",
+            "0" * 31 + "\u00e9": "'\u00e9' at position 31",
+            "\u00e9" * 16: "'\u00e9' at position 0",
+            "0" * 40 + "\U0001f389": "at position 40",
+        }
+        for hex_str, detail in cases.items():
+            with pytest.raises(ValueError, match=detail):
+                pyulid.ulid_from_hex(hex_str)
+
+    def test_from_hex_rejects_sign(self):
+        """Test that a leading '+' isn't accepted as part of the number."""
+        with pytest.raises(ValueError):
+            pyulid.ulid_from_hex("+" + "0" * 31)
+
+
+class TestTimestampCodec:
+    """Test the standalone 48-bit timestamp codec."""
+
+    def test_matches_ulid_prefix(self):
+        """Test that the encoding equals the prefix of ULIDs at that timestamp."""
+        for ts in [0, 1, 1672531200000, int(time.time() * 1000), 2**48 - 1]:
+            encoded = pyulid.encode_timestamp_base32(ts)
+            assert len(encoded) == 10
+            for _ in range(10):
+                assert pyulid.ulid_with_timestamp(ts)[:10] == encoded
+
+    def test_matches_generated_ulids(self):
+        """Test against freshly generated ULIDs."""
+        for _ in range(100):
+            ulid_str = pyulid.ulid()
+            ts = pyulid.ulid_timestamp(ulid_str)
+            assert pyulid.encode_timestamp_base32(ts) == ulid_str[:10]
+
+    def test_round_trip(self):
+        """Test encode/decode round trip including case-insensitive decoding."""
+        for _ in range(200):
+            ts = random.randrange(2**48)
+            encoded = pyulid.encode_timestamp_base32(ts)
+            assert pyulid.decode_timestamp_base32(encoded) == ts
+            assert pyulid.decode_timestamp_base32(encoded.lower()) == ts
+
+    def test_sorts_chronologically(self):
+        """Test that prefixes sort in timestamp order."""
+        timestamps = sorted(random.randrange(2**48) for _ in range(100))
+        encoded = [pyulid.encode_timestamp_base32(ts) for ts in timestamps]
+        assert encoded == sorted(encoded)
+
+    def test_encode_range(self):
+        """Test 48-bit range validation on encode."""
+        assert pyulid.encode_timestamp_base32(2**48 - 1) == "7ZZZZZZZZZ"
+        for bad in [-1, 2**48]:
+            with pytest.raises(ValueError):
+                pyulid.encode_timestamp_base32(bad)
+
+    def test_decode_invalid(self):
+        """Test decode rejects bad length, characters and overflow."""
+        for bad in ["", "000000000", "00000000000", "000000000I", "8000000000"]:
+            with pytest.raises(ValueError):
+                pyulid.decode_timestamp_base32(bad)
+
+
+class TestRFC4648Base32:
+    """Test RFC 4648 standard Base32 rendering."""
+
+    def _bytes(self, ulid_str):
+        return pyulid.ulid_to_int(ulid_str).to_bytes(16, "big")
+
+    def test_matches_stdlib_encoding(self):
+        """Test that output matches base64.b32encode of the 16 bytes."""
+        import base64
+
+        for _ in range(200):
+            ulid_str = pyulid.ulid_from_int(random.getrandbits(128))
+            expected = base64.b32encode(self._bytes(ulid_str)).decode("ascii")
+            assert pyulid.ulid_to_b32std(ulid_str) == expected
+            unpadded = pyulid.ulid_to_b32std(ulid_str, padding=False)
+            assert unpadded == expected.rstrip("=")
+
+    def test_matches_stdlib_decoding(self):
+        """Test that decoding agrees with base64.b32decode."""
+        import base64
+
+        for _ in range(200):
+            ulid_str = pyulid.ulid()
+            encoded = pyulid.ulid_to_b32std(ulid_str)
+            assert base64.b32decode(encoded) == self._bytes(ulid_str)
+            assert pyulid.ulid_from_b32std(encoded) == ulid_str
+
+    def test_length_and_padding(self):
+        """Test output length with and without padding."""
+        ulid_str = pyulid.ulid()
+        assert len(pyulid.ulid_to_b32std(ulid_str)) == 32
+        assert pyulid.ulid_to_b32std(ulid_str).endswith("======")
+        assert len(pyulid.ulid_to_b32std(ulid_str, padding=False)) == 26
+
+    def test_decode_case_insensitive_and_unpadded(self):
+        """Test that decoding accepts lowercase and unpadded input."""
+        ulid_str = pyulid.ulid()
+        encoded = pyulid.ulid_to_b32std(ulid_str)
+        assert pyulid.ulid_from_b32std(encoded.lower()) == ulid_str
+        assert pyulid.ulid_from_b32std(encoded.rstrip("=")) == ulid_str
+
+    def test_extremes(self):
+        """Test zero and maximum values."""
+        for ulid_str in ["0" * 26, "7" + "Z" * 25]:
+            encoded = pyulid.ulid_to_b32std(ulid_str)
+            assert pyulid.ulid_from_b32std(encoded) == ulid_str
+
+    def test_decode_invalid(self):
+        """Test that malformed RFC 4648 strings are rejected."""
+        valid = pyulid.ulid_to_b32std(pyulid.ulid())
+        invalid = [
+            "",
+            valid[:25],  # Too short
+            valid[:26] + "====",  # Wrong padding length
+            "0" + valid[1:],  # '0' is not in the RFC 4648 alphabet
+            valid[:25] + "B",  # Non-zero trailing pad bits
+        ]
+
+        for value in invalid:
+            with pytest.raises(ValueError):
+                pyulid.ulid_from_b32std(value)
+
+    def test_decode_rejects_non_ascii(self):
+        """Test that non-ASCII characters are not folded onto the alphabet."""
+        # U+0141 truncates to b"A", and the string is 26 bytes but 25 chars
+        for value in ["A" * 24 + "\u0141", "A" * 25 + "\u0141"]:
+            with pytest.raises(pyulid.UlidDecodeError):
+                pyulid.ulid_from_b32std(value)
+
+
+class TestBase64Url:
+    """Test base64url rendering of the 16 bytes."""
+
+    def _bytes(self, ulid_str):
+        return pyulid.ulid_to_int(ulid_str).to_bytes(16, "big")
+
+    def test_matches_stdlib_encoding(self):
+        """Test that output matches base64.urlsafe_b64encode, unpadded."""
+        import base64
+
+        for _ in range(500):
+            ulid_str = pyulid.ulid_from_int(random.getrandbits(128))
+            expected = base64.urlsafe_b64encode(self._bytes(ulid_str)).decode("ascii")
+            encoded = pyulid.ulid_to_base64(ulid_str)
+            assert encoded == expected.rstrip("=")
+            assert len(encoded) == 22
+
+    def test_matches_stdlib_decoding(self):
+        """Test that stdlib output, padded or not, decodes to the same ULID."""
+        import base64
+
+        for _ in range(500):
+            ulid_str = pyulid.ulid_from_int(random.getrandbits(128))
+            padded = base64.urlsafe_b64encode(self._bytes(ulid_str)).decode("ascii")
+            assert pyulid.ulid_from_base64(padded) == ulid_str
+            assert pyulid.ulid_from_base64(padded.rstrip("=")) == ulid_str
+            encoded = pyulid.ulid_to_base64(ulid_str)
+            assert base64.urlsafe_b64decode(encoded + "==") == self._bytes(ulid_str)
+
+    def test_extremes(self):
+        """Test zero and maximum values."""
+        assert pyulid.ulid_to_base64("0" * 26) == "A" * 22
+        for ulid_str in ["0" * 26, "7" + "Z" * 25]:
+            encoded = pyulid.ulid_to_base64(ulid_str)
+            assert pyulid.ulid_from_base64(encoded) == ulid_str
+
+    def test_accepts_ulid_objects(self):
+        """Test that Ulid objects encode like their strings."""
+        ulid_str = pyulid.ulid()
+        value = pyulid.Ulid.from_string(ulid_str)
+        assert pyulid.ulid_to_base64(value) == pyulid.ulid_to_base64(ulid_str)
+
+    def test_decode_invalid(self):
+        """Test that malformed base64url strings are rejected."""
+        valid = pyulid.ulid_to_base64(pyulid.ulid())
+        invalid = [
+            "",
+            valid[:21],  # Too short
+            valid + "A",  # Too long
+            valid + "=",  # Wrong padding length
+            "+" + valid[1:],  # Standard-alphabet character
+            "/" + valid[1:],
+            valid[:21] + "B",  # Non-zero trailing pad bits
+        ]
+
+        for value in invalid:
+            with pytest.raises(ValueError):
+                pyulid.ulid_from_base64(value)
+
+
+CHECK_SYMBOLS = "0123456789ABCDEFGHJKMNPQRSTVWXYZ*~$=U"
+
+
+class TestCheckSymbol:
+    """Test Crockford check symbols."""
+
+    def test_symbol_is_value_mod_37(self):
+        """Test that the appended symbol is the 128-bit value mod 37."""
+        for _ in range(500):
+            value = random.getrandbits(128)
+            ulid_str = pyulid.ulid_from_int(value)
+            checked = pyulid.ulid_with_check(ulid_str)
+            assert checked == ulid_str + CHECK_SYMBOLS[value % 37]
+            assert pyulid.ulid_verify_check(checked)
+            assert pyulid.ulid_strip_check(checked) == ulid_str
+
+    def test_every_symbol(self):
+        """Test that all 37 symbols occur and round-trip, including *~$=U."""
+        for remainder in range(37):
+            ulid_str = pyulid.ulid_from_int(37 * 1000 + remainder)
+            checked = pyulid.ulid_with_check(ulid_str)
+            assert checked[-1] == CHECK_SYMBOLS[remainder]
+            assert pyulid.ulid_strip_check(checked) == ulid_str
+
+    def test_case_insensitive(self):
+        """Test that lowercase ULIDs and check symbols are accepted."""
+        ulid_str = pyulid.ulid_from_int(37 * 1000 + 36)  # Check symbol 'U'
+        checked = pyulid.ulid_with_check(ulid_str)
+        assert checked.endswith("U")
+        assert pyulid.ulid_verify_check(checked.lower())
+        assert pyulid.ulid_strip_check(checked.lower()) == ulid_str
+
+    def test_accepts_ulid_objects(self):
+        """Test that Ulid objects get the same check symbol as their strings."""
+        ulid_str = pyulid.ulid()
+        value = pyulid.Ulid.from_string(ulid_str)
+        assert pyulid.ulid_with_check(value) == pyulid.ulid_with_check(ulid_str)
+
+    def test_detects_typos(self):
+        """Test that a single mistyped character fails verification."""
+        checked = pyulid.ulid_with_check(pyulid.ulid())
+        for i in range(1, 26):
+            replacement = "1" if checked[i] != "1" else "2"
+            typo = checked[:i] + replacement + checked[i + 1 :]
+            assert not pyulid.ulid_verify_check(typo)
+
+    def test_mismatch_message(self):
+        """Test that a bad checksum names the expected and actual symbols."""
+        ulid_str = pyulid.ulid_from_int(37 * 1000 + 5)
+        with pytest.raises(ValueError, match="expected '5', got '6'"):
+            pyulid.ulid_strip_check(ulid_str + "6")
+
+    def test_invalid(self):
+        """Test that malformed checked ULIDs are rejected."""
+        ulid_str = pyulid.ulid()
+        checked = pyulid.ulid_with_check(ulid_str)
+        invalid = [
+            "",
+            ulid_str,  # No check symbol
+            checked + "0",  # Too long
+            ulid_str + "#",  # Not a check symbol
+            ulid_str + "é",
+            "8" + checked[1:],  # Overflowing ULID
+            "U" + checked[1:],  # Check symbol inside the ULID
+        ]
+
+        for value in invalid:
+            assert not pyulid.ulid_verify_check(value)
+            with pytest.raises(ValueError):
+                pyulid.ulid_strip_check(value)
+        with pytest.raises(ValueError, match="27 characters, got 26"):
+            pyulid.ulid_strip_check(ulid_str)
+        with pytest.raises(ValueError, match="Invalid check symbol '#'"):
+            pyulid.ulid_strip_check(ulid_str + "#")
+
+
+class TestJsonSafeForm:
+    """Test the JavaScript-safe {ts, rhi, rlo} representation."""
+
+    def test_layout(self):
+        """Test the field layout against a known value."""
+        value = (0x0123456789AB << 80) | (0xA1B2C3D4E5 << 40) | 7
+        ulid_str = pyulid.ulid_from_int(value)
+        parts = pyulid.ulid_to_json(ulid_str)
+
+        assert parts == {"ts": 0x0123456789AB, "rhi": 0xA1B2C3D4E5, "rlo": 7}
+        assert list(parts) == ["ts", "rhi", "rlo"]
+        assert parts["ts"] == pyulid.ulid_timestamp(ulid_str)
+        assert (parts["rhi"] << 40) | parts["rlo"] == pyulid.ulid_random(ulid_str)
+
+    def test_round_trip(self):
+        """Test exact round trips through dict, tuple and json text."""
+        import json
+
+        samples = [pyulid.ulid() for _ in range(100)] + ["0" * 26, "7" + "Z" * 25]
+        for ulid_str in samples:
+            parts = pyulid.ulid_to_json(ulid_str)
+            assert all(0 <= v < 2**53 for v in parts.values())
+            assert pyulid.ulid_from_json(parts) == ulid_str
+            assert pyulid.ulid_from_json(json.loads(json.dumps(parts))) == ulid_str
+            as_tuple = (parts["ts"], parts["rhi"], parts["rlo"])
+            assert pyulid.ulid_from_json(as_tuple) == ulid_str
+
+    def test_accepts_ulid_object(self):
+        """Test that ulid_to_json accepts ULID objects."""
+        obj = pyulid.ULID()
+        assert pyulid.ulid_to_json(obj) == pyulid.ulid_to_json(str(obj))
+
+    def test_out_of_range(self):
+        """Test that out-of-range fields raise ValueError."""
+        invalid = [
+            (2**48, 0, 0),
+            (0, 2**40, 0),
+            (0, 0, 2**40),
+            (-1, 0, 0),
+            (0, 0, -1),
+            {"ts": 0, "rhi": 2**40, "rlo": 0},
+        ]
+
+        for value in invalid:
+            with pytest.raises(ValueError):
+                pyulid.ulid_from_json(value)
+
+        largest = (2**48 - 1, 2**40 - 1, 2**40 - 1)
+        assert pyulid.ulid_from_json(largest) == "7" + "Z" * 25
+
+    def test_malformed(self):
+        """Test missing fields, wrong arity and wrong types."""
+        with pytest.raises(ValueError, match="rlo"):
+            pyulid.ulid_from_json({"ts": 0, "rhi": 0})
+        with pytest.raises(ValueError):
+            pyulid.ulid_from_json((0, 0))
+        with pytest.raises(TypeError):
+            pyulid.ulid_from_json({"ts": 1.5, "rhi": 0, "rlo": 0})
+        with pytest.raises(TypeError):
+            pyulid.ulid_from_json((0, "1", 0))
+        with pytest.raises(TypeError):
+            pyulid.ulid_from_json("01ARZ3NDEKTSV4RRFFQ69G5FAV")
+        with pytest.raises(TypeError):
+            pyulid.ulid_from_json([0, 0, 0])
+
+
+class TestIPv6:
+    """Test the IPv6 address representation."""
+
+    def test_matches_ipaddress(self):
+        """Test against ipaddress.IPv6Address for random values."""
+        import ipaddress
+
+        for _ in range(1000):
+            value = random.getrandbits(128)
+            ulid_str = pyulid.ulid_from_int(value)
+            address = pyulid.ulid_to_ipv6(ulid_str)
+
+            assert address == ipaddress.IPv6Address(value).compressed
+            assert pyulid.ulid_from_ipv6(address) == ulid_str
+            exploded = ipaddress.IPv6Address(value).exploded
+            assert pyulid.ulid_from_ipv6(exploded) == ulid_str
+
+    def test_zero_run_compression(self):
+        """Test RFC 5952 compression rules."""
+        import ipaddress
+
+        cases = {
+            0: "::",
+            1: "::1",
+            2**127: "8000::",
+            (1 << 112) | 1: "1::1",
+            # A single zero group is never compressed
+            int("00010000000200030004000500060007", 16): "1:0:2:3:4:5:6:7",
+            # The longer run wins; the first wins on a tie
+            int("00010000000000020000000000000003", 16): "1:0:0:2::3",
+            int("00010000000000020000000000030004", 16): "1::2:0:0:3:4",
+        }
+
+        for value, expected in cases.items():
+            address = pyulid.ulid_to_ipv6(pyulid.ulid_from_int(value))
+            assert address == expected
+            assert ipaddress.IPv6Address(address) == ipaddress.IPv6Address(value)
+
+    def test_ipv4_mapped_values(self):
+        """Test that IPv4-mapped values print as hex groups."""
+        import ipaddress
+
+        value = int(ipaddress.IPv6Address("::ffff:1.2.3.4"))
+        address = pyulid.ulid_to_ipv6(pyulid.ulid_from_int(value))
+
+        assert address == "::ffff:102:304"
+        assert pyulid.ulid_from_ipv6(address) == pyulid.ulid_from_int(value)
+
+    def test_accepts_ulid_object_and_uppercase(self):
+        """Test ULID objects and uppercase hex input."""
+        obj = pyulid.ULID()
+        address = pyulid.ulid_to_ipv6(obj)
+        assert pyulid.ulid_from_ipv6(address.upper()) == str(obj)
+
+    def test_invalid(self):
+        """Test that malformed and dotted addresses are rejected."""
+        invalid = [
+            "",
+            "1:2:3",
+            "1::2::3",
+            "12345::",
+            "g::",
+            "::ffff:1.2.3.4",
+            "::1.2.3.4",
+            "fe80::1%eth0",
+            "[::1]",
+        ]
+
+        for address in invalid:
+            with pytest.raises(ValueError):
+                pyulid.ulid_from_ipv6(address)
+        with pytest.raises(ValueError, match="IPv4"):
+            pyulid.ulid_from_ipv6("::ffff:1.2.3.4")
+
+
+
+class TestCompositeSuffix:
+    """Test ULID keys with an encoded numeric suffix."""
+
+    def test_roundtrip(self):
+        """Test that the suffix decodes back to the same ULID and number."""
+        u = pyulid.ulid()
+        for n in [0, 1, 31, 32, 1023]:
+            key = pyulid.ulid_with_suffix(u, n)
+            assert len(key) == 28
+            assert key[:26] == u
+            assert pyulid.ulid_parse_suffix(key) == (u, n)
+
+    def test_width(self):
+        """Test wider suffixes and their range limits."""
+        u = pyulid.ulid()
+        assert pyulid.ulid_with_suffix(u, 0, width=1) == u + "0"
+        assert pyulid.ulid_with_suffix(u, 31, width=1) == u + "Z"
+        assert pyulid.ulid_with_suffix(u, 32**4 - 1, width=4) == u + "ZZZZ"
+        key = pyulid.ulid_with_suffix(u, 2**60 - 1, width=12)
+        assert pyulid.ulid_parse_suffix(key, width=12) == (u, 2**60 - 1)
+
+        with pytest.raises(ValueError):
+            pyulid.ulid_with_suffix(u, 1024)
+        with pytest.raises(ValueError):
+            pyulid.ulid_with_suffix(u, -1)
+        for width in [0, 13]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_with_suffix(u, 0, width=width)
+
+    def test_sort_order(self):
+        """Test that keys sort by ULID first, then by suffix value."""
+        ulids = [pyulid.ulid_from_int(random.getrandbits(128)) for _ in range(50)]
+        ulids += [pyulid.ulid() for _ in range(50)]
+        pairs = [(random.choice(ulids), random.randrange(1024)) for _ in range(2000)]
+
+        keys = [pyulid.ulid_with_suffix(u, n) for u, n in pairs]
+        assert [pyulid.ulid_parse_suffix(k) for k in sorted(keys)] == sorted(pairs)
+
+    def test_case_insensitive_and_url_safe(self):
+        """Test that keys use the ULID alphabet and parse in lowercase."""
+        u = pyulid.ulid()
+        key = pyulid.ulid_with_suffix(u, 777, width=3)
+        assert re.fullmatch(r"[0-9A-HJKMNP-TV-Z]{29}", key)
+        assert pyulid.ulid_parse_suffix(key.lower(), width=3) == (u, 777)
+        assert pyulid.ulid_with_suffix(pyulid.ULID(u), 5) == pyulid.ulid_with_suffix(
+            u, 5
+        )
+
+    def test_parse_invalid(self):
+        """Test that both parts of the key are validated."""
+        u = pyulid.ulid()
+        with pytest.raises(ValueError):
+            pyulid.ulid_parse_suffix(u)
+        with pytest.raises(ValueError):
+            pyulid.ulid_parse_suffix(u + "0U")
+        with pytest.raises(ValueError):
+            pyulid.ulid_parse_suffix("U" + u[1:] + "00")
+        with pytest.raises(ValueError):
+            pyulid.ulid_parse_suffix(u + "00", width=3)
+        with pytest.raises(ValueError):
+            pyulid.ulid_parse_suffix(u[:25] + "\u00e9" + "0")
+        with pytest.raises(ValueError):
+            pyulid.ulid_with_suffix("invalid", 0)
+
 
 if __name__ == "__main__":
     pytest.main([__file__, "-v"])
\ No newline at end of file
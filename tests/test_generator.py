@@ -0,0 +1,386 @@
+"""
+Generator tests for PyULID.
+
+Tests UlidGenerator: seeded, reproducible sequences on an internal clock, and
+unseeded generators keeping an isolated sequence on the system clock.
+"""
+
+import multiprocessing
+import pickle
+import threading
+import time
+from datetime import datetime, timezone
+from operator import methodcaller
+
+import pytest
+import pyulid
+
+T0 = 1672531200000
+
+
+@pytest.fixture
+def restore_config():
+    """Fixture restoring the configuration after the test."""
+    saved = pyulid.get_config()
+    yield
+    pyulid.configure(**saved)
+
+
+@pytest.fixture
+def frozen_clock():
+    """Fixture freezing the clock just ahead of real time, then letting it catch up."""
+    start = int(time.time() * 1000) + 200
+    pyulid._freeze_time(start)
+    try:
+        yield start
+    finally:
+        pyulid._unfreeze_time()
+        while int(time.time() * 1000) <= start + 10:
+            time.sleep(0.001)
+
+
+def _sequence(gen, steps=5, per_step=20):
+    ulids = []
+    for _ in range(steps):
+        ulids += [gen.generate() for _ in range(per_step)]
+        gen.advance(3)
+    return ulids
+
+
+class TestSeeded:
+    """Test reproducibility of seeded generators."""
+
+    def test_same_seed_same_sequence(self):
+        """Test that equal seeds and clocks give identical sequences."""
+        a = pyulid.UlidGenerator(42, T0)
+        b = pyulid.UlidGenerator(42, T0)
+        assert _sequence(a) == _sequence(b)
+
+    def test_different_seeds_diverge(self):
+        """Test that different seeds give different random components."""
+        a = _sequence(pyulid.UlidGenerator(1, T0))
+        b = _sequence(pyulid.UlidGenerator(2, T0))
+        assert not set(a) & set(b)
+        assert [u[:10] for u in a] == [u[:10] for u in b]
+
+    def test_stable_across_runs(self):
+        """Test a pinned value, so the sequence for a seed never silently changes."""
+        gen = pyulid.UlidGenerator(0, T0)
+        assert gen.generate() == "01GNNA1J00D5XVPAHZPB6JRVW0"
+        assert gen.generate() == "01GNNA1J00D5XVPAHZPB6JRVW1"
+
+    def test_global_generator_unaffected(self):
+        """Test that ulid() stays random while seeded generators are used."""
+        gen = pyulid.UlidGenerator(7, T0)
+        before = pyulid.ulid()
+        gen.generate()
+        after = pyulid.ulid()
+        assert pyulid.ulid_timestamp(after) > T0
+        assert after > before
+
+
+class TestClock:
+    """Test the internal clock."""
+
+    def test_monotonic_at_fixed_timestamp(self):
+        """Test that IDs increment while the clock stands still."""
+        gen = pyulid.UlidGenerator(3, T0)
+        ulids = [gen.generate() for _ in range(1000)]
+        assert {pyulid.ulid_timestamp(u) for u in ulids} == {T0}
+        randoms = [pyulid.ulid_random(u) for u in ulids]
+        assert randoms == list(range(randoms[0], randoms[0] + 1000))
+
+    def test_advance(self):
+        """Test that advance() moves the timestamp and keeps ordering."""
+        gen = pyulid.UlidGenerator(3, T0)
+        ulids = _sequence(gen)
+        assert ulids == sorted(ulids)
+        assert gen.timestamp_ms == T0 + 15
+        assert pyulid.ulid_timestamp(ulids[-1]) == T0 + 12
+
+    def test_default_start_is_now(self):
+        """Test that the clock starts at the current time by default."""
+        gen = pyulid.UlidGenerator(3)
+        assert abs(gen.timestamp_ms - time.time() * 1000) < 1000
+        time.sleep(0.01)
+        assert pyulid.ulid_timestamp(gen.generate()) == gen.timestamp_ms
+
+    def test_datetime_start(self):
+        """Test that the start can be a timezone-aware datetime."""
+        start = datetime(2023, 1, 1, tzinfo=timezone.utc)
+        assert pyulid.UlidGenerator(3, start).timestamp_ms == T0
+
+    def test_limits(self):
+        """Test the 48-bit range for the start and for advance()."""
+        with pytest.raises(ValueError):
+            pyulid.UlidGenerator(3, 2**48)
+        gen = pyulid.UlidGenerator(3, 2**48 - 2)
+        gen.advance()
+        with pytest.raises(ValueError):
+            gen.advance()
+        assert gen.timestamp_ms == 2**48 - 1
+        with pytest.raises(OverflowError):
+            gen.advance(-1)
+
+    def test_repr(self):
+        """Test that repr shows the seed and clock."""
+        gen = pyulid.UlidGenerator(9, T0)
+        assert repr(gen) == f"UlidGenerator(seed=9, timestamp_ms={T0})"
+        assert gen.seed == 9
+
+
+class TestCleanWords:
+    """Test that seeded generation refuses the word filter."""
+
+    def test_rejected(self, restore_config):
+        """Test construction and generation with clean_words on."""
+        gen = pyulid.UlidGenerator(5, T0)
+        pyulid.configure(clean_words=True)
+        with pytest.raises(ValueError, match="clean_words"):
+            pyulid.UlidGenerator(5, T0)
+        with pytest.raises(ValueError, match="clean_words"):
+            gen.generate()
+
+        pyulid.configure(clean_words=False)
+        assert gen.generate() == pyulid.UlidGenerator(5, T0).generate()
+
+
+class TestUnseeded:
+    """Test unseeded generators with isolated, per-instance state."""
+
+    def test_independent_sequences(self, frozen_clock):
+        """Test that two generators at one frozen timestamp don't share a counter."""
+        a, b = pyulid.UlidGenerator(), pyulid.UlidGenerator()
+        first_a = [a.generate() for _ in range(100)]
+        first_b = [b.generate() for _ in range(100)]
+        more_a = [a.generate() for _ in range(100)]
+
+        for ulids in (first_a + more_a, first_b):
+            assert {pyulid.ulid_timestamp(u) for u in ulids} == {frozen_clock}
+            randoms = [pyulid.ulid_random(u) for u in ulids]
+            assert randoms == list(range(randoms[0], randoms[0] + len(ulids)))
+        assert not set(first_a + more_a) & set(first_b)
+
+    def test_global_sequence_unaffected(self, frozen_clock):
+        """Test that a burst on a generator doesn't advance ulid()."""
+        gen = pyulid.UlidGenerator()
+        before = pyulid.ulid()
+        gen.generate_batch(1000)
+        after = pyulid.ulid()
+        assert pyulid.ulid_random(after) == pyulid.ulid_random(before) + 1
+
+    def test_follows_system_clock(self):
+        """Test that IDs carry the current time."""
+        ulid_str = pyulid.UlidGenerator().generate()
+        assert abs(pyulid.ulid_timestamp(ulid_str) - time.time() * 1000) < 1000
+
+    def test_generate_bytes(self, frozen_clock):
+        """Test that generate_bytes continues the same sequence."""
+        gen = pyulid.UlidGenerator()
+        text = gen.generate()
+        raw = gen.generate_bytes()
+        assert len(raw) == 16
+        assert pyulid.ulid_from_bytes(raw) > text
+        assert pyulid.ulid_random(pyulid.ulid_from_bytes(raw)) == (
+            pyulid.ulid_random(text) + 1
+        )
+
+    def test_generate_batch(self):
+        """Test that batches are sorted, unique and continue the sequence."""
+        gen = pyulid.UlidGenerator()
+        batch = gen.generate_batch(10_000)
+        assert len(batch) == 10_000
+        assert batch == sorted(batch)
+        assert len(set(batch)) == len(batch)
+        assert gen.generate() > batch[-1]
+        assert gen.generate_batch(0) == []
+
+    def test_threads_share_one_generator(self):
+        """Test concurrent use of one instance from several threads."""
+        gen = pyulid.UlidGenerator()
+        results = [[] for _ in range(4)]
+
+        def worker(out):
+            for _ in range(50):
+                out.extend(gen.generate_batch(100))
+                out.append(gen.generate())
+
+        threads = [threading.Thread(target=worker, args=(r,)) for r in results]
+        for t in threads:
+            t.start()
+        for t in threads:
+            t.join()
+
+        everything = [u for r in results for u in r]
+        assert len(set(everything)) == len(everything)
+        for r in results:
+            assert r == sorted(r)
+
+    def test_no_internal_clock(self):
+        """Test that clock controls need a seed."""
+        gen = pyulid.UlidGenerator()
+        with pytest.raises(ValueError, match="seeded"):
+            gen.advance()
+        with pytest.raises(ValueError, match="seeded"):
+            gen.set_timestamp(T0)
+        with pytest.raises(ValueError, match="needs a seed"):
+            pyulid.UlidGenerator(start_timestamp_ms=T0)
+
+    def test_clean_words_allowed(self, restore_config):
+        """Test that unseeded generators filter like ulid()."""
+        pyulid.configure(clean_words=["zz"])
+        ulids = pyulid.UlidGenerator().generate_batch(3000)
+        assert ulids == sorted(ulids)
+        assert not any("ZZ" in u for u in ulids)
+
+    def test_repr(self):
+        """Test that repr and seed reflect the unseeded mode."""
+        gen = pyulid.UlidGenerator()
+        assert repr(gen) == "UlidGenerator()"
+        assert gen.seed is None
+        assert abs(gen.timestamp_ms - time.time() * 1000) < 1000
+
+
+class TestNodeId:
+    """Test node IDs reserved in the top bits of the random component."""
+
+    def test_node_bits_fixed(self, frozen_clock):
+        """Test that every ULID carries the node ID and the counter stays below it."""
+        gen = pyulid.UlidGenerator(node_id=5, node_bits=4)
+        ulids = [gen.generate() for _ in range(100)] + gen.generate_batch(100)
+        assert {pyulid.ulid_node_id(u, 4) for u in ulids} == {5}
+        randoms = [pyulid.ulid_random(u) for u in ulids]
+        assert randoms == list(range(randoms[0], randoms[0] + len(ulids)))
+        assert pyulid.ulid_node_id(pyulid.ulid_from_bytes(gen.generate_bytes()), 4) == 5
+
+    def test_nodes_never_collide(self):
+        """Test that seeded generators sharing a seed differ only by node."""
+        a = pyulid.UlidGenerator(42, T0, node_id=1, node_bits=16)
+        b = pyulid.UlidGenerator(42, T0, node_id=2, node_bits=16)
+        ulids_a, ulids_b = _sequence(a), _sequence(b)
+        assert not set(ulids_a) & set(ulids_b)
+        mask = 2**64 - 1
+        for x, y in zip(ulids_a, ulids_b):
+            assert pyulid.ulid_random(x) & mask == pyulid.ulid_random(y) & mask
+
+    def test_new_millisecond_keeps_node(self):
+        """Test that a rollover draws only the low bits."""
+        gen = pyulid.UlidGenerator(7, T0, node_id=2**16 - 1, node_bits=16)
+        for _ in range(20):
+            assert pyulid.ulid_node_id(gen.generate(), 16) == 2**16 - 1
+            gen.advance()
+
+    def test_overflow_stays_in_low_bits(self):
+        """Test that exhausting the low bits raises instead of touching the node."""
+        gen = pyulid.UlidGenerator(0, T0, node_id=3, node_bits=16)
+        gen._resume(pyulid.ulid_from_int((T0 << 80) | (3 << 64) | (2**64 - 2)))
+
+        last = gen.generate()
+        assert pyulid.ulid_random(last) == (3 << 64) | (2**64 - 1)
+        with pytest.raises(pyulid.UlidOverflowError, match="overflow"):
+            gen.generate()
+        with pytest.raises(RuntimeError, match=r"overflow.*after 0 of 5"):
+            gen.generate_batch(5)
+
+        gen.advance()
+        assert pyulid.ulid_node_id(gen.generate(), 16) == 3
+
+    def test_validation(self):
+        """Test the node_id and node_bits checks."""
+        with pytest.raises(ValueError, match=r"node_id < 2\*\*4, got 16"):
+            pyulid.UlidGenerator(node_id=16, node_bits=4)
+        for bits in [0, 17]:
+            with pytest.raises(ValueError, match=r"1..=16"):
+                pyulid.UlidGenerator(node_id=0, node_bits=bits)
+            with pytest.raises(ValueError, match=r"1..=16"):
+                pyulid.ulid_node_id(pyulid.ulid(), bits)
+        with pytest.raises(ValueError, match="together"):
+            pyulid.UlidGenerator(node_id=1)
+
+    def test_clean_words_rejected(self, restore_config):
+        """Test that node IDs can't be combined with clean words."""
+        gen = pyulid.UlidGenerator(node_id=1, node_bits=8)
+        pyulid.configure(clean_words=["zz"])
+        with pytest.raises(ValueError, match="node_id"):
+            gen.generate()
+        with pytest.raises(ValueError, match="node_id"):
+            pyulid.UlidGenerator(node_id=1, node_bits=8)
+
+    def test_repr_and_getters(self):
+        """Test that repr and the getters show the node."""
+        gen = pyulid.UlidGenerator(node_id=9, node_bits=8)
+        assert (gen.node_id, gen.node_bits) == (9, 8)
+        assert repr(gen) == "UlidGenerator(node_id=9, node_bits=8)"
+        seeded = pyulid.UlidGenerator(1, T0, node_id=9, node_bits=8)
+        assert repr(seeded) == (
+            f"UlidGenerator(seed=1, timestamp_ms={T0}, node_id=9, node_bits=8)"
+        )
+        assert pyulid.UlidGenerator().node_id is None
+
+
+class TestPickle:
+    """Test pickling generators for worker processes."""
+
+    @pytest.mark.parametrize("protocol", range(2, pickle.HIGHEST_PROTOCOL + 1))
+    def test_keeps_configuration(self, protocol):
+        """Test that seed, clock and node survive, and the copy continues after."""
+        gen = pyulid.UlidGenerator(42, T0, node_id=3, node_bits=8)
+        gen.advance(5)
+        last = gen.generate()
+
+        restored = pickle.loads(pickle.dumps(gen, protocol=protocol))
+        assert (restored.seed, restored.node_id, restored.node_bits) == (42, 3, 8)
+        assert restored.timestamp_ms == T0 + 5
+        ulids = [restored.generate() for _ in range(100)]
+        assert ulids == sorted(ulids)
+        assert ulids[0] > last
+        assert {pyulid.ulid_node_id(u, 8) for u in ulids} == {3}
+
+    def test_fresh_rng(self):
+        """Test that copies of one pickle diverge from the original and each other."""
+        gen = pyulid.UlidGenerator(42, T0)
+        gen.generate()
+        payload = pickle.dumps(gen)
+        copies = [pickle.loads(payload) for _ in range(3)]
+
+        streams = [_sequence(g) for g in [gen] + copies]
+        everything = [u for ulids in streams for u in ulids]
+        assert len(set(everything)) == len(everything)
+
+    def test_unseeded_within_a_millisecond(self, frozen_clock):
+        """Test that copies sharing the frozen millisecond stay above the original."""
+        gen = pyulid.UlidGenerator()
+        last = gen.generate()
+        payload = pickle.dumps(gen)
+
+        copies = [pickle.loads(payload).generate_batch(100) for _ in range(4)]
+        everything = [u for ulids in copies for u in ulids]
+        assert len(set(everything)) == len(everything)
+        assert min(everything) > last
+        assert {pyulid.ulid_timestamp(u) for u in everything} == {frozen_clock}
+
+    def test_validation(self):
+        """Test that a corrupted pickle is rejected instead of half-restored."""
+        with pytest.raises(ValueError, match="together"):
+            pyulid.UlidGenerator._unpickle(None, None, 1, None, 0)
+        with pytest.raises(ValueError, match="seeded"):
+            pyulid.UlidGenerator._unpickle(1, None, None, None, 0)
+
+    @pytest.mark.skipif(
+        "spawn" not in multiprocessing.get_all_start_methods(),
+        reason="spawn start method not available",
+    )
+    def test_spawned_workers(self):
+        """Test that spawned workers handed one generator never repeat each other."""
+        gen = pyulid.UlidGenerator(node_id=1, node_bits=4)
+        last = gen.generate()
+
+        context = multiprocessing.get_context("spawn")
+        with context.Pool(2) as pool:
+            batches = pool.map(methodcaller("generate_batch", 1000), [gen] * 4)
+        batches.append(gen.generate_batch(1000))
+
+        everything = [u for ulids in batches for u in ulids]
+        assert len(set(everything)) == len(everything)
+        assert min(everything) > last
+        assert {pyulid.ulid_node_id(u, 4) for u in everything} == {1}
@@ -0,0 +1,195 @@
+"""
+Interval tests for PyULID.
+
+Tests UlidInterval construction, half-open edge semantics and set operations.
+"""
+
+from datetime import datetime, timedelta, timezone
+
+import pytest
+import pyulid
+
+T0 = 1672531200000
+
+
+def _min(ts):
+    return pyulid.ulid_floor(pyulid.ulid_with_timestamp(ts))
+
+
+def _interval(start_ms, end_ms):
+    return pyulid.UlidInterval(_min(start_ms), _min(end_ms))
+
+
+class TestConstruction:
+    """Test building intervals."""
+
+    def test_from_ulids(self):
+        """Test that bounds are kept as given."""
+        a, b = pyulid.ulid(), pyulid.ulid()
+        interval = pyulid.UlidInterval(a, b)
+        assert interval.start == a
+        assert interval.end == b
+        assert interval[0] == a and interval[1] == b
+        assert interval[-2] == a and interval[-1] == b
+        assert pyulid.UlidInterval(pyulid.ULID(a), a.lower()) == pyulid.UlidInterval(
+            a, a
+        )
+
+    def test_not_iterable(self):
+        """Test that indexable bounds don't make the interval iterable."""
+        interval = pyulid.UlidInterval(pyulid.ulid(), pyulid.ulid())
+        with pytest.raises(TypeError, match="not iterable"):
+            iter(interval)
+        with pytest.raises(TypeError):
+            list(interval)
+        with pytest.raises(TypeError):
+            start, end = interval
+
+    def test_from_datetimes(self):
+        """Test that a datetime maps to the first ULID of its millisecond."""
+        start = datetime(2023, 1, 1, tzinfo=timezone.utc)
+        interval = pyulid.UlidInterval(start, start + timedelta(seconds=1))
+
+        assert interval.start == _min(T0)
+        assert interval.end == _min(T0 + 1000)
+        assert interval.duration_ms == 1000
+
+    def test_mixed_bounds(self):
+        """Test a ULID start with a datetime end."""
+        start = pyulid.ulid_with_timestamp(T0)
+        end = datetime(2023, 1, 2, tzinfo=timezone.utc)
+        assert pyulid.UlidInterval(start, end).duration_ms == 86_400_000
+
+    def test_empty(self):
+        """Test that equal bounds give an empty interval."""
+        u = pyulid.ulid()
+        empty = pyulid.UlidInterval(u, u)
+        assert empty.is_empty
+        assert not empty
+        assert empty.duration_ms == 0
+        assert u not in empty
+        assert not empty.contains(u)
+        assert _interval(0, 1)
+
+    def test_invalid(self):
+        """Test reversed, malformed and ambiguous bounds."""
+        with pytest.raises(ValueError):
+            _interval(T0 + 1, T0)
+        with pytest.raises(ValueError):
+            pyulid.UlidInterval("invalid", pyulid.ulid())
+        with pytest.raises(TypeError):
+            pyulid.UlidInterval(0, T0)
+        with pytest.raises(ValueError):
+            pyulid.UlidInterval(datetime(2023, 1, 1), pyulid.ulid())
+        with pytest.raises(IndexError):
+            _interval(0, 1)[2]
+
+
+class TestContainment:
+    """Test half-open membership."""
+
+    def test_endpoints(self):
+        """Test that start is included and end is excluded."""
+        interval = _interval(T0, T0 + 10)
+        assert interval.contains(interval.start)
+        assert not interval.contains(interval.end)
+        assert interval.contains(pyulid.ulid_prev(interval.end))
+        assert not interval.contains(pyulid.ulid_prev(interval.start))
+
+    def test_operator(self):
+        """Test the `in` operator, which is False for invalid input."""
+        interval = _interval(T0, T0 + 10)
+        inside = pyulid.ulid_with_timestamp(T0 + 5)
+        assert inside in interval
+        assert pyulid.ULID(inside) in interval
+        assert pyulid.ulid_with_timestamp(T0 + 10) not in interval
+        assert "invalid" not in interval
+        assert 42 not in interval
+
+    def test_contains_rejects_invalid(self):
+        """Test that contains() validates its argument."""
+        with pytest.raises(ValueError):
+            _interval(0, 1).contains("invalid")
+
+
+class TestOperations:
+    """Test overlap, intersection and union."""
+
+    def test_overlaps(self):
+        """Test overlapping, touching and disjoint intervals."""
+        a = _interval(0, 10)
+        assert a.overlaps(_interval(5, 15))
+        assert a.overlaps(_interval(2, 3))
+        assert not a.overlaps(_interval(10, 20))  # touching
+        assert not _interval(10, 20).overlaps(a)
+        assert not a.overlaps(_interval(11, 20))
+
+    def test_empty_never_overlaps(self):
+        """Test that an empty interval overlaps nothing, even inside another."""
+        assert not _interval(0, 10).overlaps(_interval(5, 5))
+        assert not _interval(5, 5).overlaps(_interval(5, 5))
+
+    def test_intersection(self):
+        """Test intersections, including touching and disjoint operands."""
+        a = _interval(0, 10)
+        assert a.intersection(_interval(5, 15)) == _interval(5, 10)
+        assert a.intersection(_interval(2, 3)) == _interval(2, 3)
+
+        touching = a.intersection(_interval(10, 20))
+        assert touching.is_empty and touching == _interval(10, 10)
+        disjoint = a.intersection(_interval(15, 20))
+        assert disjoint.is_empty and disjoint == _interval(15, 15)
+
+    def test_union(self):
+        """Test unions of overlapping and touching intervals."""
+        a = _interval(0, 10)
+        assert a.union(_interval(5, 15)) == _interval(0, 15)
+        assert a.union(_interval(10, 20)) == _interval(0, 20)
+        assert _interval(10, 20).union(a) == _interval(0, 20)
+        assert a.union(_interval(2, 3)) == a
+
+    def test_union_gap(self):
+        """Test that disjoint unions need allow_gap=True."""
+        a, b = _interval(0, 10), _interval(11, 20)
+        with pytest.raises(ValueError, match="allow_gap"):
+            a.union(b)
+        assert a.union(b, allow_gap=True) == _interval(0, 20)
+
+    def test_union_with_empty(self):
+        """Test that empty operands are ignored, wherever they sit."""
+        a = _interval(0, 10)
+        assert a.union(_interval(50, 50)) == a
+        assert _interval(50, 50).union(a) == a
+
+
+class TestComparison:
+    """Test equality, ordering and hashing."""
+
+    def test_equality_and_hash(self):
+        """Test that equal bounds give equal, interchangeable keys."""
+        a, b = _interval(0, 10), _interval(0, 10)
+        assert a == b
+        assert hash(a) == hash(b)
+        assert {a: "x"}[b] == "x"
+        assert len({a, b, _interval(0, 11)}) == 2
+        assert a != (a.start, a.end)
+
+    def test_ordering(self):
+        """Test that intervals order by start, then end."""
+        intervals = [_interval(5, 6), _interval(0, 10), _interval(0, 5)]
+        assert sorted(intervals) == [_interval(0, 5), _interval(0, 10), _interval(5, 6)]
+        assert _interval(0, 5) < _interval(0, 10) <= _interval(0, 10)
+
+    def test_repr(self):
+        """Test the repr."""
+        a = _interval(0, 1)
+        assert repr(a) == f"UlidInterval('{a.start}', '{a.end}')"
+
+    def test_immutable(self):
+        """Test that bounds can't be reassigned."""
+        with pytest.raises(AttributeError):
+            _interval(0, 1).start = pyulid.ulid()
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
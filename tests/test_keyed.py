@@ -0,0 +1,135 @@
+"""
+Per-key monotonic generation tests for PyULID.
+
+Tests KeyedUlidGenerator ordering, LRU bounds and thread safety.
+"""
+
+import threading
+
+import pytest
+import pyulid
+
+
+class TestKeyedOrdering:
+    """Test per-key monotonic ordering."""
+
+    def test_monotonic_within_key(self):
+        """Test that each key's sequence is strictly increasing."""
+        gen = pyulid.KeyedUlidGenerator()
+        per_key = {key: [] for key in ["tenant-a", "tenant-b", "tenant-c"]}
+
+        for i in range(3000):
+            key = ["tenant-a", "tenant-b", "tenant-c"][i % 3]
+            per_key[key].append(gen.generate(key))
+
+        for ulids in per_key.values():
+            assert ulids == sorted(ulids)
+            assert len(set(ulids)) == len(ulids)
+
+    def test_generated_values_are_valid(self):
+        """Test that generated ULIDs are valid and current."""
+        gen = pyulid.KeyedUlidGenerator()
+        ulid_str = gen.generate("key")
+        assert pyulid.ulid_is_valid(ulid_str)
+        assert pyulid.ulid_from_str(ulid_str) == ulid_str
+
+    def test_key_types(self):
+        """Test str, bytes and int keys are independent."""
+        gen = pyulid.KeyedUlidGenerator()
+        for key in ["1", b"1", 1, -(2**100)]:
+            gen.generate(key)
+
+        assert len(gen) == 4
+        assert "1" in gen and b"1" in gen and 1 in gen
+        assert "2" not in gen
+
+    def test_invalid_keys(self):
+        """Test unsupported key types and oversized ints."""
+        gen = pyulid.KeyedUlidGenerator()
+        with pytest.raises(TypeError):
+            gen.generate(1.5)
+        with pytest.raises(TypeError):
+            gen.generate(None)
+        with pytest.raises(ValueError):
+            gen.generate(2**200)
+
+
+class TestKeyedEviction:
+    """Test the LRU bound on live keys."""
+
+    def test_live_keys_bounded(self):
+        """Test that the number of live keys never exceeds max_keys."""
+        gen = pyulid.KeyedUlidGenerator(max_keys=100)
+        assert gen.max_keys == 100
+
+        for i in range(1000):
+            gen.generate(i)
+            assert len(gen) <= 100
+
+        assert len(gen) == 100
+        assert 999 in gen and 0 not in gen
+
+    def test_least_recently_used_is_evicted(self):
+        """Test that touching a key keeps it alive."""
+        gen = pyulid.KeyedUlidGenerator(max_keys=2)
+        gen.generate("a")
+        gen.generate("b")
+        gen.generate("a")
+        gen.generate("c")  # Evicts "b", not "a"
+
+        assert "a" in gen and "c" in gen
+        assert "b" not in gen
+
+    def test_recreated_key_never_goes_back(self):
+        """Test that an evicted and re-created key continues past its last ID."""
+        gen = pyulid.KeyedUlidGenerator(max_keys=1)
+
+        for _ in range(2000):
+            first = gen.generate("a")
+            gen.generate("b")  # Evicts "a"
+            again = gen.generate("a")  # Evicts "b", re-creates "a"
+            assert again > first
+
+    def test_invalid_max_keys(self):
+        """Test that max_keys must be positive."""
+        with pytest.raises(ValueError):
+            pyulid.KeyedUlidGenerator(max_keys=0)
+        with pytest.raises(OverflowError):
+            pyulid.KeyedUlidGenerator(max_keys=-1)
+
+
+class TestKeyedConcurrency:
+    """Test thread safety of KeyedUlidGenerator."""
+
+    def test_concurrent_generation(self):
+        """Test that threads sharing keys keep per-key order and uniqueness."""
+        gen = pyulid.KeyedUlidGenerator(max_keys=4)
+        results = {}
+        lock = threading.Lock()
+
+        def worker(thread_id):
+            local = []
+            for i in range(2000):
+                key = i % 3
+                local.append((key, gen.generate(key)))
+            with lock:
+                results[thread_id] = local
+
+        threads = [threading.Thread(target=worker, args=(t,)) for t in range(4)]
+        for t in threads:
+            t.start()
+        for t in threads:
+            t.join()
+
+        all_ulids = [u for local in results.values() for _, u in local]
+        assert len(set(all_ulids)) == len(all_ulids)
+
+        # Each thread observes its own per-key sequence in increasing order
+        for local in results.values():
+            for key in range(3):
+                seq = [u for k, u in local if k == key]
+                assert seq == sorted(seq)
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
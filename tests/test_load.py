@@ -0,0 +1,103 @@
+"""
+Load generator tests for PyULID.
+
+Tests the pacing and output of UlidLoadGenerator.
+"""
+
+import itertools
+import threading
+import time
+
+import pytest
+import pyulid
+
+
+class TestLoadGenerator:
+    """Test UlidLoadGenerator."""
+
+    def test_take_rate_accuracy(self):
+        """Test that take() produces the target rate within a few percent."""
+        gen = pyulid.UlidLoadGenerator(1000, burst=20)
+        ulids = gen.take(0.5)
+
+        # 500 paced ULIDs plus the 19 extra the full initial bucket allows
+        assert 519 * 0.95 <= len(ulids) <= 519 * 1.02
+        assert ulids == sorted(ulids)
+        assert len(set(ulids)) == len(ulids)
+        assert all(pyulid.ulid_is_valid(u) for u in ulids)
+
+    def test_iteration_rate_accuracy(self):
+        """Test that iterating is paced to the target rate."""
+        gen = pyulid.UlidLoadGenerator(200, burst=5)
+        start = time.perf_counter()
+        ulids = list(itertools.islice(gen, 100))
+        duration = time.perf_counter() - start
+
+        # The first 5 come from the full bucket, the other 95 are spaced 5 ms apart
+        assert 0.475 * 0.98 <= duration <= 0.475 * 1.05
+        assert ulids == sorted(ulids)
+
+    def test_burst(self):
+        """Test that a full bucket emits `burst` ULIDs without waiting."""
+        gen = pyulid.UlidLoadGenerator(1, burst=5)
+        start = time.perf_counter()
+        ulids = [next(gen) for _ in range(5)]
+
+        assert time.perf_counter() - start < 0.5
+        assert len(ulids) == 5
+        assert gen.burst == 5
+        assert gen.rate_per_sec == 1
+
+    def test_monotonic_with_global_sequence(self):
+        """Test that paced ULIDs share the global monotonic sequence."""
+        before = pyulid.ulid()
+        ulids = pyulid.UlidLoadGenerator(10000).take(0.01)
+        after = pyulid.ulid()
+        assert before < ulids[0] and ulids[-1] < after
+
+    def test_releases_gil(self):
+        """Test that other threads run while the generator sleeps."""
+        counter = []
+        stop = threading.Event()
+
+        def spin():
+            while not stop.is_set():
+                counter.append(1)
+                time.sleep(0.001)
+
+        worker = threading.Thread(target=spin)
+        worker.start()
+        try:
+            pyulid.UlidLoadGenerator(20).take(0.2)
+        finally:
+            stop.set()
+            worker.join()
+        assert len(counter) > 10
+
+    def test_take_zero(self):
+        """Test that a zero-length window never waits."""
+        gen = pyulid.UlidLoadGenerator(1, burst=3)
+        [next(gen) for _ in range(3)]
+
+        start = time.perf_counter()
+        assert gen.take(0) == []
+        assert time.perf_counter() - start < 0.5
+
+    def test_invalid_arguments(self):
+        """Test argument validation."""
+        for rate in [0, -1, float("nan"), float("inf")]:
+            with pytest.raises(ValueError):
+                pyulid.UlidLoadGenerator(rate)
+        with pytest.raises(ValueError):
+            pyulid.UlidLoadGenerator(10, burst=0)
+        with pytest.raises(ValueError):
+            pyulid.UlidLoadGenerator(10).take(-1)
+
+    def test_repr(self):
+        """Test the repr."""
+        gen = pyulid.UlidLoadGenerator(100, burst=2)
+        assert repr(gen) == "UlidLoadGenerator(rate_per_sec=100, burst=2)"
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
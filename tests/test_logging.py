@@ -0,0 +1,210 @@
+"""
+Logging bridge tests for PyULID.
+
+Tests that internal events reach the "pyulid" logger only when enabled.
+"""
+
+import logging
+import multiprocessing
+import sys
+import time
+
+import pytest
+import pyulid
+
+
+@pytest.fixture
+def pyulid_logging():
+    """Fixture enabling DEBUG forwarding and restoring the default afterwards."""
+    pyulid.enable_logging("DEBUG")
+    yield
+    pyulid.disable_logging()
+    logging.getLogger("pyulid").setLevel(logging.NOTSET)
+
+
+@pytest.fixture
+def restore_config():
+    """Fixture restoring the configuration after the test."""
+    saved = pyulid.get_config()
+    yield
+    pyulid.configure(**saved)
+
+
+@pytest.fixture
+def frozen_clock():
+    """Fixture freezing the clock just ahead of real time, then letting it catch up."""
+    start = int(time.time() * 1000) + 200
+    pyulid._freeze_time(start)
+    try:
+        yield start
+    finally:
+        pyulid._unfreeze_time()
+        while int(time.time() * 1000) <= start + 10:
+            time.sleep(0.001)
+
+
+def _messages(caplog, level):
+    return [
+        r.getMessage()
+        for r in caplog.records
+        if r.name == "pyulid" and r.levelno == level
+    ]
+
+
+def _evict_one():
+    gen = pyulid.KeyedUlidGenerator(max_keys=1)
+    gen.generate("a")
+    gen.generate("b")  # Evicts "a"
+
+
+class TestLoggingBridge:
+    """Test enable_logging/disable_logging."""
+
+    def test_off_by_default(self, caplog):
+        """Test that nothing is logged unless enabled."""
+        caplog.set_level(logging.DEBUG)
+        _evict_one()
+        pyulid.ulid()
+        assert not [r for r in caplog.records if r.name == "pyulid"]
+
+    def test_events_forwarded(self, caplog, pyulid_logging):
+        """Test that events are emitted on the pyulid logger."""
+        _evict_one()
+
+        records = [r for r in caplog.records if r.name == "pyulid"]
+        assert len(records) == 1
+        assert records[0].levelno == logging.DEBUG
+        assert "evicted" in records[0].getMessage()
+        assert "'a'" in records[0].getMessage()
+
+    def test_level_filtering(self, caplog):
+        """Test that events below the enabled level are dropped."""
+        pyulid.enable_logging("INFO")
+        try:
+            _evict_one()
+        finally:
+            pyulid.disable_logging()
+            logging.getLogger("pyulid").setLevel(logging.NOTSET)
+
+        assert not [r for r in caplog.records if r.name == "pyulid"]
+
+    def test_disable(self, caplog, pyulid_logging):
+        """Test that disable_logging stops forwarding."""
+        pyulid.disable_logging()
+        _evict_one()
+        assert not [r for r in caplog.records if r.name == "pyulid"]
+
+    def test_reseed_event(self, caplog, pyulid_logging):
+        """Test that reseed() logs an INFO event."""
+        pyulid.reseed()
+
+        records = [
+            r
+            for r in caplog.records
+            if r.name == "pyulid" and "reseeded" in r.getMessage()
+        ]
+        assert [r.levelno for r in records] == [logging.INFO]
+
+    def test_drift_tolerance_event(
+        self, caplog, pyulid_logging, frozen_clock, restore_config
+    ):
+        """Test that a step back absorbed by the drift tolerance logs a DEBUG event."""
+        pyulid.configure(clock_drift_tolerance_ms=5)
+        pyulid.ulid()
+        pyulid._freeze_time(frozen_clock - 2)
+        pyulid.ulid()
+
+        gen = pyulid.UlidGenerator(1, frozen_clock)
+        gen.generate()
+        gen.set_timestamp(frozen_clock - 3)
+        gen.generate()
+
+        held = [m for m in _messages(caplog, logging.DEBUG) if "drift tolerance" in m]
+        assert any("by 2 ms" in m and str(frozen_clock) in m for m in held)
+        assert any("by 3 ms" in m and str(frozen_clock) in m for m in held)
+        assert not _messages(caplog, logging.WARNING)
+
+    @pytest.mark.skipif(
+        "fork" not in multiprocessing.get_all_start_methods(),
+        reason="fork start method not available",
+    )
+    def test_fork_reseed_event(self, pyulid_logging):
+        """Test that a forked child logs its reseed as an INFO event."""
+        seen = []
+
+        class Collect(logging.Handler):
+            def emit(self, record):
+                seen.append((record.levelno, record.getMessage()))
+
+        handler = Collect()
+        logger = logging.getLogger("pyulid")
+        logger.addHandler(handler)
+        pyulid.ulid()
+        context = multiprocessing.get_context("fork")
+        results = context.Queue()
+        child = context.Process(target=lambda: results.put(seen))
+        try:
+            child.start()
+            in_child = results.get(timeout=30)
+            child.join(timeout=30)
+        finally:
+            logger.removeHandler(handler)
+
+        assert child.exitcode == 0
+        reseeds = [level for level, m in in_child if "forked child" in m]
+        assert reseeds == [logging.INFO]
+        assert not [m for _, m in seen if "forked child" in m]
+
+    def test_reentrant_handler(self, pyulid_logging):
+        """Test that a handler generating ULIDs doesn't deadlock."""
+        seen = []
+
+        class Handler(logging.Handler):
+            def emit(self, record):
+                seen.append(pyulid.ulid())
+
+        handler = Handler()
+        logger = logging.getLogger("pyulid")
+        logger.addHandler(handler)
+        try:
+            _evict_one()
+        finally:
+            logger.removeHandler(handler)
+
+        assert len(seen) == 1
+        assert pyulid.ulid_is_valid(seen[0])
+
+    def test_broken_handler_does_not_break_generation(
+        self, pyulid_logging, monkeypatch
+    ):
+        """Test that a raising handler is reported as unraisable, not raised."""
+        unraisable = []
+        monkeypatch.setattr(sys, "unraisablehook", unraisable.append)
+
+        class Broken(logging.Handler):
+            def handle(self, record):
+                raise RuntimeError("handler failure")
+
+        handler = Broken()
+        logger = logging.getLogger("pyulid")
+        logger.addHandler(handler)
+        try:
+            gen = pyulid.KeyedUlidGenerator(max_keys=1)
+            gen.generate("a")
+            assert pyulid.ulid_is_valid(gen.generate("b"))
+        finally:
+            logger.removeHandler(handler)
+
+        assert len(unraisable) == 1
+        assert isinstance(unraisable[0].exc_value, RuntimeError)
+
+    def test_invalid_level(self):
+        """Test that unknown levels are rejected."""
+        with pytest.raises(ValueError):
+            pyulid.enable_logging("LOUD")
+        with pytest.raises(ValueError):
+            pyulid.enable_logging(0)
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
@@ -4,9 +4,13 @@ Monotonic ULID generation and state management tests.
 Tests monotonic ordering, state management, and related functionality.
 """
 
+import multiprocessing
 import pytest
 import pyulid
+import sys
+import sysconfig
 import time
+from datetime import datetime, timezone
 from typing import List
 
 
@@ -201,6 +205,659 @@ class TestTimestampSpecificGeneration:
         # This is correct behavior - monotonic ordering only applies to ulid() function
 
 
+class TestUlidBytes:
+    """Test generating ULIDs directly as bytes."""
+
+    def test_format(self):
+        """Test that ulid_bytes returns 16 bytes of a current ULID."""
+        raw = pyulid.ulid_bytes()
+        assert isinstance(raw, bytes) and len(raw) == 16
+        ulid_str = pyulid.ulid_from_bytes(raw)
+        assert abs(pyulid.ulid_timestamp(ulid_str) - time.time() * 1000) < 1000
+
+    def test_interleaved_with_ulid(self):
+        """Test that ulid() and ulid_bytes() share one strictly increasing sequence."""
+        values = []
+        for i in range(2000):
+            if i % 3:
+                values.append(int.from_bytes(pyulid.ulid_bytes(), "big"))
+            else:
+                values.append(pyulid.ulid_to_int(pyulid.ulid()))
+        assert all(a < b for a, b in zip(values, values[1:]))
+
+
+class TestUlidInto:
+    """Test generating ULIDs into caller-provided buffers."""
+
+    def test_bytearray(self):
+        """Test that 26 ASCII characters land at the offset and nothing else changes."""
+        buffer = bytearray(b"-" * 40)
+        assert pyulid.ulid_into(buffer, 7) == 26
+        assert buffer[:7] == b"-" * 7 and buffer[33:] == b"-" * 7
+        ulid_str = buffer[7:33].decode("ascii")
+        assert pyulid.ulid_is_valid(ulid_str)
+        assert abs(pyulid.ulid_timestamp(ulid_str) - time.time() * 1000) < 1000
+
+    def test_default_offset_and_exact_fit(self):
+        """Test offset 0 into a buffer of exactly 26 bytes."""
+        buffer = bytearray(26)
+        assert pyulid.ulid_into(buffer) == 26
+        assert pyulid.ulid_is_valid(bytes(buffer))
+
+    def test_memoryview(self):
+        """Test writing through a memoryview slice into the underlying object."""
+        backing = bytearray(100)
+        pyulid.ulid_into(memoryview(backing)[50:], 10)
+        assert pyulid.ulid_is_valid(backing[60:86].decode())
+        assert backing[:60] == bytes(60) and backing[86:] == bytes(14)
+
+    def test_monotonic_with_ulid(self):
+        """Test that ulid_into shares the monotonic sequence with ulid()."""
+        buffer = bytearray(26)
+        values = []
+        for i in range(2000):
+            if i % 2:
+                pyulid.ulid_into(buffer)
+                values.append(buffer.decode())
+            else:
+                values.append(pyulid.ulid())
+        assert values == sorted(values) and len(set(values)) == len(values)
+
+    def test_too_small(self):
+        """Test that fewer than 26 bytes at the offset raise ValueError."""
+        with pytest.raises(ValueError, match="only 25 available"):
+            pyulid.ulid_into(bytearray(25))
+        with pytest.raises(ValueError, match="only 0 available"):
+            pyulid.ulid_into(bytearray(30), 40)
+        buffer = bytearray(30)
+        with pytest.raises(ValueError):
+            pyulid.ulid_into(buffer, 5)
+        assert buffer == bytearray(30)
+
+    def test_read_only(self):
+        """Test that read-only buffers raise BufferError."""
+        for buffer in [b"x" * 26, memoryview(bytearray(26)).toreadonly()]:
+            with pytest.raises(BufferError, match="read-only"):
+                pyulid.ulid_into(buffer)
+
+    def test_non_contiguous(self):
+        """Test that strided views raise BufferError."""
+        with pytest.raises(BufferError):
+            pyulid.ulid_into(memoryview(bytearray(60))[::2])
+
+    def test_not_a_buffer(self):
+        """Test that objects without the buffer protocol raise TypeError."""
+        for value in ["0" * 26, None, [0] * 26]:
+            with pytest.raises(TypeError):
+                pyulid.ulid_into(value)
+
+
+class TestBatch:
+    """Test ulid_batch."""
+
+    def test_ordered_and_unique(self):
+        """Test that a batch is strictly increasing and continues the sequence."""
+        before = pyulid.ulid()
+        batch = pyulid.ulid_batch(10000)
+        after = pyulid.ulid()
+        assert len(batch) == 10000
+        assert all(a < b for a, b in zip([before] + batch, batch + [after]))
+        assert all(pyulid.ulid_is_valid(u) for u in batch[:100])
+
+    def test_empty(self):
+        """Test that n == 0 gives an empty list."""
+        assert pyulid.ulid_batch(0) == []
+
+    def test_concurrent_with_ulid(self):
+        """Test that batches and ulid() calls from other threads never collide."""
+        import threading
+
+        single = []
+        stop = threading.Event()
+
+        def generate():
+            while not stop.is_set():
+                single.append(pyulid.ulid())
+
+        thread = threading.Thread(target=generate)
+        thread.start()
+        try:
+            batches = [pyulid.ulid_batch(50000) for _ in range(4)]
+        finally:
+            stop.set()
+            thread.join()
+
+        everything = single + [u for batch in batches for u in batch]
+        assert len(set(everything)) == len(everything)
+        assert single == sorted(single)
+        for batch in batches:
+            assert batch == sorted(batch)
+
+    def test_overflow_reports_progress(self):
+        """Test that overflow mid-batch raises like ulid() and says how far it got."""
+        floor = _now_ms() + 300
+        near_max = pyulid.ulid_set_random(pyulid.ulid_with_timestamp(floor), 2**80 - 6)
+        pyulid.set_minimum_timestamp(pyulid.ULID(near_max))
+        try:
+            with pytest.raises(RuntimeError, match=r"overflow.*after 5 of 100"):
+                pyulid.ulid_batch(100)
+            with pytest.raises(RuntimeError, match="overflow"):
+                pyulid.ulid()
+        finally:
+            _wait_past(floor)
+
+
+class TestConcurrentGeneration:
+    """Test the shared state under many generating threads."""
+
+    def test_threads_unique_and_ordered(self):
+        """Test 100k ULIDs from each of several threads: all unique, each in order."""
+        import threading
+
+        per_thread = 100_000
+        results = {}
+        start = threading.Barrier(6)
+
+        def single(name):
+            start.wait()
+            results[name] = [pyulid.ulid() for _ in range(per_thread)]
+
+        def batched(name):
+            start.wait()
+            # Runs without the GIL, so these claim truly concurrently
+            results[name] = [
+                u for _ in range(per_thread // 1000) for u in pyulid.ulid_batch(1000)
+            ]
+
+        threads = [
+            threading.Thread(target=target, args=(f"{target.__name__}-{i}",))
+            for target in (single, batched)
+            for i in range(3)
+        ]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        assert len(results) == 6
+        for name, ulids in results.items():
+            assert len(ulids) == per_thread
+            assert all(a < b for a, b in zip(ulids, ulids[1:])), name
+        everything = [u for ulids in results.values() for u in ulids]
+        assert len(set(everything)) == len(everything)
+
+
+    def test_mixed_generators_stress(self):
+        """Test every shared-state generator at once: no duplicates, no errors."""
+        import threading
+
+        results = []
+        errors = []
+        start = threading.Barrier(8)
+
+        def run(generate):
+            start.wait()
+            try:
+                ulids = []
+                for _ in range(200):
+                    ulids.extend(generate())
+                results.append(ulids)
+            except BaseException as e:  # a panic surfaces as PanicException
+                errors.append(e)
+
+        generators = [
+            lambda: [pyulid.ulid() for _ in range(50)],
+            lambda: [pyulid.ulid_from_bytes(pyulid.ulid_bytes()) for _ in range(50)],
+            lambda: [str(pyulid.Ulid.new()) for _ in range(50)],
+            lambda: pyulid.ulid_batch(500),
+        ]
+        threads = [
+            threading.Thread(target=run, args=(generate,))
+            for generate in generators
+            for _ in range(2)
+        ]
+        for thread in threads:
+            thread.start()
+        for thread in threads:
+            thread.join()
+
+        assert errors == []
+        for ulids in results:
+            assert all(a < b for a, b in zip(ulids, ulids[1:]))
+        everything = [u for ulids in results for u in ulids]
+        assert len(set(everything)) == len(everything)
+
+    @pytest.mark.skipif(
+        not sysconfig.get_config_var("Py_GIL_DISABLED"),
+        reason="needs a free-threaded build",
+    )
+    def test_gil_stays_disabled(self):
+        """Test that importing pyulid doesn't re-enable the GIL."""
+        assert not sys._is_gil_enabled()
+
+
+class TestNonMonotonic:
+    """Test ulid_non_monotonic."""
+
+    def test_same_millisecond_not_sequential(self):
+        """Test that same-millisecond IDs differ in far more than the low bits."""
+        floor = _now_ms() + 300
+        pyulid.set_minimum_timestamp(floor)
+        try:
+            ulids = [pyulid.ulid_non_monotonic() for _ in range(100)]
+        finally:
+            _wait_past(floor)
+
+        assert {pyulid.ulid_timestamp(u) for u in ulids} == {floor}
+        randoms = [pyulid.ulid_random(u) for u in ulids]
+        assert len(set(randoms)) == len(randoms)
+        assert all(abs(a - b) > 2**40 for a, b in zip(randoms, randoms[1:]))
+        assert randoms != sorted(randoms)
+
+    def test_leaves_monotonic_state_alone(self):
+        """Test that calls in between don't advance the ulid() sequence."""
+        floor = _now_ms() + 300
+        pyulid.set_minimum_timestamp(floor)
+        try:
+            first = pyulid.ulid()
+            for _ in range(10):
+                pyulid.ulid_non_monotonic()
+            second = pyulid.ulid()
+        finally:
+            _wait_past(floor)
+
+        assert pyulid.ulid_random(second) == pyulid.ulid_random(first) + 1
+
+    def test_format(self):
+        """Test that the IDs are valid and carry the current time."""
+        ulid_str = pyulid.ulid_non_monotonic()
+        assert pyulid.ulid_is_valid(ulid_str)
+        assert abs(pyulid.ulid_timestamp(ulid_str) - _now_ms()) < 1000
+
+
+class TestTimestampMonotonic:
+    """Test ulid_with_timestamp_monotonic per-timestamp sequences."""
+
+    def test_same_timestamp_increases(self):
+        """Test that repeated calls with one timestamp strictly increase."""
+        timestamp = 1262304000000
+        ulids = [pyulid.ulid_with_timestamp_monotonic(timestamp) for _ in range(500)]
+
+        assert {pyulid.ulid_timestamp(u) for u in ulids} == {timestamp}
+        assert ulids == sorted(ulids)
+        assert len(set(ulids)) == len(ulids)
+
+    def test_interleaved_timestamps(self):
+        """Test that each timestamp's sequence survives visits to others."""
+        by_timestamp = {1262304000001: [], 1262304000002: [], 946684800000: []}
+        for _ in range(50):
+            for timestamp, seen in by_timestamp.items():
+                seen.append(pyulid.ulid_with_timestamp_monotonic(timestamp))
+
+        for timestamp, seen in by_timestamp.items():
+            assert seen == sorted(seen)
+            assert {pyulid.ulid_timestamp(u) for u in seen} == {timestamp}
+
+    def test_older_timestamp_allowed(self):
+        """Test that going back in time is fine for historical data."""
+        later = pyulid.ulid_with_timestamp_monotonic(1262304000100)
+        earlier = pyulid.ulid_with_timestamp_monotonic(1262304000050)
+        assert earlier < later
+
+    def test_independent_of_global_sequence(self):
+        """Test that ulid() keeps its own sequence around these calls."""
+        floor = _now_ms() + 300
+        pyulid.set_minimum_timestamp(floor)
+        try:
+            first = pyulid.ulid()
+            pyulid.ulid_with_timestamp_monotonic(floor)
+            second = pyulid.ulid()
+        finally:
+            _wait_past(floor)
+
+        assert pyulid.ulid_random(second) == pyulid.ulid_random(first) + 1
+
+    def test_overflow_raises(self):
+        """Test that an exhausted timestamp raises instead of wrapping."""
+        timestamp = 1262304000200
+        last = pyulid.ulid_from_int((timestamp << 80) | (2**80 - 2))
+        pyulid._resume_timestamp_sequence(last)
+
+        final = pyulid.ulid_with_timestamp_monotonic(timestamp)
+        assert pyulid.ulid_random(final) == 2**80 - 1
+        with pytest.raises(RuntimeError, match="overflow"):
+            pyulid.ulid_with_timestamp_monotonic(timestamp)
+
+    @pytest.mark.skipif(
+        "fork" not in multiprocessing.get_all_start_methods(),
+        reason="fork start method not available",
+    )
+    def test_forgotten_timestamp_never_goes_back(self):
+        """Test that a timestamp reused after eviction continues past its IDs."""
+
+        def evict_and_reuse():
+            # Runs in a forked child, which starts with no sequences remembered
+            timestamp = 1262304000400
+            before = [pyulid.ulid_with_timestamp_monotonic(timestamp) for _ in range(5)]
+            for other in range(1, 65_537):
+                pyulid.ulid_with_timestamp_monotonic(timestamp + other)
+            after = [pyulid.ulid_with_timestamp_monotonic(timestamp) for _ in range(5)]
+            return before, after
+
+        [(before, after)] = _fork_children(evict_and_reuse, count=1)
+        assert before + after == sorted(before + after)
+        assert len(set(before + after)) == 10
+
+    @pytest.mark.skipif(
+        "fork" not in multiprocessing.get_all_start_methods(),
+        reason="fork start method not available",
+    )
+    def test_evictions_leave_new_timestamps_alone(self):
+        """Test that forgotten timestamps don't raise the floor of unrelated ones."""
+
+        def evict_then_draw():
+            # Runs in a forked child, which starts with no sequences remembered
+            timestamp = 1262304000500
+            for other in range(2 * 65_536):
+                pyulid.ulid_with_timestamp_monotonic(timestamp + other)
+            fresh = range(timestamp + 10**6, timestamp + 10**6 + 64)
+            return [pyulid.ulid_with_timestamp_monotonic(t) for t in fresh]
+
+        [fresh] = _fork_children(evict_then_draw, count=1)
+        # A single floor over 65,536 forgotten sequences would sit near 2**80
+        assert min(pyulid.ulid_random(u) for u in fresh) < 2**79
+
+
+class TestSibling:
+    """Test ulid_sibling fresh IDs sharing a timestamp."""
+
+    def test_single_sibling(self):
+        """Test that a sibling shares the timestamp but not the value."""
+        original = pyulid.ulid()
+        sibling = pyulid.ulid_sibling(original)
+
+        assert pyulid.ulid_is_valid(sibling)
+        assert sibling != original
+        assert pyulid.ulid_timestamp(sibling) == pyulid.ulid_timestamp(original)
+
+    def test_count(self):
+        """Test that count=n returns n sorted, distinct siblings."""
+        original = pyulid.ulid()
+        siblings = pyulid.ulid_sibling(original, count=100)
+
+        assert len(siblings) == 100
+        assert siblings == sorted(siblings)
+        assert len(set(siblings)) == 100
+        assert original not in siblings
+        timestamp = pyulid.ulid_timestamp(original)
+        assert all(pyulid.ulid_timestamp(s) == timestamp for s in siblings)
+
+        assert pyulid.ulid_sibling(original, count=0) == []
+        assert len(pyulid.ulid_sibling(original, count=1)) == 1
+
+    def test_accepts_ulid_object(self):
+        """Test ULID objects and lowercase input."""
+        original = pyulid.ulid()
+        sibling = pyulid.ulid_sibling(pyulid.ULID(original))
+        assert pyulid.ulid_timestamp(sibling) == pyulid.ulid_timestamp(original)
+        assert pyulid.ulid_sibling(original.lower()) != original
+
+    def test_invalid(self):
+        """Test that invalid input raises ValueError."""
+        with pytest.raises(ValueError):
+            pyulid.ulid_sibling("invalid")
+
+    def test_global_state_untouched(self):
+        """Test that siblings of a far-future ULID don't move the generator clock."""
+        future = pyulid.ulid_with_timestamp(2524608000000)
+        before = pyulid.ulid()
+        pyulid.ulid_sibling(future, count=10)
+        after = pyulid.ulid()
+
+        assert before < after
+        assert pyulid.ulid_timestamp(after) < 2524608000000
+
+
+def _now_ms():
+    return time.time_ns() // 1_000_000
+
+
+def _wait_past(timestamp):
+    """Sleep until the wall clock is past `timestamp`, so no floor outlives the test."""
+    while _now_ms() <= timestamp:
+        time.sleep(0.01)
+
+
+class TestMinimumTimestamp:
+    """Test set_minimum_timestamp with a wall clock behind the floor."""
+
+    def test_pinned_at_floor(self):
+        """Test that ULIDs share the floor timestamp and increment monotonically."""
+        floor = _now_ms() + 300
+        pyulid.set_minimum_timestamp(floor)
+        assert pyulid.stats()["minimum_timestamp"] == floor
+
+        ulids = [pyulid.ulid() for _ in range(1000)]
+        assert {pyulid.ulid_timestamp(u) for u in ulids} == {floor}
+        assert ulids == sorted(ulids)
+        assert len(set(ulids)) == len(ulids)
+        randoms = [pyulid.ulid_random(u) for u in ulids]
+        assert randoms == sorted(randoms)
+
+        _wait_past(floor)
+        assert pyulid.stats()["minimum_timestamp"] is None
+        after = pyulid.ulid()
+        assert after > ulids[-1]
+        assert pyulid.ulid_timestamp(after) > floor
+
+    def test_sorts_after_restored_ulid(self):
+        """Test that new ULIDs sort after a restored ULID in the same millisecond."""
+        restored = pyulid.ulid_set_random(
+            pyulid.ulid_with_timestamp(_now_ms() + 300), 2**80 - 100
+        )
+        floor = pyulid.ulid_timestamp(restored)
+        pyulid.set_minimum_timestamp(pyulid.ULID(restored))
+
+        ulids = [pyulid.ulid() for _ in range(50)]
+        assert all(u > restored for u in ulids)
+        assert pyulid.ulid_timestamp(ulids[0]) == floor
+        _wait_past(floor)
+
+    def test_not_lowered_below_issued(self):
+        """Test that lowering the floor never lets time go back behind issued ULIDs."""
+        floor = _now_ms() + 300
+        pyulid.set_minimum_timestamp(floor)
+        issued = pyulid.ulid()
+
+        pyulid.set_minimum_timestamp(None)
+        assert pyulid.stats()["minimum_timestamp"] == floor
+        assert pyulid.ulid() > issued
+        _wait_past(floor)
+
+    def test_floor_in_past_is_inert(self):
+        """Test that a floor behind the wall clock changes nothing."""
+        pyulid.set_minimum_timestamp(datetime(2020, 1, 1, tzinfo=timezone.utc))
+        assert pyulid.stats()["minimum_timestamp"] is None
+        assert abs(pyulid.ulid_timestamp(pyulid.ulid()) - _now_ms()) < 1000
+        pyulid.set_minimum_timestamp(None)
+
+    def test_invalid(self):
+        """Test range and type validation."""
+        for value in [2**48, -1, "invalid", datetime(2020, 1, 1)]:
+            with pytest.raises(ValueError):
+                pyulid.set_minimum_timestamp(value)
+        with pytest.raises(TypeError):
+            pyulid.set_minimum_timestamp(1.5)
+        assert pyulid.stats()["minimum_timestamp"] is None
+
+
+@pytest.fixture
+def frozen_clock():
+    """Fixture freezing the clock just ahead of real time, then letting it catch up."""
+    start = _now_ms() + 200
+    pyulid._freeze_time(start)
+    try:
+        yield start
+    finally:
+        pyulid._unfreeze_time()
+        _wait_past(start + 10)
+
+
+class TestFrozenClock:
+    """Test the monotonic logic against the test-only frozen clock."""
+
+    def test_same_millisecond_increments(self, frozen_clock):
+        """Test that ULIDs at a frozen time share the prefix and count up by one."""
+        ulids = [pyulid.ulid() for _ in range(10)]
+        prefix = pyulid.encode_timestamp_base32(frozen_clock)
+        assert all(u[:10] == prefix for u in ulids)
+        randoms = [pyulid.ulid_random(u) for u in ulids]
+        assert randoms == list(range(randoms[0], randoms[0] + 10))
+
+    def test_step_forward(self, frozen_clock):
+        """Test that moving the frozen clock on starts a new millisecond."""
+        first = pyulid.ulid()
+        pyulid._freeze_time(frozen_clock + 5)
+        second = pyulid.ulid()
+        assert pyulid.ulid_timestamp(second) == frozen_clock + 5
+        assert second > first
+
+    def test_step_backwards_raises(self, frozen_clock):
+        """Test the clock-backwards error, and recovery once time is restored."""
+        first = pyulid.ulid()
+        pyulid._freeze_time(frozen_clock - 1)
+        with pytest.raises(RuntimeError, match="backwards"):
+            pyulid.ulid()
+        pyulid._freeze_time(frozen_clock)
+        assert pyulid.ulid() > first
+
+    def test_unfreeze_keeps_order(self, frozen_clock):
+        """Test that unfreezing ahead of the real clock doesn't break ordering."""
+        frozen = pyulid.ulid()
+        pyulid._unfreeze_time()
+        assert pyulid.ulid() > frozen
+        assert pyulid.stats()["minimum_timestamp"] == frozen_clock
+
+
+def _fork_children(target, count=4):
+    """Run `target()` in `count` forked children and collect what each returns."""
+    context = multiprocessing.get_context("fork")
+    results = context.Queue()
+    children = [
+        context.Process(target=lambda: results.put(target())) for _ in range(count)
+    ]
+    for child in children:
+        child.start()
+    collected = [results.get(timeout=30) for _ in children]
+    for child in children:
+        child.join(timeout=30)
+        assert child.exitcode == 0
+    return collected
+
+
+@pytest.mark.skipif(
+    "fork" not in multiprocessing.get_all_start_methods(),
+    reason="fork start method not available",
+)
+class TestForkSafety:
+    """Test that forked children don't repeat their parent's ULIDs."""
+
+    def test_no_duplicates_within_a_millisecond(self, frozen_clock):
+        """Test parent and children sharing one frozen millisecond stay unique."""
+        before = pyulid.ulid()
+        children = _fork_children(lambda: [pyulid.ulid() for _ in range(1000)])
+        parent = [pyulid.ulid() for _ in range(1000)]
+
+        everything = [before] + parent + [u for ulids in children for u in ulids]
+        assert len(set(everything)) == len(everything)
+        assert {pyulid.ulid_timestamp(u) for u in everything} == {frozen_clock}
+
+    def test_children_stay_monotonic(self, frozen_clock):
+        """Test that a child's ULIDs still sort after those issued before the fork."""
+        before = pyulid.ulid()
+        for ulids in _fork_children(lambda: [pyulid.ulid() for _ in range(100)]):
+            assert ulids == sorted(ulids)
+            assert ulids[0] > before
+
+    def test_bytes_and_batch_paths(self, frozen_clock):
+        """Test the non-string generation paths diverge after a fork as well."""
+        pyulid.ulid()
+        children = _fork_children(
+            lambda: [pyulid.ulid_bytes()] + pyulid.ulid_batch(100), count=2
+        )
+        parent = [pyulid.ulid_bytes()] + pyulid.ulid_batch(100)
+
+        assert parent[0] not in {ulids[0] for ulids in children}
+        batches = [u for ulids in children + [parent] for u in ulids[1:]]
+        assert len(set(batches)) == len(batches)
+
+    def test_unseeded_generators(self, frozen_clock):
+        """Test that generators built before the fork diverge in the children."""
+        gen = pyulid.UlidGenerator()
+        keyed = pyulid.KeyedUlidGenerator()
+        before = [gen.generate(), keyed.generate("a")]
+
+        def draw():
+            same_ms = [gen.generate() for _ in range(5)] + [keyed.generate("a")]
+            pyulid._freeze_time(frozen_clock + 1)
+            return same_ms, gen.generate()
+
+        children = _fork_children(draw, count=2)
+        pyulid._freeze_time(frozen_clock)
+        parent = draw()
+
+        runs = [parent] + children
+        everything = before + [
+            u for same_ms, next_ms in runs for u in same_ms + [next_ms]
+        ]
+        assert len(set(everything)) == len(everything)
+        for same_ms, _ in runs:
+            assert same_ms[:5] == sorted(same_ms[:5]) and same_ms[0] > before[0]
+            assert same_ms[5] > before[1]
+        # A fresh RNG, not just fresh low bits: the next millisecond's top bits differ
+        tops = {pyulid.ulid_random(next_ms) >> 40 for _, next_ms in runs}
+        assert len(tops) == len(runs)
+
+    def test_seeded_generators_unchanged(self, frozen_clock):
+        """Test that a seeded generator still reproduces its sequence in a child."""
+        gen = pyulid.UlidGenerator(42, 1672531200000)
+        gen.generate()
+        children = _fork_children(lambda: [gen.generate() for _ in range(5)], count=1)
+        assert children == [[gen.generate() for _ in range(5)]]
+
+    def test_timestamp_sequences(self):
+        """Test ulid_with_timestamp_monotonic sequences aren't continued by children."""
+        timestamp = 1700000000000
+        pyulid.ulid_with_timestamp_monotonic(timestamp)
+        children = _fork_children(
+            lambda: [pyulid.ulid_with_timestamp_monotonic(timestamp) for _ in range(10)]
+        )
+        parent = [pyulid.ulid_with_timestamp_monotonic(timestamp) for _ in range(10)]
+
+        everything = parent + [u for ulids in children for u in ulids]
+        assert len(set(everything)) == len(everything)
+
+    def test_fork_during_generation_in_other_thread(self):
+        """Test forking while another thread generates without the GIL."""
+        import threading
+
+        stop = threading.Event()
+
+        def generate():
+            while not stop.is_set():
+                pyulid.ulid_batch(10000)
+
+        thread = threading.Thread(target=generate)
+        thread.start()
+        try:
+            for _ in range(5):
+                ulids = _fork_children(lambda: pyulid.ulid_batch(10), count=1)
+                assert len(ulids[0]) == 10
+        finally:
+            stop.set()
+            thread.join()
+
+
 class TestClassBasedMonotonic:
     """Test monotonic behavior with ULID class."""
 
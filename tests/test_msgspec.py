@@ -0,0 +1,119 @@
+"""
+msgspec integration tests for PyULID.
+
+Tests the enc/dec hooks directly and, when msgspec is installed, JSON and
+msgpack round trips through msgspec.Struct models.
+"""
+
+from typing import Optional
+
+import pytest
+import pyulid
+
+
+@pytest.fixture
+def msgspec():
+    """Fixture providing msgspec, skipping when it is not installed."""
+    return pytest.importorskip("msgspec")
+
+
+class TestHooks:
+    """Test the hooks without msgspec itself."""
+
+    def test_json_enc_hook(self):
+        """Test that ULIDs encode to their canonical string."""
+        obj = pyulid.ULID()
+        assert pyulid.msgspec_enc_hook(obj) == str(obj)
+
+    def test_msgpack_enc_hook(self):
+        """Test that ULIDs encode to 16 big-endian bytes."""
+        obj = pyulid.ULID()
+        raw = pyulid.msgspec_msgpack_enc_hook(obj)
+        assert raw == pyulid.ulid_to_int(str(obj)).to_bytes(16, "big")
+
+    def test_enc_hooks_reject_other_types(self):
+        """Test that unsupported types raise NotImplementedError."""
+        for hook in [pyulid.msgspec_enc_hook, pyulid.msgspec_msgpack_enc_hook]:
+            with pytest.raises(NotImplementedError):
+                hook(object())
+            with pytest.raises(NotImplementedError):
+                hook(str(pyulid.ULID()))
+
+    def test_dec_hook(self):
+        """Test decoding from str, bytes and ULID objects."""
+        obj = pyulid.ULID()
+        raw = pyulid.msgspec_msgpack_enc_hook(obj)
+
+        assert pyulid.msgspec_dec_hook(pyulid.ULID, str(obj)) == obj
+        assert pyulid.msgspec_dec_hook(pyulid.ULID, str(obj).lower()) == obj
+        assert pyulid.msgspec_dec_hook(pyulid.ULID, raw) == obj
+        assert pyulid.msgspec_dec_hook(pyulid.ULID, obj) is obj
+        assert isinstance(pyulid.msgspec_dec_hook(pyulid.ULID, raw), pyulid.ULID)
+
+    def test_dec_hook_invalid(self):
+        """Test that invalid values raise ValueError or TypeError."""
+        with pytest.raises(ValueError):
+            pyulid.msgspec_dec_hook(pyulid.ULID, "invalid")
+        with pytest.raises(ValueError):
+            pyulid.msgspec_dec_hook(pyulid.ULID, b"\x00" * 15)
+        with pytest.raises(TypeError):
+            pyulid.msgspec_dec_hook(pyulid.ULID, 42)
+        with pytest.raises(NotImplementedError):
+            pyulid.msgspec_dec_hook(complex, "1+2j")
+
+
+class TestMsgspecRoundTrip:
+    """Test round trips through msgspec encoders and decoders."""
+
+    def _model(self, msgspec):
+        class Event(msgspec.Struct):
+            id: pyulid.ULID
+            parent: Optional[pyulid.ULID] = None
+
+        return Event
+
+    def test_json_round_trip(self, msgspec):
+        """Test ULID fields encode as JSON strings and decode back."""
+        Event = self._model(msgspec)
+        event = Event(id=pyulid.ULID(), parent=pyulid.ULID())
+
+        data = msgspec.json.Encoder(enc_hook=pyulid.msgspec_enc_hook).encode(event)
+        assert f'"{event.id}"'.encode() in data
+
+        decoder = msgspec.json.Decoder(Event, dec_hook=pyulid.msgspec_dec_hook)
+        decoded = decoder.decode(data)
+        assert decoded.id == event.id and decoded.parent == event.parent
+        assert isinstance(decoded.id, pyulid.ULID)
+
+    def test_msgpack_round_trip(self, msgspec):
+        """Test ULID fields encode as 16-byte bin in msgpack and decode back."""
+        Event = self._model(msgspec)
+        event = Event(id=pyulid.ULID())
+
+        encoder = msgspec.msgpack.Encoder(enc_hook=pyulid.msgspec_msgpack_enc_hook)
+        data = encoder.encode(event)
+        assert msgspec.msgpack.decode(data)["id"] == pyulid.msgspec_msgpack_enc_hook(
+            event.id
+        )
+
+        decoder = msgspec.msgpack.Decoder(Event, dec_hook=pyulid.msgspec_dec_hook)
+        assert decoder.decode(data).id == event.id
+
+    def test_validation_errors(self, msgspec):
+        """Test invalid IDs surface as msgspec.ValidationError."""
+        Event = self._model(msgspec)
+        json_decoder = msgspec.json.Decoder(Event, dec_hook=pyulid.msgspec_dec_hook)
+        msgpack_decoder = msgspec.msgpack.Decoder(
+            Event, dec_hook=pyulid.msgspec_dec_hook
+        )
+
+        for payload in [b'{"id": "invalid"}', b'{"id": 42}', b'{"id": ""}']:
+            with pytest.raises(msgspec.ValidationError):
+                json_decoder.decode(payload)
+
+        with pytest.raises(msgspec.ValidationError):
+            msgpack_decoder.decode(msgspec.msgpack.encode({"id": b"\x00" * 15}))
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
@@ -0,0 +1,226 @@
+"""
+NumPy interop tests for PyULID.
+"""
+
+import struct
+import sys
+
+import pytest
+import pyulid
+from pyulid import pyulid as _rs
+
+
+@pytest.fixture
+def np():
+    """Fixture providing NumPy, skipping when it is not installed."""
+    return pytest.importorskip("numpy")
+
+
+def _unpack(raw):
+    return list(struct.unpack(f"{len(raw) // 8}q", bytes(raw)))
+
+
+NAT = -(2**63)
+
+
+class TestRawTimestampBuffer:
+    """Test the Rust buffer backing ulid_timestamps_np (no NumPy needed)."""
+
+    def test_matches_ulid_timestamp(self):
+        """Test that each slot holds the ULID's millisecond timestamp."""
+        ulids = [pyulid.ulid_with_timestamp(1672531200000 + i) for i in range(100)]
+        raw = _rs._ulid_timestamps_ms(ulids)
+
+        assert isinstance(raw, bytearray)
+        assert len(raw) == 8 * len(ulids)
+        assert _unpack(raw) == [pyulid.ulid_timestamp(u) for u in ulids]
+
+    def test_accepts_bytes_and_iterables(self):
+        """Test bytes entries, lowercase input and generator input."""
+        ulid_str = pyulid.ulid_with_timestamp(1234567890)
+        raw = _rs._ulid_timestamps_ms(
+            x for x in [ulid_str.encode(), ulid_str.lower()]
+        )
+        assert _unpack(raw) == [1234567890, 1234567890]
+
+    def test_empty(self):
+        """Test that an empty sequence gives an empty buffer."""
+        assert _rs._ulid_timestamps_ms([]) == bytearray()
+
+    def test_raise_names_index(self):
+        """Test that the default mode reports the first invalid index."""
+        ulids = [pyulid.ulid(), pyulid.ulid(), "not-a-ulid"]
+        with pytest.raises(ValueError, match="index 2"):
+            _rs._ulid_timestamps_ms(ulids)
+
+        with pytest.raises(TypeError, match="index 1"):
+            _rs._ulid_timestamps_ms([pyulid.ulid(), None])
+
+    def test_coerce_to_nat(self):
+        """Test that coerce mode writes NaT for invalid entries."""
+        good = pyulid.ulid_with_timestamp(42)
+        bad_char = "I" + good[1:]  # 'I' is excluded from Crockford Base32
+        raw = _rs._ulid_timestamps_ms(
+            [good, "short", bad_char, None, good], errors="coerce"
+        )
+        assert _unpack(raw) == [42, NAT, NAT, NAT, 42]
+
+    def test_overflow(self):
+        """Test that values above 128 bits are invalid rather than wrapped."""
+        good = pyulid.ulid_with_timestamp(42)
+        with pytest.raises(ValueError, match="index 1"):
+            _rs._ulid_timestamps_ms([good, "8" + good[1:]])
+        raw = _rs._ulid_timestamps_ms([good, "Z" * 26, good], errors="coerce")
+        assert _unpack(raw) == [42, NAT, 42]
+
+    def test_invalid_errors_argument(self):
+        """Test that unknown errors modes are rejected."""
+        with pytest.raises(ValueError):
+            _rs._ulid_timestamps_ms([], errors="ignore")
+
+
+class TestTimestampsNumpy:
+    """Test ulid_timestamps_np."""
+
+    def test_dtype_and_values(self, np):
+        """Test that the result is datetime64[ms] with matching values."""
+        ulids = [pyulid.ulid() for _ in range(1000)]
+        result = pyulid.ulid_timestamps_np(ulids)
+
+        assert result.dtype == np.dtype("datetime64[ms]")
+        assert result.astype(np.int64).tolist() == [
+            pyulid.ulid_timestamp(u) for u in ulids
+        ]
+
+    def test_numpy_array_inputs(self, np):
+        """Test object, str and bytes dtype arrays."""
+        ulids = [pyulid.ulid() for _ in range(10)]
+        expected = pyulid.ulid_timestamps_np(ulids)
+
+        for arr in [
+            np.array(ulids, dtype=object),
+            np.array(ulids),
+            np.array([u.encode() for u in ulids], dtype="S26"),
+        ]:
+            assert (pyulid.ulid_timestamps_np(arr) == expected).all()
+
+    def test_coerce_nat(self, np):
+        """Test that invalid entries become NaT under errors='coerce'."""
+        result = pyulid.ulid_timestamps_np([pyulid.ulid(), "bad"], errors="coerce")
+        assert not np.isnat(result[0])
+        assert np.isnat(result[1])
+
+    def test_raise(self, np):
+        """Test that invalid entries raise by default."""
+        with pytest.raises(ValueError, match="index 0"):
+            pyulid.ulid_timestamps_np(["bad"])
+
+
+HAS_BATCH_RAW = hasattr(_rs, "_ulid_batch_raw")
+
+
+@pytest.mark.skipif(not HAS_BATCH_RAW, reason="built without the numpy feature")
+class TestRawBatchBuffer:
+    """Test the Rust buffer backing ulid_batch_numpy (no NumPy needed)."""
+
+    def test_text_records(self):
+        """Test that text records are 26 bytes of increasing valid ULIDs."""
+        raw = _rs._ulid_batch_raw(1000)
+
+        assert isinstance(raw, bytearray)
+        assert len(raw) == 26 * 1000
+        ulids = [raw[i : i + 26].decode() for i in range(0, len(raw), 26)]
+        assert all(pyulid.ulid_is_valid(u) for u in ulids)
+        assert ulids == sorted(ulids)
+        assert len(set(ulids)) == len(ulids)
+
+    def test_binary_records(self):
+        """Test that binary records are big-endian and increasing."""
+        raw = _rs._ulid_batch_raw(1000, True)
+
+        assert len(raw) == 16 * 1000
+        records = [bytes(raw[i : i + 16]) for i in range(0, len(raw), 16)]
+        assert records == sorted(records)
+        assert len(set(records)) == len(records)
+        ulid_str = pyulid.ulid_from_bytes(records[0])
+        assert pyulid.ulid_to_bytes(ulid_str) == records[0]
+
+    def test_continues_global_sequence(self):
+        """Test that a batch sorts between ulid() calls around it."""
+        before = pyulid.ulid()
+        raw = _rs._ulid_batch_raw(10)
+        after = pyulid.ulid()
+        assert before < raw[:26].decode()
+        assert raw[-26:].decode() < after
+
+    def test_empty(self):
+        """Test that n=0 gives an empty buffer."""
+        assert _rs._ulid_batch_raw(0) == bytearray()
+        assert _rs._ulid_batch_raw(0, True) == bytearray()
+
+
+@pytest.mark.skipif(not HAS_BATCH_RAW, reason="built without the numpy feature")
+class TestBatchNumpy:
+    """Test ulid_batch_numpy."""
+
+    def test_text_dtype(self, np):
+        """Test that the default result is an S26 array of sorted ULIDs."""
+        result = pyulid.ulid_batch_numpy(1000)
+
+        assert result.dtype == np.dtype("S26")
+        assert result.shape == (1000,)
+        ulids = [u.decode() for u in result.tolist()]
+        assert all(pyulid.ulid_is_valid(u) for u in ulids)
+        assert ulids == sorted(ulids)
+
+    def test_binary_dtype(self, np):
+        """Test that binary=True gives an (n, 16) uint8 array."""
+        result = pyulid.ulid_batch_numpy(1000, binary=True)
+
+        assert result.dtype == np.uint8
+        assert result.shape == (1000, 16)
+        first = pyulid.ulid_from_bytes(result[0].tobytes())
+        assert pyulid.ulid_is_valid(first)
+
+    def test_empty(self, np):
+        """Test that n=0 gives empty arrays of the right shape."""
+        assert pyulid.ulid_batch_numpy(0).shape == (0,)
+        assert pyulid.ulid_batch_numpy(0, binary=True).shape == (0, 16)
+
+    def test_missing_numpy(self, monkeypatch):
+        """Test that a missing NumPy raises a helpful ImportError."""
+        monkeypatch.setitem(sys.modules, "numpy", None)
+        with pytest.raises(ImportError, match="ulid_batch_numpy requires NumPy"):
+            pyulid.ulid_batch_numpy(10)
+
+
+@pytest.mark.skipif(HAS_BATCH_RAW, reason="built with the numpy feature")
+class TestBatchNumpyWithoutFeature:
+    """Test ulid_batch_numpy in a build without the numpy feature."""
+
+    def test_missing_feature(self, np):
+        """Test that a build without the feature raises a helpful ImportError."""
+        with pytest.raises(ImportError, match="`numpy` cargo feature"):
+            pyulid.ulid_batch_numpy(10)
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
+
+
+class TestUlidIntoNumpy:
+    """Test ulid_into with NumPy arrays."""
+
+    def test_uint8_array(self, np):
+        """Test writing into a row of a 2-D uint8 array."""
+        rows = np.zeros((4, 26), dtype=np.uint8)
+        assert pyulid.ulid_into(rows[2]) == 26
+        assert pyulid.ulid_is_valid(rows[2].tobytes())
+        assert not rows[[0, 1, 3]].any()
+
+    def test_read_only_array(self, np):
+        """Test that a non-writeable array raises BufferError."""
+        array = np.zeros(26, dtype=np.uint8)
+        array.flags.writeable = False
+        with pytest.raises(BufferError):
+            pyulid.ulid_into(array)
@@ -0,0 +1,70 @@
+"""
+Structural pattern matching tests for PyULID (Python 3.10+).
+
+Tests positional and keyword class patterns on ULID objects.
+"""
+
+import pytest
+import pyulid
+
+
+def _describe(value):
+    match value:
+        case pyulid.ULID(timestamp_ms=0):
+            return "epoch"
+        case pyulid.ULID(ts, r) if ts == 1672531200000:
+            return f"new-year:{r}"
+        case pyulid.ULID():
+            return "other"
+        case _:
+            return "not a ulid"
+
+
+class TestPatternMatching:
+    """Test match/case destructuring of ULID objects."""
+
+    def test_match_args(self):
+        """Test the declared positional attributes."""
+        assert pyulid.ULID.__match_args__ == ("timestamp_ms", "random")
+
+    def test_positional_capture(self):
+        """Test that positional patterns bind timestamp and random."""
+        obj = pyulid.ULID.with_timestamp(1672531200000)
+
+        match obj:
+            case pyulid.ULID(ts, r):
+                assert ts == obj.timestamp_ms == 1672531200000
+                assert r == obj.random
+            case _:
+                pytest.fail("positional pattern did not match")
+
+    def test_keyword_capture(self):
+        """Test that keyword patterns bind the same attributes."""
+        obj = pyulid.ULID()
+
+        match obj:
+            case pyulid.ULID(timestamp_ms=ts, random=r):
+                assert ts == obj.timestamp
+                assert r == obj.random
+            case _:
+                pytest.fail("keyword pattern did not match")
+
+    def test_guard_and_literal(self):
+        """Test guards and literal sub-patterns."""
+        new_year = pyulid.ULID.with_timestamp(1672531200000)
+
+        assert _describe(pyulid.ULID.with_timestamp(0)) == "epoch"
+        assert _describe(new_year) == f"new-year:{new_year.random}"
+        assert _describe(pyulid.ULID.with_timestamp(5)) == "other"
+        assert _describe(str(new_year)) == "not a ulid"
+
+    def test_too_many_positional_patterns(self):
+        """Test that a third positional sub-pattern is rejected."""
+        with pytest.raises(TypeError):
+            match pyulid.ULID():
+                case pyulid.ULID(a, b, c):
+                    pass
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
@@ -47,6 +47,49 @@ class TestPerformanceBenchmarks:
         print(f"  Rate: {result['ops_per_second']:,.0f} ULIDs/second")
         print(f"  Std Dev: {result['std_dev']:.4f}s")
 
+    def test_os_rng_mode_cost(self):
+        """Benchmark ulid_with_timestamp in the os RNG mode against fast."""
+        fast = self.measure_performance(lambda: pyulid.ulid_with_timestamp(0), 50000)
+        pyulid.set_rng_mode("os")
+        try:
+            os_mode = self.measure_performance(
+                lambda: pyulid.ulid_with_timestamp(0), 50000
+            )
+        finally:
+            pyulid.set_rng_mode("fast")
+
+        # Each call draws a fresh random component, so this is the per-draw cost
+        print("\nRNG Mode Performance (ulid_with_timestamp):")
+        print(f"  fast: {fast['ops_per_second']:,.0f} ULIDs/second")
+        print(f"  os: {os_mode['ops_per_second']:,.0f} ULIDs/second")
+        print(f"  Slowdown: {os_mode['duration'] / fast['duration']:.2f}x")
+
+    @pytest.mark.skipif(
+        not hasattr(pyulid.pyulid, "_ulid_via_string"),
+        reason="needs a build with the test-hooks feature",
+    )
+    def test_string_construction_performance(self):
+        """Benchmark ulid()'s direct str against the old owned-String path."""
+        baseline = pyulid.pyulid._ulid_via_string
+
+        def trial(func) -> float:
+            start = time.perf_counter()
+            for _ in range(100000):
+                func()
+            return time.perf_counter() - start
+
+        # Same claim on the shared state, so the gap is the intermediate String; the
+        # trials alternate so a busy machine slows both alike
+        trials = [(trial(baseline), trial(pyulid.ulid)) for _ in range(7)]
+        old = min(t for t, _ in trials)
+        new = min(t for _, t in trials)
+
+        print("\nULID String Construction Performance:")
+        print(f"  Owned String: {100000 / old:,.0f} ULIDs/second")
+        print(f"  Direct str: {100000 / new:,.0f} ULIDs/second")
+        print(f"  Speedup: {old / new:.2f}x")
+        assert new < old
+
     def test_ulid_validation_performance(self):
         """Benchmark ULID validation speed."""
         test_ulid = pyulid.ulid()
@@ -60,6 +103,44 @@ class TestPerformanceBenchmarks:
         print(f"  Duration: {result['duration']:.4f}s")
         print(f"  Rate: {result['ops_per_second']:,.0f} validations/second")
 
+    def test_bulk_validation_performance(self):
+        """Benchmark ulid_is_valid_many against a Python loop over ulid_is_valid."""
+        ulids = [pyulid.ulid() for _ in range(100000)]
+
+        start_time = time.perf_counter()
+        looped = [pyulid.ulid_is_valid(u) for u in ulids]
+        loop_duration = time.perf_counter() - start_time
+
+        start_time = time.perf_counter()
+        bulk = pyulid.ulid_is_valid_many(ulids)
+        bulk_duration = time.perf_counter() - start_time
+
+        assert bulk == looped
+        print("\nBulk Validation Performance:")
+        print(f"  Python loop: {len(ulids) / loop_duration:,.0f} validations/second")
+        rate = len(ulids) / bulk_duration
+        print(f"  ulid_is_valid_many: {rate:,.0f} validations/second")
+        print(f"  Speedup: {loop_duration / bulk_duration:.2f}x")
+
+    def test_bulk_timestamp_performance(self):
+        """Benchmark ulid_timestamp_many against a Python loop over ulid_timestamp."""
+        ulids = [pyulid.ulid() for _ in range(100000)]
+
+        start_time = time.perf_counter()
+        looped = [pyulid.ulid_timestamp(u) for u in ulids]
+        loop_duration = time.perf_counter() - start_time
+
+        start_time = time.perf_counter()
+        bulk = pyulid.ulid_timestamp_many(ulids)
+        bulk_duration = time.perf_counter() - start_time
+
+        assert bulk == looped
+        print("\nBulk Timestamp Extraction Performance:")
+        print(f"  Python loop: {len(ulids) / loop_duration:,.0f} extractions/second")
+        rate = len(ulids) / bulk_duration
+        print(f"  ulid_timestamp_many: {rate:,.0f} extractions/second")
+        print(f"  Speedup: {loop_duration / bulk_duration:.2f}x")
+
     def test_base32_encoding_performance(self):
         """Benchmark Base32 encoding speed."""
         test_value = 12345678901234567890
@@ -120,6 +201,57 @@ class TestPerformanceBenchmarks:
         print("UUID to ULID Conversion Performance:")
         print(f"  Rate: {result_to_ulid['ops_per_second']:,.0f} conversions/second")
 
+    def test_unvalidated_decode_performance(self):
+        """Benchmark validate=False against the default validating path."""
+        # Distinct ULIDs, or the validated path would be served from the decode cache
+        ulids = [pyulid.ulid() for _ in range(100000)]
+
+        def trial(validate: bool) -> float:
+            start = time.perf_counter()
+            for ulid_str in ulids:
+                pyulid.ulid_timestamp(ulid_str, validate=validate)
+            return time.perf_counter() - start
+
+        # Both sides pass the keyword so only the validation cost differs, and the
+        # trials alternate so a busy machine slows both alike
+        trials = [(trial(True), trial(False)) for _ in range(7)]
+        checked = min(t for t, _ in trials)
+        unchecked = min(u for _, u in trials)
+
+        print("\nTimestamp Extraction validate=False Performance:")
+        print(f"  Validated: {len(ulids) / checked:,.0f} extractions/second")
+        print(f"  Unvalidated: {len(ulids) / unchecked:,.0f} extractions/second")
+        print(f"  Speedup: {checked / unchecked:.2f}x")
+        assert unchecked < checked
+
+    def test_abbreviate_performance(self):
+        """Benchmark ulid_abbreviate on a 100k-row table."""
+        ulids = [pyulid.ulid() for _ in range(100000)]
+
+        start_time = time.perf_counter()
+        prefixes = pyulid.ulid_abbreviate(ulids)
+        duration = time.perf_counter() - start_time
+
+        assert len(prefixes) == len(ulids)
+        print("\nAbbreviation Performance:")
+        print(f"  Rows: {len(ulids):,}")
+        print(f"  Duration: {duration * 1000:.1f}ms")
+        print(f"  Longest prefix: {max(map(len, prefixes))}")
+
+    def test_closest_performance(self):
+        """Benchmark ulid_closest over a 1M-element candidate list."""
+        array = pyulid.ulid_batch_array(1000000)
+        typo = "Z" + array[500000][1:]
+
+        start_time = time.perf_counter()
+        result = pyulid.ulid_closest(typo, array)
+        duration = time.perf_counter() - start_time
+
+        assert result is not None
+        print("\nClosest Match Performance:")
+        print(f"  Candidates: {len(array):,}")
+        print(f"  Duration: {duration * 1000:.1f}ms")
+
 
 class TestMemoryEfficiency:
     """Test memory usage and efficiency."""
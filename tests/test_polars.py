@@ -0,0 +1,138 @@
+"""
+Polars interop tests for PyULID.
+"""
+
+import time
+import uuid
+from datetime import datetime, timezone
+
+import pytest
+import pyulid
+from pyulid import pyulid as _rs
+
+
+@pytest.fixture
+def pl():
+    """Fixture providing Polars with the ulid namespace, skipping when not installed."""
+    polars = pytest.importorskip("polars")
+    import pyulid.polars  # noqa: F401
+
+    return polars
+
+
+def _raw(ulid_str):
+    return pyulid.ulid_to_int(ulid_str).to_bytes(16, "big")
+
+
+class TestColumnKernel:
+    """Test the Rust column kernel behind pyulid.polars (no Polars needed)."""
+
+    def test_timestamp(self):
+        """Test timestamps from text, raw bytes and nulls."""
+        ulids = [pyulid.ulid_with_timestamp(1672531200000 + i) for i in range(3)]
+        values = [ulids[0], _raw(ulids[1]), None, ulids[2].encode()]
+        assert _rs._ulid_column(values, "timestamp") == [
+            1672531200000,
+            1672531200001,
+            None,
+            1672531200002,
+        ]
+
+    def test_is_valid(self):
+        """Test that invalid entries are False and nulls stay None."""
+        u = pyulid.ulid()
+        values = [u, u.lower(), "invalid", None, b"short", "8" + u[1:], _raw(u)]
+        assert _rs._ulid_column(values, "is_valid") == [
+            True,
+            True,
+            False,
+            None,
+            False,
+            False,
+            True,
+        ]
+
+    def test_to_bytes_and_uuid(self):
+        """Test the bytes and UUID forms against the scalar functions."""
+        u = pyulid.ulid()
+        assert _rs._ulid_column([u, None], "to_bytes") == [_raw(u), None]
+        expected = [pyulid.ulid_to_uuid(u)] * 2
+        assert _rs._ulid_column([u, _raw(u)], "to_uuid") == expected
+        assert uuid.UUID(_rs._ulid_column([u], "to_uuid")[0]).bytes == _raw(u)
+
+    def test_invalid(self):
+        """Test errors for invalid entries, wrong types and unknown ops."""
+        with pytest.raises(ValueError, match="index 1"):
+            _rs._ulid_column([pyulid.ulid(), "invalid"], "timestamp")
+        with pytest.raises(TypeError, match="index 0"):
+            _rs._ulid_column([42], "is_valid")
+        with pytest.raises(ValueError):
+            _rs._ulid_column([], "frobnicate")
+
+
+class TestPolarsNamespace:
+    """Test the pl.Expr.ulid namespace."""
+
+    def test_utf8_column(self, pl):
+        """Test every expression on a Utf8 column with nulls."""
+        ulids = [pyulid.ulid_with_timestamp(1672531200000 + i) for i in range(3)]
+        df = pl.DataFrame({"id": [ulids[0], None, ulids[1], ulids[2]]})
+
+        out = df.select(
+            ts=pl.col("id").ulid.timestamp(),
+            valid=pl.col("id").ulid.is_valid(),
+            raw=pl.col("id").ulid.to_bytes(),
+            uuid=pl.col("id").ulid.to_uuid(),
+        )
+        assert out.schema["ts"] == pl.Datetime("ms", "UTC")
+        assert out["ts"].to_list()[0] == datetime(2023, 1, 1, tzinfo=timezone.utc)
+        assert out["ts"].null_count() == 1
+        assert out["valid"].to_list() == [True, None, True, True]
+        assert out["raw"].to_list() == [
+            _raw(ulids[0]),
+            None,
+            _raw(ulids[1]),
+            _raw(ulids[2]),
+        ]
+        assert out["uuid"].to_list()[2] == pyulid.ulid_to_uuid(ulids[1])
+
+    def test_binary_column(self, pl):
+        """Test that 16-byte Binary columns decode natively."""
+        ulids = [pyulid.ulid() for _ in range(10)]
+        df = pl.DataFrame({"id": [_raw(u) for u in ulids]}, schema={"id": pl.Binary})
+        ts = df.select(pl.col("id").ulid.timestamp().dt.epoch("ms"))["id"].to_list()
+        assert ts == [pyulid.ulid_timestamp(u) for u in ulids]
+
+    def test_invalid_raises(self, pl):
+        """Test that invalid entries raise for the decoding expressions."""
+        df = pl.DataFrame({"id": [pyulid.ulid(), "invalid"]})
+        assert df.select(pl.col("id").ulid.is_valid())["id"].to_list() == [True, False]
+        with pytest.raises(Exception, match="index 1"):
+            df.select(pl.col("id").ulid.timestamp())
+
+    def test_generate(self, pl):
+        """Test the Series constructor."""
+        series = pyulid.polars.generate(1000, "id")
+        assert series.name == "id"
+        assert series.dtype == pl.Utf8
+        assert series.to_list() == sorted(series.to_list())
+        assert series.n_unique() == 1000
+
+    def test_million_rows(self, pl):
+        """Test a million-row DataFrame end to end."""
+        df = pl.DataFrame({"id": pyulid.polars.generate(1_000_000)})
+
+        start = time.perf_counter()
+        out = df.select(
+            ts=pl.col("id").ulid.timestamp(),
+            valid=pl.col("id").ulid.is_valid(),
+        )
+        print(f"\n1M rows timestamp + is_valid: {time.perf_counter() - start:.3f}s")
+
+        assert out.height == 1_000_000
+        assert out["valid"].all()
+        assert out["ts"].is_sorted()
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
@@ -0,0 +1,478 @@
+"""
+Range and ordering helper tests for PyULID.
+
+Tests successor/predecessor helpers and range boundary construction.
+"""
+
+import pytest
+import pyulid
+import random
+
+
+MIN_ULID = "0" * 26
+MAX_ULID = "7" + "Z" * 25
+
+
+class TestNextPrev:
+    """Test ulid_next and ulid_prev."""
+
+    def test_next_increments_value(self):
+        """Test that ulid_next adds one to the 128-bit value."""
+        ulid_str = pyulid.ulid()
+        next_ulid = pyulid.ulid_next(ulid_str)
+
+        assert pyulid.decode_base32(next_ulid) == pyulid.decode_base32(ulid_str) + 1
+        assert next_ulid > ulid_str
+
+    def test_prev_decrements_value(self):
+        """Test that ulid_prev subtracts one from the 128-bit value."""
+        ulid_str = pyulid.ulid()
+        prev_ulid = pyulid.ulid_prev(ulid_str)
+
+        assert pyulid.decode_base32(prev_ulid) == pyulid.decode_base32(ulid_str) - 1
+        assert prev_ulid < ulid_str
+
+    def test_roundtrip(self):
+        """Test that next and prev are inverses."""
+        ulid_str = pyulid.ulid()
+        assert pyulid.ulid_prev(pyulid.ulid_next(ulid_str)) == ulid_str
+        assert pyulid.ulid_next(pyulid.ulid_prev(ulid_str)) == ulid_str
+
+    def test_carry_into_timestamp(self):
+        """Test that a full random part rolls over into the timestamp."""
+        ulid_str = "01ARZ3NDEK" + "Z" * 16
+        next_ulid = pyulid.ulid_next(ulid_str)
+
+        assert next_ulid == "01ARZ3NDEM" + "0" * 16
+        assert pyulid.ulid_timestamp(next_ulid) == pyulid.ulid_timestamp(ulid_str) + 1
+        assert pyulid.ulid_random(next_ulid) == 0
+
+    def test_borrow_from_timestamp(self):
+        """Test that a zero random part borrows from the timestamp."""
+        ulid_str = "01ARZ3NDEM" + "0" * 16
+        prev_ulid = pyulid.ulid_prev(ulid_str)
+
+        assert prev_ulid == "01ARZ3NDEK" + "Z" * 16
+        assert pyulid.ulid_random(prev_ulid) == 2**80 - 1
+
+    def test_increment_decrement_aliases(self):
+        """Test the increment/decrement names, including at the extremes."""
+        assert pyulid.ulid_increment is pyulid.ulid_next
+        assert pyulid.ulid_decrement is pyulid.ulid_prev
+        assert pyulid.ulid_increment(MAX_ULID[:-1] + "Y") == MAX_ULID
+        assert pyulid.ulid_decrement("0" * 25 + "1") == MIN_ULID
+        with pytest.raises(OverflowError):
+            pyulid.ulid_increment(MAX_ULID)
+        with pytest.raises(ValueError):
+            pyulid.ulid_decrement(MIN_ULID)
+
+    def test_lowercase_input_returns_canonical(self):
+        """Test that results are canonical uppercase strings."""
+        ulid_str = pyulid.ulid()
+        assert pyulid.ulid_next(ulid_str.lower()) == pyulid.ulid_next(ulid_str)
+        assert pyulid.ulid_next(ulid_str.lower()).isupper()
+
+    def test_next_of_max_raises(self):
+        """Test that ulid_next raises OverflowError at the maximum ULID."""
+        with pytest.raises(OverflowError):
+            pyulid.ulid_next(MAX_ULID)
+
+    def test_prev_of_min_raises(self):
+        """Test that ulid_prev raises ValueError at the minimum ULID."""
+        with pytest.raises(ValueError):
+            pyulid.ulid_prev(MIN_ULID)
+
+    def test_invalid_input(self):
+        """Test that invalid ULIDs are rejected."""
+        invalid = ["", "01ARZ3NDEKTSV4RRFFQ69G5FA", "01ARZ3NDEKTSV4RRFFQ69G5FAI"]
+        for invalid_ulid in invalid:
+            with pytest.raises(ValueError):
+                pyulid.ulid_next(invalid_ulid)
+            with pytest.raises(ValueError):
+                pyulid.ulid_prev(invalid_ulid)
+
+    def test_string_ordering_matches(self):
+        """Test that string ordering of successors matches value ordering."""
+        values = [random.getrandbits(128) for _ in range(5000)]
+        values += [(v >> 80) << 80 | (2**80 - 1) for v in values[:500]]  # Force carries
+
+        for value in values:
+            if value == 2**128 - 1 or value == 0:
+                continue
+            ulid_str = pyulid.ulid_from_int(value)
+            next_ulid = pyulid.ulid_next(ulid_str)
+            prev_ulid = pyulid.ulid_prev(ulid_str)
+
+            assert prev_ulid < ulid_str < next_ulid
+            assert pyulid.ulid_to_int(next_ulid) == value + 1
+            assert pyulid.ulid_to_int(prev_ulid) == value - 1
+
+
+class TestFloorCeil:
+    """Test ulid_floor and ulid_ceil."""
+
+    def test_floor_and_ceil_values(self):
+        """Test that floor/ceil keep the timestamp and fix the random part."""
+        ulid_str = pyulid.ulid()
+        floor = pyulid.ulid_floor(ulid_str)
+        ceil = pyulid.ulid_ceil(ulid_str)
+
+        assert floor == ulid_str[:10] + "0" * 16
+        assert ceil == ulid_str[:10] + "Z" * 16
+        assert pyulid.ulid_timestamp(floor) == pyulid.ulid_timestamp(ulid_str)
+        assert pyulid.ulid_timestamp(ceil) == pyulid.ulid_timestamp(ulid_str)
+        assert pyulid.ulid_random(floor) == 0
+        assert pyulid.ulid_random(ceil) == 2**80 - 1
+
+    def test_bounds_property(self):
+        """Test floor(x) <= x <= ceil(x) for many values."""
+        samples = [pyulid.ulid_from_int(random.getrandbits(128)) for _ in range(2000)]
+        samples += [pyulid.ulid() for _ in range(500)]
+        samples += [MIN_ULID, MAX_ULID]
+
+        for ulid_str in samples:
+            assert pyulid.ulid_floor(ulid_str) <= ulid_str <= pyulid.ulid_ceil(ulid_str)
+
+    def test_idempotent(self):
+        """Test that applying floor/ceil twice changes nothing."""
+        ulid_str = pyulid.ulid()
+        floor = pyulid.ulid_floor(ulid_str)
+        ceil = pyulid.ulid_ceil(ulid_str)
+
+        assert pyulid.ulid_floor(floor) == floor
+        assert pyulid.ulid_ceil(ceil) == ceil
+        assert pyulid.ulid_floor(ceil) == floor
+
+    def test_accepts_ulid_objects(self):
+        """Test that ULID objects are accepted."""
+        ulid_obj = pyulid.ULID()
+        assert pyulid.ulid_floor(ulid_obj) == pyulid.ulid_floor(str(ulid_obj))
+        assert pyulid.ulid_ceil(ulid_obj) == pyulid.ulid_ceil(str(ulid_obj))
+
+    def test_canonical_output(self):
+        """Test that lowercase input yields canonical uppercase output."""
+        ulid_str = pyulid.ulid()
+        assert pyulid.ulid_floor(ulid_str.lower()) == pyulid.ulid_floor(ulid_str)
+
+    def test_invalid_input(self):
+        """Test that invalid ULIDs are rejected."""
+        with pytest.raises(ValueError):
+            pyulid.ulid_floor("INVALID")
+        with pytest.raises(ValueError):
+            pyulid.ulid_ceil("01ARZ3NDEKTSV4RRFFQ69G5FAI")
+
+
+class TestTimestampBounds:
+    """Test ulid_min_for_timestamp and ulid_max_for_timestamp."""
+
+    def test_values(self):
+        """Test that the random part is all zeros / all ones."""
+        ts = 1672531200000
+        low = pyulid.ulid_min_for_timestamp(ts)
+        high = pyulid.ulid_max_for_timestamp(ts)
+        assert low == pyulid.ulid_floor(pyulid.ulid_with_timestamp(ts))
+        assert high == pyulid.ulid_ceil(pyulid.ulid_with_timestamp(ts))
+        assert pyulid.ulid_random(low) == 0
+        assert pyulid.ulid_random(high) == 2**80 - 1
+        assert pyulid.ulid_min_for_timestamp(0) == MIN_ULID
+        assert pyulid.ulid_max_for_timestamp(2**48 - 1) == MAX_ULID
+
+    def test_generated_ulids_within_bounds(self):
+        """Test that IDs sort within their own bounds and outside the neighbours'."""
+        for _ in range(50):
+            ts = random.randrange(1, 2**48 - 1)
+            low = pyulid.ulid_min_for_timestamp(ts)
+            high = pyulid.ulid_max_for_timestamp(ts)
+            assert pyulid.ulid_max_for_timestamp(ts - 1) < low
+            assert high < pyulid.ulid_min_for_timestamp(ts + 1)
+            for _ in range(40):
+                assert low <= pyulid.ulid_with_timestamp(ts) <= high
+
+    def test_datetime(self):
+        """Test that aware datetimes are accepted."""
+        from datetime import datetime, timezone
+
+        dt = datetime(2023, 1, 1, tzinfo=timezone.utc)
+        assert pyulid.ulid_min_for_timestamp(dt) == pyulid.ulid_min_for_timestamp(
+            1672531200000
+        )
+
+    def test_out_of_range(self):
+        """Test that timestamps outside 48 bits are rejected."""
+        for ts in [-1, 2**48]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_min_for_timestamp(ts)
+            with pytest.raises(ValueError):
+                pyulid.ulid_max_for_timestamp(ts)
+
+
+class TestNilAndMax:
+    """Test the MIN_ULID / MAX_ULID constants and ulid_nil / ulid_max."""
+
+    def test_values(self):
+        """Test the exact strings and that the functions match the constants."""
+        assert pyulid.MIN_ULID == pyulid.ulid_nil() == MIN_ULID
+        assert pyulid.MAX_ULID == pyulid.ulid_max() == MAX_ULID
+        assert "MIN_ULID" in pyulid.__all__ and "MAX_ULID" in pyulid.__all__
+
+    def test_components(self):
+        """Test that the components are zero and the 48-/80-bit maxima."""
+        assert pyulid.ulid_timestamp(pyulid.MIN_ULID) == 0
+        assert pyulid.ulid_random(pyulid.MIN_ULID) == 0
+        assert pyulid.ulid_timestamp(pyulid.MAX_ULID) == 2**48 - 1
+        assert pyulid.ulid_random(pyulid.MAX_ULID) == 2**80 - 1
+        assert pyulid.ulid_to_int(pyulid.MAX_ULID) == 2**128 - 1
+
+    def test_validation_boundary(self):
+        """Test that both are valid and nothing above the maximum is."""
+        assert pyulid.ulid_is_valid(pyulid.MIN_ULID)
+        assert pyulid.ulid_is_valid(pyulid.MAX_ULID)
+        assert not pyulid.ulid_is_valid("8" + "0" * 25)
+        with pytest.raises(OverflowError):
+            pyulid.ulid_next(pyulid.MAX_ULID)
+        with pytest.raises(ValueError):
+            pyulid.ulid_prev(pyulid.MIN_ULID)
+
+    def test_generated_ulids_strictly_between(self):
+        """Test that every generated ULID sorts strictly between the two."""
+        generated = [pyulid.ulid() for _ in range(500)]
+        generated += [pyulid.ulid_with_timestamp(ts) for ts in [1, 2**48 - 2]]
+        for ulid_str in generated:
+            assert pyulid.MIN_ULID < ulid_str < pyulid.MAX_ULID
+        assert sorted(generated + [pyulid.MAX_ULID, pyulid.MIN_ULID])[0] == MIN_ULID
+        assert sorted(generated + [pyulid.MAX_ULID])[-1] == MAX_ULID
+
+
+class TestRange:
+    """Test ulid_range window bounds."""
+
+    def test_values(self):
+        """Test the pair is the floor of start and the ceiling of end."""
+        low, high = pyulid.ulid_range(1000, 2000)
+        assert low == pyulid.ulid_min_for_timestamp(1000)
+        assert high == pyulid.ulid_max_for_timestamp(2000)
+        assert pyulid.ulid_range(5, 5) == (
+            pyulid.ulid_min_for_timestamp(5),
+            pyulid.ulid_max_for_timestamp(5),
+        )
+
+    def test_inside_and_outside_window(self):
+        """Test ULIDs inside the window sort within the pair and others don't."""
+        start, end = 1672531200000, 1672531205000
+        low, high = pyulid.ulid_range(start, end)
+        for ts in [start, start + 1, (start + end) // 2, end - 1, end]:
+            for _ in range(20):
+                assert low <= pyulid.ulid_with_timestamp(ts) <= high
+        for ts in [0, start - 1, end + 1, 2**48 - 1]:
+            for _ in range(20):
+                assert not low <= pyulid.ulid_with_timestamp(ts) <= high
+
+    def test_float_seconds(self):
+        """Test float bounds are seconds, floored to the millisecond holding them."""
+        assert pyulid.ulid_range(1.0, 2.5) == pyulid.ulid_range(1000, 2500)
+        assert pyulid.ulid_range(0.0015, 0.0025) == pyulid.ulid_range(1, 2)
+        assert pyulid.ulid_range(1.2349, 1.2351) == pyulid.ulid_range(1234, 1235)
+
+    def test_float_sub_millisecond_inclusivity(self):
+        """Test that a sub-millisecond fraction never drops or adds a millisecond."""
+        start, end = 1700000000.0006, 1700000001.0006
+        low, high = pyulid.ulid_range(start, end)
+        # The millisecond holding each instant is in, the ones just beyond are out
+        for ts in [1700000000000, 1700000000001, 1700000001000]:
+            assert low <= pyulid.ulid_with_timestamp(ts) <= high
+        for ts in [1699999999999, 1700000001001]:
+            assert not low <= pyulid.ulid_with_timestamp(ts) <= high
+
+    def test_datetime(self):
+        """Test aware datetimes, alone or mixed with other bound types."""
+        from datetime import datetime, timezone
+
+        start = datetime(2023, 1, 1, tzinfo=timezone.utc)
+        end = datetime(2023, 1, 2, tzinfo=timezone.utc)
+        expected = pyulid.ulid_range(1672531200000, 1672617600000)
+        assert pyulid.ulid_range(start, end) == expected
+        assert pyulid.ulid_range(start, 1672617600.0) == expected
+
+    def test_open_bounds(self):
+        """Test that None stands for MIN_ULID or MAX_ULID."""
+        assert pyulid.ulid_range(None, None) == (MIN_ULID, MAX_ULID)
+        assert pyulid.ulid_range(None, 1000) == (
+            MIN_ULID,
+            pyulid.ulid_max_for_timestamp(1000),
+        )
+        assert pyulid.ulid_range(1000, None) == (
+            pyulid.ulid_min_for_timestamp(1000),
+            MAX_ULID,
+        )
+
+    def test_end_before_start(self):
+        """Test that a reversed window raises ValueError."""
+        with pytest.raises(ValueError, match="end must not be before start"):
+            pyulid.ulid_range(2000, 1999)
+        with pytest.raises(ValueError, match="end must not be before start"):
+            pyulid.ulid_range(2.0, 1.0)
+
+    def test_out_of_range(self):
+        """Test bounds outside 48 bits raise ValueError, other types TypeError."""
+        for bound in [-1, 2**48, -0.001, 2.0**48, float("nan"), float("inf")]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_range(bound, None)
+            with pytest.raises(ValueError):
+                pyulid.ulid_range(None, bound)
+        with pytest.raises(TypeError):
+            pyulid.ulid_range("1000", None)
+
+
+class TestBisect:
+    """Test ulid_bisect_left and ulid_bisect_right."""
+
+    def _sorted_ulids(self, n):
+        # Few distinct timestamps so duplicates and ties are common
+        return sorted(
+            pyulid.ulid_set_random(
+                pyulid.ulid_with_timestamp(random.randrange(5)), random.randrange(4)
+            )
+            for _ in range(n)
+        )
+
+    def test_matches_stdlib(self):
+        """Test that results match bisect on canonical strings."""
+        import bisect
+
+        for n in [0, 1, 2, 10, 101]:
+            seq = self._sorted_ulids(n)
+            probes = seq + self._sorted_ulids(10) + [MIN_ULID, MAX_ULID]
+            for probe in probes:
+                left = pyulid.ulid_bisect_left(seq, probe)
+                right = pyulid.ulid_bisect_right(seq, probe)
+                assert left == bisect.bisect_left(seq, probe)
+                assert right == bisect.bisect_right(seq, probe)
+
+    def test_lo_hi_match_stdlib(self):
+        """Test that lo/hi bounds behave like bisect."""
+        import bisect
+
+        seq = self._sorted_ulids(50)
+        for _ in range(200):
+            probe = random.choice(seq)
+            lo = random.randrange(60)
+            hi = random.randrange(51)
+            left = pyulid.ulid_bisect_left(seq, probe, lo, hi)
+            right = pyulid.ulid_bisect_right(seq, probe, lo=lo, hi=hi)
+            assert left == bisect.bisect_left(seq, probe, lo, hi)
+            assert right == bisect.bisect_right(seq, probe, lo=lo, hi=hi)
+
+        with pytest.raises(ValueError):
+            pyulid.ulid_bisect_left(seq, seq[0], -1)
+
+    def test_case_insensitive(self):
+        """Test lowercase elements and probes compare by decoded value."""
+        import bisect
+
+        seq = self._sorted_ulids(50)
+        mixed = [u.lower() if i % 2 else u for i, u in enumerate(seq)]
+        for probe in seq:
+            left = pyulid.ulid_bisect_left(mixed, probe.lower())
+            right = pyulid.ulid_bisect_right(mixed, probe)
+            assert left == bisect.bisect_left(seq, probe)
+            assert right == bisect.bisect_right(seq, probe)
+
+    def test_ulid_array_and_objects(self):
+        """Test UlidArray sequences and ULID object probes."""
+        import bisect
+
+        seq = self._sorted_ulids(50)
+        arr = pyulid.UlidArray(seq)
+        for probe in seq[::5]:
+            obj = pyulid.ULID(probe)
+            assert pyulid.ulid_bisect_left(arr, obj) == bisect.bisect_left(seq, probe)
+            right = pyulid.ulid_bisect_right(arr, probe)
+            assert right == bisect.bisect_right(seq, probe)
+
+    def test_invalid_elements(self):
+        """Test that only probed elements are decoded, and bad ones raise."""
+        seq = sorted(pyulid.ulid() for _ in range(100))
+
+        # Index 0 is never probed when searching past the end
+        assert pyulid.ulid_bisect_left(["garbage"] + seq, MAX_ULID) == 101
+
+        bad = seq[:]
+        bad[50] = "not-a-ulid"
+        with pytest.raises(ValueError, match="index 50"):
+            pyulid.ulid_bisect_left(bad, seq[50])
+
+        with pytest.raises(ValueError):
+            pyulid.ulid_bisect_left(seq, "invalid")
+
+
+class TestScanPartitions:
+    """Test ulid_scan_partitions."""
+
+    def test_boundaries(self):
+        """Test boundary count, endpoints and even spacing."""
+        bounds = pyulid.ulid_scan_partitions(1000, 2000, 4)
+
+        assert len(bounds) == 5
+        assert bounds[0] == pyulid.ulid_floor(pyulid.ulid_with_timestamp(1000))
+        assert bounds[-1] == pyulid.ulid_ceil(pyulid.ulid_with_timestamp(2000))
+        timestamps = [pyulid.ulid_timestamp(b) for b in bounds]
+        assert timestamps == [1000, 1250, 1500, 1750, 2000]
+        assert all(pyulid.ulid_random(b) == 0 for b in bounds[:-1])
+        assert bounds == sorted(bounds)
+
+    def test_datetime_arguments(self):
+        """Test that aware datetimes are accepted."""
+        from datetime import datetime, timezone
+
+        start = datetime(2023, 1, 1, tzinfo=timezone.utc)
+        end = datetime(2023, 1, 2, tzinfo=timezone.utc)
+
+        bounds = pyulid.ulid_scan_partitions(start, end, 24)
+        assert bounds == pyulid.ulid_scan_partitions(1672531200000, 1672617600000, 24)
+        assert pyulid.ulid_timestamp(bounds[1]) == 1672531200000 + 3_600_000
+
+    def test_single_part_and_empty_window(self):
+        """Test parts=1 and start == end."""
+        assert pyulid.ulid_scan_partitions(5, 5, 1) == [
+            pyulid.ulid_floor(pyulid.ulid_with_timestamp(5)),
+            pyulid.ulid_ceil(pyulid.ulid_with_timestamp(5)),
+        ]
+        bounds = pyulid.ulid_scan_partitions(5, 6, 3)
+        assert len(bounds) == 4
+        assert bounds == sorted(bounds)
+
+    def test_every_ulid_in_exactly_one_partition(self):
+        """Test that in-window ULIDs fall into exactly one range."""
+        start, end, parts = 1_000_000, 1_000_777, 7
+        bounds = pyulid.ulid_scan_partitions(start, end, parts)
+
+        samples = [
+            pyulid.ulid_with_timestamp(random.randint(start, end)) for _ in range(2000)
+        ]
+        samples += [bounds[0], bounds[-1], pyulid.ulid_with_timestamp(end)]
+        samples += bounds[1:-1]
+
+        for ulid_str in samples:
+            hits = 0
+            for i in range(parts):
+                last = i == parts - 1
+                upper = bounds[i + 1]
+                upper_ok = ulid_str <= upper if last else ulid_str < upper
+                if bounds[i] <= ulid_str and upper_ok:
+                    hits += 1
+            assert hits == 1, ulid_str
+
+    def test_invalid_arguments(self):
+        """Test validation of parts and window order."""
+        with pytest.raises(ValueError):
+            pyulid.ulid_scan_partitions(0, 10, 0)
+        with pytest.raises(ValueError):
+            pyulid.ulid_scan_partitions(0, 10, -1)
+        with pytest.raises(ValueError):
+            pyulid.ulid_scan_partitions(10, 0, 2)
+        with pytest.raises(ValueError):
+            pyulid.ulid_scan_partitions(0, 2**48, 2)
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
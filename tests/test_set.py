@@ -0,0 +1,168 @@
+"""
+UlidSet container tests for PyULID.
+
+Tests construction, membership, set algebra and serialization.
+"""
+
+import random
+
+import pytest
+import pyulid
+
+
+def _random_ulids(n):
+    return [pyulid.ulid_from_int(random.getrandbits(128)) for _ in range(n)]
+
+
+class TestSetConstruction:
+    """Test the ways to build a UlidSet."""
+
+    def test_from_iterables(self):
+        """Test construction from strings, ULID objects and UlidArray."""
+        ulids = [pyulid.ulid() for _ in range(10)]
+        mixed = [ulids[0].lower(), pyulid.ULID(ulids[1])] + ulids[2:] + ulids[:3]
+
+        members = pyulid.UlidSet(mixed)
+        assert len(members) == 10
+        assert list(members) == sorted(ulids)
+        assert pyulid.UlidSet(pyulid.UlidArray(ulids)) == members
+        assert len(pyulid.UlidSet()) == 0
+
+    def test_from_bytes_blob(self):
+        """Test construction from a blob of big-endian records."""
+        members = pyulid.UlidSet(_random_ulids(100))
+        blob = members.to_bytes()
+
+        assert len(blob) == 1600
+        assert pyulid.UlidSet(blob) == members
+        assert pyulid.UlidSet(bytearray(blob)) == members
+        assert pyulid.UlidSet.from_bytes(blob) == members
+        assert pyulid.UlidSet(pyulid.UlidArray(list(members)).to_bytes()) == members
+
+        with pytest.raises(ValueError):
+            pyulid.UlidSet(b"\x00" * 17)
+
+    def test_to_bytes_sorted(self):
+        """Test that the bytes form is the sorted, unique records."""
+        ulids = _random_ulids(50)
+        blob = pyulid.UlidSet(ulids + ulids).to_bytes()
+
+        records = [blob[i : i + 16] for i in range(0, len(blob), 16)]
+        expected = [pyulid.ulid_to_int(u).to_bytes(16, "big") for u in sorted(ulids)]
+        assert records == expected
+
+    def test_invalid_items(self):
+        """Test that invalid entries raise."""
+        with pytest.raises(ValueError):
+            pyulid.UlidSet([pyulid.ulid(), "invalid"])
+        with pytest.raises(TypeError):
+            pyulid.UlidSet([42])
+
+    def test_overflowing_items(self):
+        """Test that values above 128 bits raise instead of wrapping."""
+        with pytest.raises(pyulid.UlidDecodeError, match="0-7"):
+            pyulid.UlidSet([pyulid.ulid(), "Z" * 26])
+        with pytest.raises(pyulid.UlidDecodeError, match="0-7"):
+            pyulid.UlidSet().union(["8" + "0" * 25])
+
+
+class TestSetMembership:
+    """Test membership, add and iteration."""
+
+    def test_contains(self):
+        """Test `in` with strings, ULID objects and non-ULIDs."""
+        ulids = _random_ulids(100)
+        members = pyulid.UlidSet(ulids)
+
+        for ulid_str in ulids:
+            assert ulid_str in members
+            assert ulid_str.lower() in members
+            assert pyulid.ULID(ulid_str) in members
+        assert pyulid.ulid() not in members
+        assert "invalid" not in members
+        assert 42 not in members
+
+    def test_add(self):
+        """Test that add inserts once and keeps order."""
+        members = pyulid.UlidSet()
+        ulids = _random_ulids(50)
+        for ulid_str in ulids + ulids:
+            members.add(ulid_str)
+
+        assert len(members) == 50
+        assert list(members) == sorted(ulids)
+        with pytest.raises(ValueError):
+            members.add("invalid")
+
+    def test_memory_per_element(self):
+        """Test that each element costs exactly 16 bytes."""
+        empty = pyulid.UlidSet().__sizeof__()
+        for n in [1, 1000, 100000]:
+            members = pyulid.UlidSet(pyulid.ulid_batch_array(n))
+            assert members.__sizeof__() - empty == 16 * n
+
+
+class TestSetAlgebra:
+    """Test set operations against the built-in set."""
+
+    def test_operations_match_builtin_set(self):
+        """Test every operation against Python sets of strings."""
+        pool = _random_ulids(400)
+        a_list = random.sample(pool, 250)
+        b_list = random.sample(pool, 250)
+        a, b = pyulid.UlidSet(a_list), pyulid.UlidSet(b_list)
+        sa, sb = set(a_list), set(b_list)
+
+        assert list(a.union(b)) == sorted(sa | sb)
+        assert list(a.intersection(b)) == sorted(sa & sb)
+        assert list(a.difference(b)) == sorted(sa - sb)
+        assert list(a.symmetric_difference(b)) == sorted(sa ^ sb)
+
+        assert a | b == a.union(b)
+        assert a & b == a.intersection(b)
+        assert a - b == a.difference(b)
+        assert a ^ b == a.symmetric_difference(b)
+
+    def test_methods_accept_iterables(self):
+        """Test that named methods take any iterable, like set methods."""
+        ulids = _random_ulids(20)
+        a = pyulid.UlidSet(ulids[:10])
+
+        assert a.union(ulids[10:]) == pyulid.UlidSet(ulids)
+        assert len(a.intersection(pyulid.UlidArray(ulids[5:]))) == 5
+        assert len(a.difference(pyulid.UlidSet(ulids[:5]).to_bytes())) == 5
+
+    def test_operators_require_ulidset(self):
+        """Test that operators reject non-UlidSet operands."""
+        a = pyulid.UlidSet(_random_ulids(5))
+        with pytest.raises(TypeError):
+            a | list(a)
+        with pytest.raises(TypeError):
+            a - set(a)
+
+    def test_returns_new_sets(self):
+        """Test that operations leave their operands unchanged."""
+        a = pyulid.UlidSet(_random_ulids(10))
+        b = pyulid.UlidSet(_random_ulids(10))
+        before_a, before_b = a.to_bytes(), b.to_bytes()
+
+        result = a | b
+        result.add(pyulid.ulid())
+        assert a.to_bytes() == before_a
+        assert b.to_bytes() == before_b
+        assert (a - a) == pyulid.UlidSet()
+        assert (a & a) == a
+
+    def test_large_reconciliation(self):
+        """Test difference over large exports."""
+        export_a = pyulid.ulid_batch_array(200000)
+        export_b = export_a[::2]
+
+        missing = pyulid.UlidSet(export_a) - pyulid.UlidSet(export_b)
+        assert len(missing) == 100000
+        assert export_a[1] in missing
+        assert export_a[0] not in missing
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
@@ -0,0 +1,124 @@
+"""
+Covert tag tests for PyULID.
+
+Tests hiding and recovering keyed tags in the random component.
+"""
+
+import random
+
+import pytest
+import pyulid
+
+KEY = b"0123456789abcdef-forensics"
+
+
+def _random_ulid(timestamp):
+    return pyulid.ulid_set_random(
+        pyulid.ulid_with_timestamp(timestamp), random.getrandbits(80)
+    )
+
+
+class TestEmbedTag:
+    """Test ulid_embed_tag and ulid_extract_tag."""
+
+    def test_roundtrip_many_timestamps(self):
+        """Test that tags survive embedding across many timestamps."""
+        for _ in range(2000):
+            timestamp = random.randrange(2**48)
+            tag = random.randrange(256)
+            tagged = pyulid.ulid_embed_tag(_random_ulid(timestamp), tag, KEY)
+            assert pyulid.ulid_extract_tag(tagged, KEY) == tag
+
+    def test_tag_bits(self):
+        """Test round trips at several tag widths."""
+        u = pyulid.ulid()
+        for bits in [1, 4, 16, 32]:
+            for tag in [0, 1, 2**bits - 1, random.randrange(2**bits)]:
+                tagged = pyulid.ulid_embed_tag(u, tag, KEY, tag_bits=bits)
+                assert pyulid.ulid_extract_tag(tagged, KEY, tag_bits=bits) == tag
+
+    def test_keeps_ulid_valid_and_sortable(self):
+        """Test that only random bits change, so the timestamp order holds."""
+        ulids = []
+        for ts in range(1672531200000, 1672531200000 + 500):
+            ulids.append(pyulid.ulid_embed_tag(_random_ulid(ts), 42, KEY))
+
+        assert all(pyulid.ulid_is_valid(u) for u in ulids)
+        assert ulids == sorted(ulids)
+        assert [pyulid.ulid_timestamp(u) for u in ulids] == list(
+            range(1672531200000, 1672531200500)
+        )
+
+    def test_deterministic(self):
+        """Test that embedding is a pure function of its inputs."""
+        u = pyulid.ulid()
+        assert pyulid.ulid_embed_tag(u, 7, KEY) == pyulid.ulid_embed_tag(u, 7, KEY)
+        assert pyulid.ulid_embed_tag(u, 7, KEY.decode()) == pyulid.ulid_embed_tag(
+            u, 7, KEY
+        )
+        tagged = pyulid.ulid_embed_tag(u, 7, KEY)
+        assert pyulid.ulid_embed_tag(tagged, 7, KEY) == tagged
+
+    def test_changes_few_bits(self):
+        """Test that at most tag_bits bits of the random component change."""
+        u = _random_ulid(1672531200000)
+        tagged = pyulid.ulid_embed_tag(u, 200, KEY)
+        changed = pyulid.ulid_to_int(u) ^ pyulid.ulid_to_int(tagged)
+        assert changed < 2**80
+        assert bin(changed).count("1") <= 8
+
+    def test_wrong_key(self):
+        """Test that a different key doesn't recover the tag reliably."""
+        other = b"another-sixteen-byte-key"
+        hits = 0
+        for _ in range(500):
+            tag = random.randrange(256)
+            u = _random_ulid(random.randrange(2**48))
+            tagged = pyulid.ulid_embed_tag(u, tag, KEY)
+            hits += pyulid.ulid_extract_tag(tagged, other) == tag
+
+        # Chance level is 1/256; allow a generous margin
+        assert hits < 15
+
+    def test_looks_random_without_key(self):
+        """Test that every random bit stays balanced when the same tag is embedded."""
+        counts = [0] * 80
+        n = 4000
+        for _ in range(n):
+            u = _random_ulid(random.randrange(2**48))
+            tagged = pyulid.ulid_embed_tag(u, 0, KEY)
+            rnd = pyulid.ulid_random(tagged)
+            for bit in range(80):
+                counts[bit] += (rnd >> bit) & 1
+
+        assert all(0.42 * n < c < 0.58 * n for c in counts)
+
+    def test_accepts_ulid_objects(self):
+        """Test ULID objects and lowercase strings."""
+        u = pyulid.ulid()
+        tagged = pyulid.ulid_embed_tag(pyulid.ULID(u), 3, KEY)
+        assert tagged == pyulid.ulid_embed_tag(u.lower(), 3, KEY)
+        assert pyulid.ulid_extract_tag(pyulid.ULID(tagged), KEY) == 3
+
+    def test_invalid(self):
+        """Test validation of tags, widths, keys and ULIDs."""
+        u = pyulid.ulid()
+        with pytest.raises(ValueError):
+            pyulid.ulid_embed_tag(u, 256, KEY)
+        with pytest.raises(ValueError):
+            pyulid.ulid_embed_tag(u, -1, KEY)
+        for bits in [0, 33]:
+            with pytest.raises(ValueError):
+                pyulid.ulid_embed_tag(u, 0, KEY, tag_bits=bits)
+            with pytest.raises(ValueError):
+                pyulid.ulid_extract_tag(u, KEY, tag_bits=bits)
+        with pytest.raises(ValueError, match="16 bytes"):
+            pyulid.ulid_embed_tag(u, 0, b"short")
+        with pytest.raises(TypeError):
+            pyulid.ulid_embed_tag(u, 0, 12345)
+        with pytest.raises(ValueError):
+            pyulid.ulid_extract_tag("invalid", KEY)
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])
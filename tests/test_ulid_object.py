@@ -0,0 +1,145 @@
+"""
+Ulid value object tests for PyULID.
+
+Tests the Rust-backed Ulid type: construction, ordering, hashing and interop with
+the string functions.
+"""
+
+import copy
+import pickle
+
+import pytest
+import pyulid
+
+KNOWN = "01ARZ3NDEKTSV4RRFFQ69G5FAV"
+
+
+class TestConstruction:
+    """Test creating Ulid objects."""
+
+    def test_from_string(self):
+        """Test parsing a string, case-insensitively."""
+        value = pyulid.Ulid.from_string(KNOWN.lower())
+        assert str(value) == KNOWN
+        assert repr(value) == f"Ulid('{KNOWN}')"
+
+    def test_from_string_invalid(self):
+        """Test that invalid strings raise ValueError."""
+        for bad in ["", "01ARZ3NDEKTSV4RRFFQ69G5FA", "01ARZ3NDEKTSV4RRFFQ69G5FAU"]:
+            with pytest.raises(ValueError):
+                pyulid.Ulid.from_string(bad)
+        with pytest.raises(TypeError):
+            pyulid.Ulid.from_string(123)
+
+    def test_new_is_monotonic(self):
+        """Test that new() shares the global generator with ulid()."""
+        values = [pyulid.Ulid.new() for _ in range(100)]
+        before, after = pyulid.ulid(), pyulid.Ulid.new()
+        assert values == sorted(values)
+        assert str(values[-1]) < before < str(after)
+
+    def test_components(self):
+        """Test timestamp_ms and random match the string functions."""
+        value = pyulid.Ulid.from_string(KNOWN)
+        assert value.timestamp_ms == pyulid.ulid_timestamp(KNOWN)
+        assert value.random == pyulid.ulid_random(KNOWN)
+
+    def test_immutable(self):
+        """Test that attributes can't be assigned."""
+        value = pyulid.Ulid.new()
+        with pytest.raises(AttributeError):
+            value.random = 0
+
+
+class TestOrdering:
+    """Test comparisons and hashing."""
+
+    def test_matches_string_order(self):
+        """Test that object order agrees with lexicographic string order."""
+        strings = [pyulid.ulid_with_timestamp(ts) for ts in range(0, 5000, 7)]
+        strings.append("7ZZZZZZZZZZZZZZZZZZZZZZZZZ")
+        strings.append("00000000000000000000000000")
+        objects = sorted(pyulid.Ulid.from_string(s) for s in strings)
+        assert [str(o) for o in objects] == sorted(strings)
+
+    def test_rich_comparisons(self):
+        """Test every comparison operator."""
+        low = pyulid.Ulid.from_string(pyulid.ulid_with_timestamp(1000))
+        high = pyulid.Ulid.from_string(pyulid.ulid_with_timestamp(2000))
+        assert low < high and low <= high and high > low and high >= low
+        assert low != high and low <= low and low >= low
+
+    def test_hash_and_equality(self):
+        """Test that equal values hash alike and work as dict keys."""
+        a = pyulid.Ulid.from_string(KNOWN)
+        b = pyulid.Ulid.from_string(KNOWN.lower())
+        assert a == b and a is not b
+        assert hash(a) == hash(b)
+        assert {a: 1}[b] == 1
+        assert len({a, b, pyulid.Ulid.new()}) == 2
+
+    def test_not_equal_to_string(self):
+        """Test that a Ulid never equals its string form."""
+        assert pyulid.Ulid.from_string(KNOWN) != KNOWN
+
+
+class TestInterop:
+    """Test passing Ulid objects to the string functions."""
+
+    def test_accepted_as_argument(self):
+        """Test that functions taking ULID objects accept a Ulid too."""
+        value = pyulid.Ulid.from_string(KNOWN)
+        assert pyulid.ulid_floor(value) == pyulid.ulid_floor(KNOWN)
+        assert pyulid.ulid_to_ipv6(value) == pyulid.ulid_to_ipv6(KNOWN)
+        assert pyulid.ulid_delta(value, KNOWN, "ms") == 0
+
+    def test_interval_bounds(self):
+        """Test that UlidInterval accepts Ulid bounds."""
+        a, b = pyulid.Ulid.new(), pyulid.Ulid.new()
+        interval = pyulid.UlidInterval(a, b)
+        assert interval.start == str(a)
+        assert a in interval and b not in interval
+
+
+class TestSerialization:
+    """Test bytes round-trips, pickling and copying."""
+
+    def test_bytes_round_trip(self):
+        """Test from_bytes/to_bytes against the module functions."""
+        value = pyulid.Ulid.from_string(KNOWN)
+        assert value.to_bytes() == pyulid.ulid_to_bytes(KNOWN)
+        assert pyulid.Ulid.from_bytes(value.to_bytes()) == value
+
+    def test_from_bytes_invalid(self):
+        """Test that anything but 16 bytes raises ValueError."""
+        with pytest.raises(ValueError, match="exactly 16 bytes, got 15"):
+            pyulid.Ulid.from_bytes(b"\x00" * 15)
+
+    @pytest.mark.parametrize("protocol", range(2, pickle.HIGHEST_PROTOCOL + 1))
+    def test_pickle(self, protocol):
+        """Test that every protocol round-trips to an equal, equally hashed value."""
+        value = pyulid.Ulid.new()
+        restored = pickle.loads(pickle.dumps(value, protocol=protocol))
+        assert type(restored) is pyulid.Ulid
+        assert restored == value
+        assert hash(restored) == hash(value)
+
+    def test_pickle_is_compact(self):
+        """Test that the payload carries the 16-byte form, not the string."""
+        value = pyulid.Ulid.from_string(KNOWN)
+        payload = pickle.dumps(value, protocol=pickle.HIGHEST_PROTOCOL)
+        assert value.to_bytes() in payload
+        assert KNOWN.encode() not in payload
+
+    def test_pickle_in_containers(self):
+        """Test values nested in the kind of payload a task queue sends."""
+        values = [pyulid.Ulid.new() for _ in range(10)]
+        payload = {"ids": values, "first": values[0]}
+        assert pickle.loads(pickle.dumps(payload)) == payload
+
+    def test_copy(self):
+        """Test that copy and deepcopy give equal values."""
+        value = pyulid.Ulid.new()
+        assert copy.copy(value) == value
+        assert copy.deepcopy(value) == value
+        assert copy.deepcopy([value, value]) == [value, value]
@@ -0,0 +1,118 @@
+"""
+uuid4 patch tests for PyULID.
+
+Tests the opt-in replacement of uuid.uuid4 with time-ordered values.
+"""
+
+import threading
+import time
+import uuid
+
+import pytest
+import pyulid
+
+ORIGINAL_UUID4 = uuid.uuid4
+
+
+@pytest.fixture(autouse=True)
+def unpatch():
+    yield
+    pyulid.uninstall_uuid4_patch()
+    assert uuid.uuid4 is ORIGINAL_UUID4
+
+
+class TestUuid4Patch:
+    """Test install_uuid4_patch and uninstall_uuid4_patch."""
+
+    def test_presents_as_v4(self):
+        """Test that patched values are valid version 4 UUIDs."""
+        pyulid.install_uuid4_patch()
+        for _ in range(100):
+            value = uuid.uuid4()
+            assert isinstance(value, uuid.UUID)
+            assert value.version == 4
+            assert value.variant == uuid.RFC_4122
+            assert uuid.UUID(str(value)).version == 4
+
+    def test_time_ordered(self):
+        """Test that patched values sort in generation order."""
+        pyulid.install_uuid4_patch()
+        values = []
+        for _ in range(50):
+            values.extend(uuid.uuid4() for _ in range(20))
+            time.sleep(0.001)
+
+        assert values == sorted(values)
+        assert [str(v) for v in values] == sorted(str(v) for v in values)
+        assert len(set(values)) == len(values)
+
+    def test_carries_timestamp(self):
+        """Test that the first 48 bits are the generation time."""
+        pyulid.install_uuid4_patch()
+        before = int(time.time() * 1000)
+        value = uuid.uuid4()
+        after = int(time.time() * 1000)
+        assert before <= value.int >> 80 <= after + 1
+
+    def test_strict_ulid(self):
+        """Test that strict_ulid keeps the exact ULID bits."""
+        pyulid.install_uuid4_patch(strict_ulid=True)
+        before = pyulid.ulid()
+        value = uuid.uuid4()
+        ulid_str = pyulid.ulid_from_int(value.int)
+
+        assert pyulid.ulid_is_valid(ulid_str)
+        assert before < ulid_str < pyulid.ulid()
+
+    def test_uninstall_restores(self):
+        """Test that uninstall brings back random v4 UUIDs."""
+        pyulid.install_uuid4_patch()
+        assert uuid.uuid4 is not ORIGINAL_UUID4
+        pyulid.uninstall_uuid4_patch()
+        assert uuid.uuid4 is ORIGINAL_UUID4
+        assert uuid.uuid4().version == 4
+
+        # Uninstalling when not installed is a no-op
+        pyulid.uninstall_uuid4_patch()
+        assert uuid.uuid4 is ORIGINAL_UUID4
+
+    def test_idempotent(self):
+        """Test that repeated installs don't stack and only change the mode."""
+        pyulid.install_uuid4_patch()
+        patched = uuid.uuid4
+        pyulid.install_uuid4_patch(strict_ulid=True)
+        assert uuid.uuid4 is patched
+
+        pyulid.install_uuid4_patch()
+        assert uuid.uuid4().version == 4
+        pyulid.uninstall_uuid4_patch()
+        assert uuid.uuid4 is ORIGINAL_UUID4
+
+    def test_thread_safe(self):
+        """Test concurrent installs, uninstalls and calls."""
+        errors = []
+
+        def worker(i):
+            try:
+                for _ in range(200):
+                    if i % 2:
+                        pyulid.install_uuid4_patch()
+                    else:
+                        pyulid.uninstall_uuid4_patch()
+                    assert uuid.uuid4().version == 4
+            except Exception as e:
+                errors.append(e)
+
+        threads = [threading.Thread(target=worker, args=(i,)) for i in range(8)]
+        for t in threads:
+            t.start()
+        for t in threads:
+            t.join()
+
+        assert errors == []
+        pyulid.uninstall_uuid4_patch()
+        assert uuid.uuid4 is ORIGINAL_UUID4
+
+
+if __name__ == "__main__":
+    pytest.main([__file__, "-v"])